@@ -5,7 +5,7 @@ use mollie_api::Mollie;
 #[tokio::main]
 async fn main() {
     let auth_token = var("MOLLIE_API_KEY").expect("MOLLIE_API_KEY not set");
-    let client = Mollie::build(&auth_token);
+    let client = Mollie::build(auth_token);
 
     let me = client.organizations().me().await.unwrap();
     println!("Me: {:#?}", me);
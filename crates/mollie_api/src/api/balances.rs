@@ -1,19 +1,26 @@
 //! Balances API module
 //!
 //! Used to retrieve information about your balances.
-use crate::models::balance::{BalanceResource, BalancesListResource};
+use crate::models::{
+    balance::{
+        BalanceReportGrouping, BalanceReportResource, BalanceResource, BalanceTransactionResource,
+    },
+    list::Page,
+};
 use crate::ApiClient;
+use chrono::NaiveDate;
 use std::collections::HashMap;
 
 /// [Balances Api](https://docs.mollie.com/reference/v2/balances-api/overview)
 /// Used to retrieve information about a balance.
-pub struct BalancesApi<'client> {
-    api_client: &'client ApiClient<'client>,
+#[derive(Debug, Clone)]
+pub struct BalancesApi {
+    api_client: ApiClient,
 }
 
-impl<'client> BalancesApi<'client> {
+impl BalancesApi {
     /// Create a new `BalancesApi`.
-    pub fn new(api_client: &'client ApiClient) -> Self {
+    pub fn new(api_client: ApiClient) -> Self {
         Self { api_client }
     }
 
@@ -27,7 +34,7 @@ impl<'client> BalancesApi<'client> {
         &self,
         limit: Option<i32>,
         from: &Option<String>,
-    ) -> crate::Result<BalancesListResource> {
+    ) -> crate::Result<Page<BalanceResource>> {
         let mut query_params: HashMap<&str, String> = HashMap::new();
 
         if let Some(l) = limit {
@@ -38,6 +45,41 @@ impl<'client> BalancesApi<'client> {
             query_params.insert("from", f.to_string());
         }
 
-        self.api_client.get("/balances", Some(query_params)).await
+        self.api_client
+            .get_page("/balances", Some(query_params))
+            .await
+    }
+
+    /// [List balance transactions](https://docs.mollie.com/reference/v2/balances-api/list-balance-transactions),
+    /// newest first; use `primary` as id for the primary balance
+    pub async fn list_transactions(
+        &self,
+        balance_id: &str,
+        limit: Option<i32>,
+        from: &Option<String>,
+    ) -> crate::Result<Page<BalanceTransactionResource>> {
+        let endpoint = format!("/balances/{}/transactions", balance_id);
+        let params = super::list_params(limit, from, None);
+        self.api_client.get_page(&endpoint, Some(params)).await
+    }
+
+    /// [Get balance report](https://docs.mollie.com/reference/v2/balances-api/get-balance-report)
+    /// of the days from `from` up to, but not including, `until`
+    pub async fn get_report(
+        &self,
+        balance_id: &str,
+        from: NaiveDate,
+        until: NaiveDate,
+        grouping: Option<&BalanceReportGrouping>,
+    ) -> crate::Result<BalanceReportResource> {
+        let endpoint = format!("/balances/{}/report", balance_id);
+        let mut params = HashMap::new();
+        params.insert("from", from.format("%Y-%m-%d").to_string());
+        params.insert("until", until.format("%Y-%m-%d").to_string());
+        if let Some(grouping) = grouping {
+            params.insert("grouping", grouping.to_string());
+        }
+
+        self.api_client.get(&endpoint, Some(params)).await
     }
 }
@@ -0,0 +1,60 @@
+//! Captures API module
+//!
+//! Used to capture the funds of payments that were only authorized
+use super::list_params;
+use crate::{
+    models::{
+        capture::{CaptureResource, CreateCaptureRequest},
+        list::Page,
+    },
+    ApiClient,
+};
+
+/// [Captures Api](https://docs.mollie.com/reference/v2/captures-api/overview)
+/// Used to capture authorized payments, fully or partially, and to retrieve their captures.
+#[derive(Debug, Clone)]
+pub struct CapturesApi {
+    api_client: ApiClient,
+}
+
+impl CapturesApi {
+    /// Create a new `CapturesApi`.
+    pub fn new(api_client: ApiClient) -> Self {
+        Self { api_client }
+    }
+
+    /// [Create capture](https://docs.mollie.com/reference/v2/captures-api/create-capture)
+    pub async fn create(
+        &self,
+        payment_id: &str,
+        body: &CreateCaptureRequest,
+    ) -> crate::Result<CaptureResource> {
+        let endpoint = format!("/payments/{}/captures", payment_id);
+        self.api_client.post(&endpoint, body).await
+    }
+
+    /// [Get capture](https://docs.mollie.com/reference/v2/captures-api/get-capture)
+    pub async fn get_by_id(
+        &self,
+        payment_id: &str,
+        capture_id: &str,
+    ) -> crate::Result<CaptureResource> {
+        let endpoint = format!("/payments/{}/captures/{}", payment_id, capture_id);
+        self.api_client.get(&endpoint, None).await
+    }
+
+    /// [List captures](https://docs.mollie.com/reference/v2/captures-api/list-captures)
+    /// of a payment
+    pub async fn list(
+        &self,
+        payment_id: &str,
+        limit: Option<i32>,
+        from: &Option<String>,
+        test_mode: Option<bool>,
+    ) -> crate::Result<Page<CaptureResource>> {
+        let endpoint = format!("/payments/{}/captures", payment_id);
+        let params = list_params(limit, from, test_mode);
+
+        self.api_client.get_page(&endpoint, Some(params)).await
+    }
+}
@@ -0,0 +1,63 @@
+//! Chargebacks API module
+//!
+//! Used to retrieve the payments customers disputed with their bank or card issuer
+use super::list_params;
+use crate::{
+    models::{chargeback::ChargebackResource, list::Page},
+    ApiClient,
+};
+
+/// [Chargebacks Api](https://docs.mollie.com/reference/v2/chargebacks-api/overview)
+/// Used to retrieve chargebacks, which can't be created or changed through the API.
+#[derive(Debug, Clone)]
+pub struct ChargebacksApi {
+    api_client: ApiClient,
+}
+
+impl ChargebacksApi {
+    /// Create a new `ChargebacksApi`.
+    pub fn new(api_client: ApiClient) -> Self {
+        Self { api_client }
+    }
+
+    /// [Get payment chargeback](https://docs.mollie.com/reference/v2/chargebacks-api/get-payment-chargeback)
+    pub async fn get_by_id(
+        &self,
+        payment_id: &str,
+        chargeback_id: &str,
+    ) -> crate::Result<ChargebackResource> {
+        let endpoint = format!("/payments/{}/chargebacks/{}", payment_id, chargeback_id);
+        self.api_client.get(&endpoint, None).await
+    }
+
+    /// [List all chargebacks](https://docs.mollie.com/reference/v2/chargebacks-api/list-all-chargebacks)
+    /// of the organization
+    pub async fn list(
+        &self,
+        limit: Option<i32>,
+        from: &Option<String>,
+        profile_id: &Option<String>,
+        test_mode: Option<bool>,
+    ) -> crate::Result<Page<ChargebackResource>> {
+        let mut params = list_params(limit, from, test_mode);
+        if let Some(p) = profile_id {
+            params.insert("profileId", p.to_string());
+        }
+
+        self.api_client.get_page("/chargebacks", Some(params)).await
+    }
+
+    /// [List payment chargebacks](https://docs.mollie.com/reference/v2/chargebacks-api/list-payment-chargebacks)
+    pub async fn list_for_payment(
+        &self,
+        payment_id: &str,
+        limit: Option<i32>,
+        from: &Option<String>,
+        test_mode: Option<bool>,
+    ) -> crate::Result<Page<ChargebackResource>> {
+        let endpoint = format!("/payments/{}/chargebacks", payment_id);
+        let params = list_params(limit, from, test_mode);
+
+        self.api_client.get_page(&endpoint, Some(params)).await
+    }
+}
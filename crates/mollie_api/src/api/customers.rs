@@ -0,0 +1,77 @@
+//! Customers API module
+//!
+//! Used to create and manage customers, e.g. for recurring payments
+use super::list_params;
+use crate::{
+    models::{
+        customer::{CreateCustomerRequest, CustomerResource, UpdateCustomerRequest},
+        list::Page,
+        payment::PaymentResource,
+    },
+    ApiClient,
+};
+
+/// [Customers Api](https://docs.mollie.com/reference/v2/customers-api/overview)
+/// Used to create and manage customers.
+#[derive(Debug, Clone)]
+pub struct CustomersApi {
+    api_client: ApiClient,
+}
+
+impl CustomersApi {
+    /// Create a new `CustomersApi`.
+    pub fn new(api_client: ApiClient) -> Self {
+        Self { api_client }
+    }
+
+    /// [Create customer](https://docs.mollie.com/reference/v2/customers-api/create-customer)
+    pub async fn create(&self, body: &CreateCustomerRequest) -> crate::Result<CustomerResource> {
+        self.api_client.post("/customers", body).await
+    }
+
+    /// [Get customer](https://docs.mollie.com/reference/v2/customers-api/get-customer)
+    pub async fn get_by_id(&self, id: &str) -> crate::Result<CustomerResource> {
+        let endpoint = format!("/customers/{}", id);
+        self.api_client.get(&endpoint, None).await
+    }
+
+    /// [Update customer](https://docs.mollie.com/reference/v2/customers-api/update-customer)
+    pub async fn update(
+        &self,
+        id: &str,
+        body: &UpdateCustomerRequest,
+    ) -> crate::Result<CustomerResource> {
+        let endpoint = format!("/customers/{}", id);
+        self.api_client.patch(&endpoint, body).await
+    }
+
+    /// [Delete customer](https://docs.mollie.com/reference/v2/customers-api/delete-customer)
+    pub async fn delete(&self, id: &str) -> crate::Result<()> {
+        let endpoint = format!("/customers/{}", id);
+        self.api_client.delete(&endpoint, None).await
+    }
+
+    /// [List customers](https://docs.mollie.com/reference/v2/customers-api/list-customers)
+    pub async fn list(
+        &self,
+        limit: Option<i32>,
+        from: &Option<String>,
+        test_mode: Option<bool>,
+    ) -> crate::Result<Page<CustomerResource>> {
+        let params = list_params(limit, from, test_mode);
+        self.api_client.get_page("/customers", Some(params)).await
+    }
+
+    /// [List customer payments](https://docs.mollie.com/reference/v2/customers-api/list-customer-payments)
+    pub async fn list_payments(
+        &self,
+        customer_id: &str,
+        limit: Option<i32>,
+        from: &Option<String>,
+        test_mode: Option<bool>,
+    ) -> crate::Result<Page<PaymentResource>> {
+        let endpoint = format!("/customers/{}/payments", customer_id);
+        let params = list_params(limit, from, test_mode);
+        self.api_client.get_page(&endpoint, Some(params)).await
+    }
+}
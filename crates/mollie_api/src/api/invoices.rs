@@ -0,0 +1,54 @@
+//! Invoices API module
+//!
+//! Used to retrieve the invoices Mollie sends for its fees
+use super::list_params;
+use crate::{
+    models::{invoice::InvoiceResource, link::Link, list::Page},
+    ApiClient,
+};
+
+/// [Invoices Api](https://docs.mollie.com/reference/v2/invoices-api/overview)
+/// Used to retrieve invoices and their PDFs, only available with an access token.
+#[derive(Debug, Clone)]
+pub struct InvoicesApi {
+    api_client: ApiClient,
+}
+
+impl InvoicesApi {
+    /// Create a new `InvoicesApi`.
+    pub fn new(api_client: ApiClient) -> Self {
+        Self { api_client }
+    }
+
+    /// [Get invoice](https://docs.mollie.com/reference/v2/invoices-api/get-invoice)
+    pub async fn get_by_id(&self, invoice_id: &str) -> crate::Result<InvoiceResource> {
+        let endpoint = format!("/invoices/{}", invoice_id);
+        self.api_client.get(&endpoint, None).await
+    }
+
+    /// [List invoices](https://docs.mollie.com/reference/v2/invoices-api/list-invoices),
+    /// optionally only the one with a reference or the ones issued in a year
+    pub async fn list(
+        &self,
+        reference: &Option<String>,
+        year: Option<i32>,
+        limit: Option<i32>,
+        from: &Option<String>,
+    ) -> crate::Result<Page<InvoiceResource>> {
+        let mut params = list_params(limit, from, None);
+        if let Some(r) = reference {
+            params.insert("reference", r.to_string());
+        }
+
+        if let Some(y) = year {
+            params.insert("year", y.to_string());
+        }
+
+        self.api_client.get_page("/invoices", Some(params)).await
+    }
+
+    /// Download the PDF of an invoice, see [`InvoiceResource::pdf_link`]
+    pub async fn download_pdf(&self, pdf_link: &Link) -> crate::Result<Vec<u8>> {
+        self.api_client.download(&pdf_link.href).await
+    }
+}
@@ -0,0 +1,74 @@
+//! Mandates API module
+//!
+//! Used to manage the mandates of customers, which allow charging them with recurring payments
+use super::{list_params, test_mode_params};
+use crate::{
+    models::{
+        list::Page,
+        mandate::{CreateMandateRequest, MandateResource},
+    },
+    ApiClient,
+};
+
+/// [Mandates Api](https://docs.mollie.com/reference/v2/mandates-api/overview)
+/// Used to create, retrieve and revoke the mandates of a customer.
+#[derive(Debug, Clone)]
+pub struct MandatesApi {
+    api_client: ApiClient,
+}
+
+impl MandatesApi {
+    /// Create a new `MandatesApi`.
+    pub fn new(api_client: ApiClient) -> Self {
+        Self { api_client }
+    }
+
+    /// [Create mandate](https://docs.mollie.com/reference/v2/mandates-api/create-mandate)
+    pub async fn create(
+        &self,
+        customer_id: &str,
+        body: &CreateMandateRequest,
+    ) -> crate::Result<MandateResource> {
+        let endpoint = format!("/customers/{}/mandates", customer_id);
+        self.api_client.post(&endpoint, body).await
+    }
+
+    /// [Get mandate](https://docs.mollie.com/reference/v2/mandates-api/get-mandate)
+    pub async fn get_by_id(
+        &self,
+        customer_id: &str,
+        mandate_id: &str,
+        test_mode: Option<bool>,
+    ) -> crate::Result<MandateResource> {
+        let endpoint = format!("/customers/{}/mandates/{}", customer_id, mandate_id);
+        self.api_client
+            .get(&endpoint, test_mode_params(test_mode))
+            .await
+    }
+
+    /// [Revoke mandate](https://docs.mollie.com/reference/v2/mandates-api/revoke-mandate)
+    pub async fn revoke(
+        &self,
+        customer_id: &str,
+        mandate_id: &str,
+        test_mode: Option<bool>,
+    ) -> crate::Result<()> {
+        let endpoint = format!("/customers/{}/mandates/{}", customer_id, mandate_id);
+        self.api_client
+            .delete(&endpoint, test_mode_params(test_mode))
+            .await
+    }
+
+    /// [List mandates](https://docs.mollie.com/reference/v2/mandates-api/list-mandates)
+    pub async fn list(
+        &self,
+        customer_id: &str,
+        limit: Option<i32>,
+        from: &Option<String>,
+        test_mode: Option<bool>,
+    ) -> crate::Result<Page<MandateResource>> {
+        let endpoint = format!("/customers/{}/mandates", customer_id);
+        let params = list_params(limit, from, test_mode);
+        self.api_client.get_page(&endpoint, Some(params)).await
+    }
+}
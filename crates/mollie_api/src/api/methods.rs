@@ -0,0 +1,45 @@
+//! Methods API module
+//!
+//! Used to find out which payment methods can be offered to a customer
+use crate::{
+    models::method::{MethodResource, MethodsListResource, MethodsQuery, PaymentMethod},
+    ApiClient,
+};
+
+/// [Methods Api](https://docs.mollie.com/reference/v2/methods-api/overview)
+/// Used to retrieve the payment methods enabled on a profile, their issuers and pricing.
+#[derive(Debug, Clone)]
+pub struct MethodsApi {
+    api_client: ApiClient,
+}
+
+impl MethodsApi {
+    /// Create a new `MethodsApi`.
+    pub fn new(api_client: ApiClient) -> Self {
+        Self { api_client }
+    }
+
+    /// [List payment methods](https://docs.mollie.com/reference/v2/methods-api/list-methods),
+    /// only the methods that are enabled and match the filters of the query
+    pub async fn list(&self, query: &MethodsQuery) -> crate::Result<MethodsListResource> {
+        self.api_client.get("/methods", Some(query.params())).await
+    }
+
+    /// [List all methods](https://docs.mollie.com/reference/v2/methods-api/list-all-methods),
+    /// including the ones that are not enabled on the profile
+    pub async fn list_all(&self, query: &MethodsQuery) -> crate::Result<MethodsListResource> {
+        self.api_client
+            .get("/methods/all", Some(query.params()))
+            .await
+    }
+
+    /// [Get method](https://docs.mollie.com/reference/v2/methods-api/get-method)
+    pub async fn get_by_id(
+        &self,
+        id: &PaymentMethod,
+        query: &MethodsQuery,
+    ) -> crate::Result<MethodResource> {
+        let endpoint = format!("/methods/{}", id);
+        self.api_client.get(&endpoint, Some(query.params())).await
+    }
+}
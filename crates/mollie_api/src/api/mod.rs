@@ -1,5 +1,47 @@
 pub mod balances;
+pub mod captures;
+pub mod chargebacks;
+pub mod customers;
+pub mod invoices;
+pub mod mandates;
+pub mod methods;
+pub mod onboarding;
+pub mod orders;
 pub mod organizations;
+pub mod payment_links;
 pub mod payments;
 pub mod permissions;
+pub mod profiles;
 pub mod refunds;
+pub mod settlements;
+pub mod shipments;
+pub mod subscriptions;
+
+use std::collections::HashMap;
+
+/// Query parameters shared by the list endpoints: pagination and test mode
+pub(crate) fn list_params(
+    limit: Option<i32>,
+    from: &Option<String>,
+    test_mode: Option<bool>,
+) -> HashMap<&'static str, String> {
+    let mut params = HashMap::new();
+    if let Some(l) = limit {
+        params.insert("limit", l.to_string());
+    }
+
+    if let Some(f) = from {
+        params.insert("from", f.to_string());
+    }
+
+    if let Some(t) = test_mode {
+        params.insert("testmode", t.to_string());
+    }
+
+    params
+}
+
+/// Query parameters of the endpoints of a single resource, which only need the test mode
+pub(crate) fn test_mode_params(test_mode: Option<bool>) -> Option<HashMap<&'static str, String>> {
+    test_mode.map(|t| HashMap::from([("testmode", t.to_string())]))
+}
@@ -0,0 +1,33 @@
+//! Onboarding API module
+//!
+//! Used to follow and speed up the onboarding of the organization
+use crate::{
+    models::onboarding::{OnboardingResource, SubmitOnboardingRequest},
+    ApiClient,
+};
+
+/// [Onboarding Api](https://docs.mollie.com/reference/v2/onboarding-api/overview)
+/// Used to check whether the organization can receive payments and settlements yet,
+/// and to prefill the data the merchant is asked for during onboarding.
+#[derive(Debug, Clone)]
+pub struct OnboardingApi {
+    api_client: ApiClient,
+}
+
+impl OnboardingApi {
+    /// Create a new `OnboardingApi`.
+    pub fn new(api_client: ApiClient) -> Self {
+        Self { api_client }
+    }
+
+    /// [Get onboarding status](https://docs.mollie.com/reference/v2/onboarding-api/get-onboarding-status)
+    pub async fn get_status(&self) -> crate::Result<OnboardingResource> {
+        self.api_client.get("/onboarding/me", None).await
+    }
+
+    /// [Submit onboarding data](https://docs.mollie.com/reference/v2/onboarding-api/submit-onboarding-data),
+    /// which the merchant can then review and complete in the dashboard
+    pub async fn submit(&self, request: &SubmitOnboardingRequest) -> crate::Result<()> {
+        self.api_client.post("/onboarding/me", request).await
+    }
+}
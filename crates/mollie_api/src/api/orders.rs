@@ -0,0 +1,105 @@
+//! Orders API module
+//!
+//! Used to create and manage orders, required for pay later methods like Klarna
+use std::collections::HashMap;
+
+use super::list_params;
+use crate::{
+    models::{
+        list::Page,
+        order::{
+            CancelOrderLinesRequest, CreateOrderRequest, OrderEmbed, OrderResource,
+            UpdateOrderLineRequest, UpdateOrderRequest,
+        },
+    },
+    ApiClient,
+};
+
+/// [Orders Api](https://docs.mollie.com/reference/v2/orders-api/overview)
+/// Used to create and manage orders and their lines.
+#[derive(Debug, Clone)]
+pub struct OrdersApi {
+    api_client: ApiClient,
+}
+
+impl OrdersApi {
+    /// Create a new `OrdersApi`.
+    pub fn new(api_client: ApiClient) -> Self {
+        Self { api_client }
+    }
+
+    /// [Create order](https://docs.mollie.com/reference/v2/orders-api/create-order)
+    pub async fn create(&self, body: &CreateOrderRequest) -> crate::Result<OrderResource> {
+        self.api_client.post("/orders", body).await
+    }
+
+    /// [Get order](https://docs.mollie.com/reference/v2/orders-api/get-order), embedding
+    /// the requested related resources in [`OrderResource::embedded`]
+    pub async fn get_by_id(&self, id: &str, embed: &[OrderEmbed]) -> crate::Result<OrderResource> {
+        let endpoint = format!("/orders/{}", id);
+        let params = (!embed.is_empty()).then(|| {
+            let embed = embed
+                .iter()
+                .map(OrderEmbed::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            HashMap::from([("embed", embed)])
+        });
+        self.api_client.get(&endpoint, params).await
+    }
+
+    /// [Update order](https://docs.mollie.com/reference/v2/orders-api/update-order)
+    pub async fn update(
+        &self,
+        id: &str,
+        body: &UpdateOrderRequest,
+    ) -> crate::Result<OrderResource> {
+        let endpoint = format!("/orders/{}", id);
+        self.api_client.patch(&endpoint, body).await
+    }
+
+    /// [Update order line](https://docs.mollie.com/reference/v2/orders-api/update-orderline),
+    /// returns the updated order
+    pub async fn update_line(
+        &self,
+        order_id: &str,
+        line_id: &str,
+        body: &UpdateOrderLineRequest,
+    ) -> crate::Result<OrderResource> {
+        let endpoint = format!("/orders/{}/lines/{}", order_id, line_id);
+        self.api_client.patch(&endpoint, body).await
+    }
+
+    /// [Cancel order lines](https://docs.mollie.com/reference/v2/orders-api/cancel-order-lines)
+    pub async fn cancel_lines(
+        &self,
+        order_id: &str,
+        body: &CancelOrderLinesRequest,
+    ) -> crate::Result<()> {
+        let endpoint = format!("/orders/{}/lines", order_id);
+        self.api_client.delete_with_body(&endpoint, body).await
+    }
+
+    /// [Cancel order](https://docs.mollie.com/reference/v2/orders-api/cancel-order),
+    /// returns the canceled order
+    pub async fn cancel(&self, id: &str) -> crate::Result<OrderResource> {
+        let endpoint = format!("/orders/{}", id);
+        self.api_client.delete(&endpoint, None).await
+    }
+
+    /// [List orders](https://docs.mollie.com/reference/v2/orders-api/list-orders)
+    pub async fn list(
+        &self,
+        limit: Option<i32>,
+        from: &Option<String>,
+        profile_id: &Option<String>,
+        test_mode: Option<bool>,
+    ) -> crate::Result<Page<OrderResource>> {
+        let mut params = list_params(limit, from, test_mode);
+        if let Some(p) = profile_id {
+            params.insert("profileId", p.to_string());
+        }
+
+        self.api_client.get_page("/orders", Some(params)).await
+    }
+}
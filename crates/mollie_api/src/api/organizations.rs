@@ -9,13 +9,14 @@ use crate::{
 
 /// [Organizations Api](https://docs.mollie.com/reference/v2/organizations-api/overview)
 /// Used to retrieve information about an organization.
-pub struct OrganizationsApi<'client> {
-    api_client: &'client ApiClient<'client>,
+#[derive(Debug, Clone)]
+pub struct OrganizationsApi {
+    api_client: ApiClient,
 }
 
-impl<'client> OrganizationsApi<'client> {
+impl OrganizationsApi {
     /// Create a new `OrganizationsApi`.
-    pub fn new(api_client: &'client ApiClient) -> Self {
+    pub fn new(api_client: ApiClient) -> Self {
         Self { api_client }
     }
 
@@ -0,0 +1,89 @@
+//! Payment Links API module
+//!
+//! Used to create links to share with customers, who can pay them without a checkout of your own
+use super::list_params;
+use crate::{
+    models::{
+        list::Page,
+        payment::PaymentResource,
+        payment_link::{CreatePaymentLinkRequest, PaymentLinkResource, UpdatePaymentLinkRequest},
+    },
+    ApiClient,
+};
+
+/// [Payment Links Api](https://docs.mollie.com/reference/v2/payment-links-api/overview)
+/// Used to create and manage payment links.
+#[derive(Debug, Clone)]
+pub struct PaymentLinksApi {
+    api_client: ApiClient,
+}
+
+impl PaymentLinksApi {
+    /// Create a new `PaymentLinksApi`.
+    pub fn new(api_client: ApiClient) -> Self {
+        Self { api_client }
+    }
+
+    /// [Create payment link](https://docs.mollie.com/reference/v2/payment-links-api/create-payment-link)
+    pub async fn create(
+        &self,
+        body: &CreatePaymentLinkRequest,
+    ) -> crate::Result<PaymentLinkResource> {
+        self.api_client.post("/payment-links", body).await
+    }
+
+    /// [Get payment link](https://docs.mollie.com/reference/v2/payment-links-api/get-payment-link)
+    pub async fn get_by_id(&self, id: &str) -> crate::Result<PaymentLinkResource> {
+        let endpoint = format!("/payment-links/{}", id);
+        self.api_client.get(&endpoint, None).await
+    }
+
+    /// [Update payment link](https://docs.mollie.com/reference/v2/payment-links-api/update-payment-link)
+    pub async fn update(
+        &self,
+        id: &str,
+        body: &UpdatePaymentLinkRequest,
+    ) -> crate::Result<PaymentLinkResource> {
+        let endpoint = format!("/payment-links/{}", id);
+        self.api_client.patch(&endpoint, body).await
+    }
+
+    /// [Delete payment link](https://docs.mollie.com/reference/v2/payment-links-api/delete-payment-link),
+    /// only possible for links that have not been paid yet
+    pub async fn delete(&self, id: &str) -> crate::Result<()> {
+        let endpoint = format!("/payment-links/{}", id);
+        self.api_client.delete(&endpoint, None).await
+    }
+
+    /// [List payment links](https://docs.mollie.com/reference/v2/payment-links-api/list-payment-links)
+    pub async fn list(
+        &self,
+        limit: Option<i32>,
+        from: &Option<String>,
+        profile_id: &Option<String>,
+        test_mode: Option<bool>,
+    ) -> crate::Result<Page<PaymentLinkResource>> {
+        let mut params = list_params(limit, from, test_mode);
+        if let Some(p) = profile_id {
+            params.insert("profileId", p.to_string());
+        }
+
+        self.api_client
+            .get_page("/payment-links", Some(params))
+            .await
+    }
+
+    /// [List payment link payments](https://docs.mollie.com/reference/v2/payment-links-api/get-payment-link-payments),
+    /// the payments made with a link
+    pub async fn list_payments(
+        &self,
+        id: &str,
+        limit: Option<i32>,
+        from: &Option<String>,
+        test_mode: Option<bool>,
+    ) -> crate::Result<Page<PaymentResource>> {
+        let endpoint = format!("/payment-links/{}/payments", id);
+        let params = list_params(limit, from, test_mode);
+        self.api_client.get_page(&endpoint, Some(params)).await
+    }
+}
@@ -4,19 +4,23 @@
 //!
 //! Used to retrieve information about
 use crate::{
-    models::payment::{CreatePaymentRequest, PaymentResource, PaymentsListResource},
+    models::{
+        list::Page,
+        payment::{CreatePaymentRequest, PaymentResource, UpdatePaymentRequest},
+    },
     ApiClient,
 };
 
 /// [Organizations Api](https://docs.mollie.com/reference/v2/organizations-api/overview)
 /// Used to retrieve information about an organization.
-pub struct PaymentsApi<'client> {
-    api_client: &'client ApiClient<'client>,
+#[derive(Debug, Clone)]
+pub struct PaymentsApi {
+    api_client: ApiClient,
 }
 
-impl<'client> PaymentsApi<'client> {
+impl PaymentsApi {
     /// Create a new `OrganizationsApi`.
-    pub fn new(api_client: &'client ApiClient) -> Self {
+    pub fn new(api_client: ApiClient) -> Self {
         Self { api_client }
     }
 
@@ -33,7 +37,7 @@ impl<'client> PaymentsApi<'client> {
         from: &Option<String>,
         profile_id: &Option<String>,
         test_mode: &Option<bool>,
-    ) -> crate::Result<PaymentsListResource> {
+    ) -> crate::Result<Page<PaymentResource>> {
         let endpoint = "/payments";
         let mut params = std::collections::HashMap::new();
         if let Some(l) = limit {
@@ -52,7 +56,7 @@ impl<'client> PaymentsApi<'client> {
             params.insert("testmode", t.to_string());
         }
 
-        self.api_client.get(&endpoint, Some(params)).await
+        self.api_client.get_page(endpoint, Some(params)).await
     }
 
     /// [Create Payment](https://docs.mollie.com/reference/v2/payments-api/create-payment)
@@ -62,7 +66,17 @@ impl<'client> PaymentsApi<'client> {
     ) -> crate::Result<PaymentResource> {
         let endpoint = "/payments";
 
-        self.api_client.post(&endpoint, body).await
+        self.api_client.post(endpoint, body).await
+    }
+
+    /// [Update Payment](https://docs.mollie.com/reference/v2/payments-api/update-payment)
+    pub async fn update(
+        &self,
+        id: &str,
+        body: &UpdatePaymentRequest,
+    ) -> crate::Result<PaymentResource> {
+        let endpoint = format!("/payments/{}", id);
+        self.api_client.patch(&endpoint, body).await
     }
 
     /// [Cancel Payment](https://docs.mollie.com/reference/v2/payments-api/cancel-payment)
@@ -70,4 +84,13 @@ impl<'client> PaymentsApi<'client> {
         let endpoint = format!("/payments/{}", id);
         self.api_client.delete(&endpoint, None).await
     }
+
+    /// [Release authorization](https://docs.mollie.com/reference/v2/payments-api/release-authorization),
+    /// cancels the part of an authorized payment that wasn't captured
+    pub async fn release_authorization(&self, id: &str) -> crate::Result<()> {
+        let endpoint = format!("/payments/{}/release-authorization", id);
+        self.api_client
+            .post(&endpoint, &serde_json::json!({}))
+            .await
+    }
 }
@@ -5,13 +5,14 @@ use crate::{models::permission::PermissionsListResource, ApiClient};
 
 /// [Permissions Api](https://docs.mollie.com/reference/v2/permissions-api/overview)
 /// Used to retrieve information about an organization.
-pub struct PermissionsApi<'client> {
-    api_client: &'client ApiClient<'client>,
+#[derive(Debug, Clone)]
+pub struct PermissionsApi {
+    api_client: ApiClient,
 }
 
-impl<'client> PermissionsApi<'client> {
+impl PermissionsApi {
     /// Create a new `PermissionsApi`.
-    pub fn new(api_client: &'client ApiClient) -> Self {
+    pub fn new(api_client: ApiClient) -> Self {
         Self { api_client }
     }
 
@@ -0,0 +1,123 @@
+//! Profiles API module
+//!
+//! Used to manage the website profiles of an organization and the payment methods enabled on them
+use super::list_params;
+use crate::{
+    models::{
+        list::Page,
+        method::{MethodResource, PaymentMethod},
+        profile::{
+            CreateProfileRequest, GiftCardIssuerResource, ProfileResource, UpdateProfileRequest,
+        },
+    },
+    ApiClient,
+};
+
+/// [Profiles Api](https://docs.mollie.com/reference/v2/profiles-api/overview)
+/// Used to create and manage profiles, and to enable payment methods on them.
+///
+/// Profile ids can be replaced with `me` to use the profile of the API key.
+#[derive(Debug, Clone)]
+pub struct ProfilesApi {
+    api_client: ApiClient,
+}
+
+impl ProfilesApi {
+    /// Create a new `ProfilesApi`.
+    pub fn new(api_client: ApiClient) -> Self {
+        Self { api_client }
+    }
+
+    /// [Create profile](https://docs.mollie.com/reference/v2/profiles-api/create-profile)
+    pub async fn create(&self, body: &CreateProfileRequest) -> crate::Result<ProfileResource> {
+        self.api_client.post("/profiles", body).await
+    }
+
+    /// [Get profile](https://docs.mollie.com/reference/v2/profiles-api/get-profile)
+    pub async fn get_by_id(&self, id: &str) -> crate::Result<ProfileResource> {
+        let endpoint = format!("/profiles/{}", id);
+        self.api_client.get(&endpoint, None).await
+    }
+
+    /// [Get current profile](https://docs.mollie.com/reference/v2/profiles-api/get-profile-me),
+    /// the profile the API key belongs to
+    pub async fn current(&self) -> crate::Result<ProfileResource> {
+        self.get_by_id("me").await
+    }
+
+    /// [Update profile](https://docs.mollie.com/reference/v2/profiles-api/update-profile)
+    pub async fn update(
+        &self,
+        id: &str,
+        body: &UpdateProfileRequest,
+    ) -> crate::Result<ProfileResource> {
+        let endpoint = format!("/profiles/{}", id);
+        self.api_client.patch(&endpoint, body).await
+    }
+
+    /// [Delete profile](https://docs.mollie.com/reference/v2/profiles-api/delete-profile)
+    pub async fn delete(&self, id: &str) -> crate::Result<()> {
+        let endpoint = format!("/profiles/{}", id);
+        self.api_client.delete(&endpoint, None).await
+    }
+
+    /// [List profiles](https://docs.mollie.com/reference/v2/profiles-api/list-profiles)
+    pub async fn list(
+        &self,
+        limit: Option<i32>,
+        from: &Option<String>,
+    ) -> crate::Result<Page<ProfileResource>> {
+        let params = list_params(limit, from, None);
+        self.api_client.get_page("/profiles", Some(params)).await
+    }
+
+    /// [Enable payment method](https://docs.mollie.com/reference/v2/profiles-api/enable-method)
+    pub async fn enable_method(
+        &self,
+        profile_id: &str,
+        method: &PaymentMethod,
+    ) -> crate::Result<MethodResource> {
+        let endpoint = format!("/profiles/{}/methods/{}", profile_id, method);
+        self.api_client
+            .post(&endpoint, &serde_json::json!({}))
+            .await
+    }
+
+    /// [Disable payment method](https://docs.mollie.com/reference/v2/profiles-api/disable-method)
+    pub async fn disable_method(
+        &self,
+        profile_id: &str,
+        method: &PaymentMethod,
+    ) -> crate::Result<()> {
+        let endpoint = format!("/profiles/{}/methods/{}", profile_id, method);
+        self.api_client.delete(&endpoint, None).await
+    }
+
+    /// [Enable gift card issuer](https://docs.mollie.com/reference/v2/profiles-api/enable-gift-card-issuer)
+    pub async fn enable_gift_card_issuer(
+        &self,
+        profile_id: &str,
+        issuer_id: &str,
+    ) -> crate::Result<GiftCardIssuerResource> {
+        let endpoint = format!(
+            "/profiles/{}/methods/giftcard/issuers/{}",
+            profile_id, issuer_id
+        );
+        self.api_client
+            .post(&endpoint, &serde_json::json!({}))
+            .await
+    }
+
+    /// [Disable gift card issuer](https://docs.mollie.com/reference/v2/profiles-api/disable-gift-card-issuer)
+    pub async fn disable_gift_card_issuer(
+        &self,
+        profile_id: &str,
+        issuer_id: &str,
+    ) -> crate::Result<()> {
+        let endpoint = format!(
+            "/profiles/{}/methods/giftcard/issuers/{}",
+            profile_id, issuer_id
+        );
+        self.api_client.delete(&endpoint, None).await
+    }
+}
@@ -1,23 +1,29 @@
 //! Refunds API module
 //!
-//! Used to refund payments
+//! Used to refund payments and to retrieve or cancel refunds
+use super::{list_params, test_mode_params};
 use crate::{
-    models::refund::{RefundPaymentRequest, RefundResource},
+    models::{
+        list::Page,
+        refund::{RefundPaymentRequest, RefundResource},
+    },
     ApiClient,
 };
 
-/// [Refunds Api](https://docs.mollie.com/reference/v2/organizations-api/overview)
-/// Used to retrieve information about an organization.
-pub struct RefundsApi<'client> {
-    api_client: &'client ApiClient<'client>,
+/// [Refunds Api](https://docs.mollie.com/reference/v2/refunds-api/overview)
+/// Used to refund payments and to retrieve or cancel refunds.
+#[derive(Debug, Clone)]
+pub struct RefundsApi {
+    api_client: ApiClient,
 }
 
-impl<'client> RefundsApi<'client> {
-    /// Create a new `OrganizationsApi`.
-    pub fn new(api_client: &'client ApiClient) -> Self {
+impl RefundsApi {
+    /// Create a new `RefundsApi`.
+    pub fn new(api_client: ApiClient) -> Self {
         Self { api_client }
     }
 
+    /// [Create payment refund](https://docs.mollie.com/reference/v2/refunds-api/create-payment-refund)
     pub async fn refund(
         &self,
         id: &str,
@@ -26,6 +32,64 @@ impl<'client> RefundsApi<'client> {
         let endpoint = format!("/payments/{}/refunds", id);
         let x = self.api_client.post(&endpoint, body).await;
         log::debug!("{:?}", x);
-        return x;
+        x
+    }
+
+    /// [Get payment refund](https://docs.mollie.com/reference/v2/refunds-api/get-payment-refund)
+    pub async fn get_by_id(
+        &self,
+        payment_id: &str,
+        refund_id: &str,
+        test_mode: Option<bool>,
+    ) -> crate::Result<RefundResource> {
+        let endpoint = format!("/payments/{}/refunds/{}", payment_id, refund_id);
+        self.api_client
+            .get(&endpoint, test_mode_params(test_mode))
+            .await
+    }
+
+    /// [Cancel payment refund](https://docs.mollie.com/reference/v2/refunds-api/cancel-payment-refund),
+    /// only refunds that are `queued` or `pending` can be canceled.
+    pub async fn cancel(
+        &self,
+        payment_id: &str,
+        refund_id: &str,
+        test_mode: Option<bool>,
+    ) -> crate::Result<()> {
+        let endpoint = format!("/payments/{}/refunds/{}", payment_id, refund_id);
+        self.api_client
+            .delete(&endpoint, test_mode_params(test_mode))
+            .await
+    }
+
+    /// [List all refunds](https://docs.mollie.com/reference/v2/refunds-api/list-all-refunds)
+    /// of the organization
+    pub async fn list(
+        &self,
+        limit: Option<i32>,
+        from: &Option<String>,
+        profile_id: &Option<String>,
+        test_mode: Option<bool>,
+    ) -> crate::Result<Page<RefundResource>> {
+        let mut params = list_params(limit, from, test_mode);
+        if let Some(p) = profile_id {
+            params.insert("profileId", p.to_string());
+        }
+
+        self.api_client.get_page("/refunds", Some(params)).await
+    }
+
+    /// [List payment refunds](https://docs.mollie.com/reference/v2/refunds-api/list-payment-refunds)
+    pub async fn list_for_payment(
+        &self,
+        payment_id: &str,
+        limit: Option<i32>,
+        from: &Option<String>,
+        test_mode: Option<bool>,
+    ) -> crate::Result<Page<RefundResource>> {
+        let endpoint = format!("/payments/{}/refunds", payment_id);
+        let params = list_params(limit, from, test_mode);
+
+        self.api_client.get_page(&endpoint, Some(params)).await
     }
 }
@@ -0,0 +1,102 @@
+//! Settlements API module
+//!
+//! Used to find out which payments, refunds, chargebacks and captures were paid out together
+use super::list_params;
+use crate::{
+    models::{
+        capture::CaptureResource, chargeback::ChargebackResource, list::Page,
+        payment::PaymentResource, refund::RefundResource, settlement::SettlementResource,
+    },
+    ApiClient,
+};
+
+/// [Settlements Api](https://docs.mollie.com/reference/v2/settlements-api/overview)
+/// Used to retrieve the payouts of an organization and what they consist of.
+#[derive(Debug, Clone)]
+pub struct SettlementsApi {
+    api_client: ApiClient,
+}
+
+impl SettlementsApi {
+    /// Create a new `SettlementsApi`.
+    pub fn new(api_client: ApiClient) -> Self {
+        Self { api_client }
+    }
+
+    /// [Get settlement](https://docs.mollie.com/reference/v2/settlements-api/get-settlement),
+    /// by id or by the reference on the bank statement
+    pub async fn get_by_id(&self, id: &str) -> crate::Result<SettlementResource> {
+        let endpoint = format!("/settlements/{}", id);
+        self.api_client.get(&endpoint, None).await
+    }
+
+    /// [Get next settlement](https://docs.mollie.com/reference/v2/settlements-api/get-next-settlement),
+    /// the one that will be paid out next
+    pub async fn next(&self) -> crate::Result<SettlementResource> {
+        self.get_by_id("next").await
+    }
+
+    /// [Get open settlement](https://docs.mollie.com/reference/v2/settlements-api/get-open-settlement),
+    /// the one new payments are added to
+    pub async fn open(&self) -> crate::Result<SettlementResource> {
+        self.get_by_id("open").await
+    }
+
+    /// [List settlements](https://docs.mollie.com/reference/v2/settlements-api/list-settlements)
+    pub async fn list(
+        &self,
+        limit: Option<i32>,
+        from: &Option<String>,
+    ) -> crate::Result<Page<SettlementResource>> {
+        let params = list_params(limit, from, None);
+        self.api_client.get_page("/settlements", Some(params)).await
+    }
+
+    /// [List settlement payments](https://docs.mollie.com/reference/v2/settlements-api/list-settlement-payments)
+    pub async fn list_payments(
+        &self,
+        settlement_id: &str,
+        limit: Option<i32>,
+        from: &Option<String>,
+    ) -> crate::Result<Page<PaymentResource>> {
+        let endpoint = format!("/settlements/{}/payments", settlement_id);
+        let params = list_params(limit, from, None);
+        self.api_client.get_page(&endpoint, Some(params)).await
+    }
+
+    /// [List settlement refunds](https://docs.mollie.com/reference/v2/settlements-api/list-settlement-refunds)
+    pub async fn list_refunds(
+        &self,
+        settlement_id: &str,
+        limit: Option<i32>,
+        from: &Option<String>,
+    ) -> crate::Result<Page<RefundResource>> {
+        let endpoint = format!("/settlements/{}/refunds", settlement_id);
+        let params = list_params(limit, from, None);
+        self.api_client.get_page(&endpoint, Some(params)).await
+    }
+
+    /// [List settlement chargebacks](https://docs.mollie.com/reference/v2/settlements-api/list-settlement-chargebacks)
+    pub async fn list_chargebacks(
+        &self,
+        settlement_id: &str,
+        limit: Option<i32>,
+        from: &Option<String>,
+    ) -> crate::Result<Page<ChargebackResource>> {
+        let endpoint = format!("/settlements/{}/chargebacks", settlement_id);
+        let params = list_params(limit, from, None);
+        self.api_client.get_page(&endpoint, Some(params)).await
+    }
+
+    /// [List settlement captures](https://docs.mollie.com/reference/v2/settlements-api/list-settlement-captures)
+    pub async fn list_captures(
+        &self,
+        settlement_id: &str,
+        limit: Option<i32>,
+        from: &Option<String>,
+    ) -> crate::Result<Page<CaptureResource>> {
+        let endpoint = format!("/settlements/{}/captures", settlement_id);
+        let params = list_params(limit, from, None);
+        self.api_client.get_page(&endpoint, Some(params)).await
+    }
+}
@@ -0,0 +1,69 @@
+//! Shipments API module
+//!
+//! Used to ship the lines of an order, which captures the authorized amount of those lines
+use std::collections::HashMap;
+
+use crate::{
+    models::shipment::{
+        CreateShipmentRequest, ShipmentResource, ShipmentsListResource, UpdateShipmentRequest,
+    },
+    ApiClient,
+};
+
+/// [Shipments Api](https://docs.mollie.com/reference/v2/shipments-api/overview)
+/// Used to create, retrieve and update the shipments of an order.
+#[derive(Debug, Clone)]
+pub struct ShipmentsApi {
+    api_client: ApiClient,
+}
+
+impl ShipmentsApi {
+    /// Create a new `ShipmentsApi`.
+    pub fn new(api_client: ApiClient) -> Self {
+        Self { api_client }
+    }
+
+    /// [Create shipment](https://docs.mollie.com/reference/v2/shipments-api/create-shipment)
+    pub async fn create(
+        &self,
+        order_id: &str,
+        body: &CreateShipmentRequest,
+    ) -> crate::Result<ShipmentResource> {
+        let endpoint = format!("/orders/{}/shipments", order_id);
+        self.api_client.post(&endpoint, body).await
+    }
+
+    /// [Get shipment](https://docs.mollie.com/reference/v2/shipments-api/get-shipment)
+    pub async fn get_by_id(
+        &self,
+        order_id: &str,
+        shipment_id: &str,
+    ) -> crate::Result<ShipmentResource> {
+        let endpoint = format!("/orders/{}/shipments/{}", order_id, shipment_id);
+        self.api_client.get(&endpoint, None).await
+    }
+
+    /// [List shipments](https://docs.mollie.com/reference/v2/shipments-api/list-shipments)
+    /// of an order, this list is not paginated
+    pub async fn list(
+        &self,
+        order_id: &str,
+        test_mode: Option<bool>,
+    ) -> crate::Result<ShipmentsListResource> {
+        let endpoint = format!("/orders/{}/shipments", order_id);
+        let params = test_mode.map(|t| HashMap::from([("testmode", t.to_string())]));
+        self.api_client.get(&endpoint, params).await
+    }
+
+    /// [Update shipment](https://docs.mollie.com/reference/v2/shipments-api/update-shipment),
+    /// to add or change its tracking information
+    pub async fn update(
+        &self,
+        order_id: &str,
+        shipment_id: &str,
+        body: &UpdateShipmentRequest,
+    ) -> crate::Result<ShipmentResource> {
+        let endpoint = format!("/orders/{}/shipments/{}", order_id, shipment_id);
+        self.api_client.patch(&endpoint, body).await
+    }
+}
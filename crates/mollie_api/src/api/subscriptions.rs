@@ -0,0 +1,134 @@
+//! Subscriptions API module
+//!
+//! Used to charge customers on a regular interval
+use super::{list_params, test_mode_params};
+use crate::{
+    models::{
+        list::Page,
+        payment::PaymentResource,
+        subscription::{
+            CreateSubscriptionRequest, SubscriptionResource, UpdateSubscriptionRequest,
+        },
+    },
+    ApiClient,
+};
+
+/// [Subscriptions Api](https://docs.mollie.com/reference/v2/subscriptions-api/overview)
+/// Used to create and manage the subscriptions of a customer.
+#[derive(Debug, Clone)]
+pub struct SubscriptionsApi {
+    api_client: ApiClient,
+}
+
+impl SubscriptionsApi {
+    /// Create a new `SubscriptionsApi`.
+    pub fn new(api_client: ApiClient) -> Self {
+        Self { api_client }
+    }
+
+    /// [Create subscription](https://docs.mollie.com/reference/v2/subscriptions-api/create-subscription)
+    pub async fn create(
+        &self,
+        customer_id: &str,
+        body: &CreateSubscriptionRequest,
+    ) -> crate::Result<SubscriptionResource> {
+        let endpoint = format!("/customers/{}/subscriptions", customer_id);
+        self.api_client.post(&endpoint, body).await
+    }
+
+    /// [Get subscription](https://docs.mollie.com/reference/v2/subscriptions-api/get-subscription)
+    pub async fn get_by_id(
+        &self,
+        customer_id: &str,
+        subscription_id: &str,
+        test_mode: Option<bool>,
+    ) -> crate::Result<SubscriptionResource> {
+        let endpoint = format!(
+            "/customers/{}/subscriptions/{}",
+            customer_id, subscription_id
+        );
+        self.api_client
+            .get(&endpoint, test_mode_params(test_mode))
+            .await
+    }
+
+    /// [Update subscription](https://docs.mollie.com/reference/v2/subscriptions-api/update-subscription)
+    pub async fn update(
+        &self,
+        customer_id: &str,
+        subscription_id: &str,
+        body: &UpdateSubscriptionRequest,
+    ) -> crate::Result<SubscriptionResource> {
+        let endpoint = format!(
+            "/customers/{}/subscriptions/{}",
+            customer_id, subscription_id
+        );
+        self.api_client.patch(&endpoint, body).await
+    }
+
+    /// [Cancel subscription](https://docs.mollie.com/reference/v2/subscriptions-api/cancel-subscription)
+    pub async fn cancel(
+        &self,
+        customer_id: &str,
+        subscription_id: &str,
+        test_mode: Option<bool>,
+    ) -> crate::Result<SubscriptionResource> {
+        let endpoint = format!(
+            "/customers/{}/subscriptions/{}",
+            customer_id, subscription_id
+        );
+        self.api_client
+            .delete(&endpoint, test_mode_params(test_mode))
+            .await
+    }
+
+    /// [List subscriptions](https://docs.mollie.com/reference/v2/subscriptions-api/list-subscriptions)
+    /// of a customer
+    pub async fn list(
+        &self,
+        customer_id: &str,
+        limit: Option<i32>,
+        from: &Option<String>,
+        test_mode: Option<bool>,
+    ) -> crate::Result<Page<SubscriptionResource>> {
+        let endpoint = format!("/customers/{}/subscriptions", customer_id);
+        let params = list_params(limit, from, test_mode);
+        self.api_client.get_page(&endpoint, Some(params)).await
+    }
+
+    /// [List all subscriptions](https://docs.mollie.com/reference/v2/subscriptions-api/list-all-subscriptions)
+    /// of the organization
+    pub async fn list_all(
+        &self,
+        limit: Option<i32>,
+        from: &Option<String>,
+        profile_id: &Option<String>,
+        test_mode: Option<bool>,
+    ) -> crate::Result<Page<SubscriptionResource>> {
+        let mut params = list_params(limit, from, test_mode);
+        if let Some(p) = profile_id {
+            params.insert("profileId", p.to_string());
+        }
+
+        self.api_client
+            .get_page("/subscriptions", Some(params))
+            .await
+    }
+
+    /// [List subscription payments](https://docs.mollie.com/reference/v2/subscriptions-api/list-subscription-payments)
+    pub async fn list_payments(
+        &self,
+        customer_id: &str,
+        subscription_id: &str,
+        limit: Option<i32>,
+        from: &Option<String>,
+        test_mode: Option<bool>,
+    ) -> crate::Result<Page<PaymentResource>> {
+        let endpoint = format!(
+            "/customers/{}/subscriptions/{}/payments",
+            customer_id, subscription_id
+        );
+        let params = list_params(limit, from, test_mode);
+        self.api_client.get_page(&endpoint, Some(params)).await
+    }
+}
@@ -20,9 +20,9 @@ impl TryFrom<String> for AccessCode {
     }
 }
 
-impl Into<String> for AccessCode {
-    fn into(self) -> String {
-        self.value
+impl From<AccessCode> for String {
+    fn from(val: AccessCode) -> Self {
+        val.value
     }
 }
 
@@ -51,9 +51,9 @@ impl TryFrom<String> for ApiKey {
     }
 }
 
-impl Into<String> for ApiKey {
-    fn into(self) -> String {
-        self.value
+impl From<ApiKey> for String {
+    fn from(val: ApiKey) -> Self {
+        val.value
     }
 }
 
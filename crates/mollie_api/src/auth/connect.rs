@@ -19,8 +19,8 @@ impl TryFrom<String> for ConnectToken {
     }
 }
 
-impl Into<String> for ConnectToken {
-    fn into(self) -> String {
-        self.value
+impl From<ConnectToken> for String {
+    fn from(val: ConnectToken) -> Self {
+        val.value
     }
 }
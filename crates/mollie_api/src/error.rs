@@ -1,4 +1,9 @@
+use std::{fmt, time::Duration};
+
 use miette::Diagnostic;
+use reqwest::{header::HeaderMap, StatusCode};
+
+use crate::{models::error_response::ErrorResponse, retry::retry_after};
 
 #[derive(thiserror::Error, Debug, Diagnostic)]
 pub enum Error {
@@ -23,16 +28,385 @@ pub enum Error {
     )]
     InvalidAccessToken,
 
+    #[error("Invalid amount: {0}")]
+    #[diagnostic(
+        code("api::amount"),
+        help("Amounts should be a decimal number using a dot as decimal separator, e.g. 10.50")
+    )]
+    InvalidAmount(String),
+
+    #[error("Unsupported currency: {0}")]
+    #[diagnostic(
+        code("api::amount::currency"),
+        help("Currencies should be a 3 letter ISO 4217 currency code, e.g. EUR")
+    )]
+    UnsupportedCurrency(String),
+
+    #[error("Invalid amount {value}: {currency} amounts can have at most {precision} decimals")]
+    #[diagnostic(
+        code("api::amount::precision"),
+        help("Make sure the amount uses the number of decimals of its currency")
+    )]
+    InvalidAmountPrecision {
+        value: String,
+        currency: String,
+        precision: u32,
+    },
+
+    #[error("Currency mismatch: expected an amount in {expected}, got one in {actual}")]
+    #[diagnostic(
+        code("api::amount::currency_mismatch"),
+        help(
+            "Amounts can only be added, subtracted or compared when they are in the same currency"
+        )
+    )]
+    CurrencyMismatch { expected: String, actual: String },
+
+    #[error("Invalid interval: {0}")]
+    #[diagnostic(
+        code("api::interval"),
+        help(
+            "Intervals are a number followed by days, weeks or months, e.g. '1 month' or '14 days'"
+        )
+    )]
+    InvalidInterval(String),
+
+    #[error("Invalid VAT rate: {0}")]
+    #[diagnostic(
+        code("api::vat_rate"),
+        help("VAT rates are a percentage using a dot as decimal separator, e.g. 21.00")
+    )]
+    InvalidVatRate(String),
+
+    #[error("An order needs at least one line")]
+    #[diagnostic(
+        code("api::order::lines"),
+        help("Add the products, shipping fees and discounts of the order as lines")
+    )]
+    NoOrderLines,
+
+    #[error("Payment {id} can't be captured or released (status {status})")]
+    #[diagnostic(
+        code("api::payment::not_capturable"),
+        help("Only authorized payments with a remaining authorized amount can be captured or released")
+    )]
+    NotCapturable { id: String, status: String },
+
+    #[error("Can't capture {amount}, the remaining authorized amount is {remaining}")]
+    #[diagnostic(
+        code("api::capture::amount"),
+        help("Capture an amount higher than zero and at most the remaining authorized amount")
+    )]
+    InvalidCaptureAmount { amount: String, remaining: String },
+
     #[error("Error while performing request: {0}")]
     #[diagnostic(code("api::request"), help("Please try again later"))]
     CouldNotPerformRequest(#[from] reqwest::Error),
 
-    #[error("Mollie API Error {status}: {title} - {detail}. {raw_response}")]
-    #[diagnostic(code("api::mollie_error"), help("Please try again later"))]
+    #[error("Unauthorized: {detail}")]
+    #[diagnostic(
+        code("api::unauthorized"),
+        help(
+            "Make sure your API key or access token is valid and has not expired.{documentation}"
+        )
+    )]
+    Unauthorized {
+        detail: String,
+        documentation: DocumentationLink,
+    },
+
+    #[error("Forbidden: {detail}")]
+    #[diagnostic(
+        code("api::forbidden"),
+        help("Your access token isn't allowed to perform this request, make sure it was granted the required permissions.{documentation}")
+    )]
+    Forbidden {
+        /// Permission (e.g. `payments.read`) the access token is missing, if Mollie mentioned it
+        missing_permission: Option<String>,
+        detail: String,
+        documentation: DocumentationLink,
+    },
+
+    #[error("Not found: {detail}")]
+    #[diagnostic(
+        code("api::not_found"),
+        help("Make sure the id is correct and the resource belongs to the mode (live or test) of your credentials.{documentation}")
+    )]
+    NotFound {
+        detail: String,
+        documentation: DocumentationLink,
+    },
+
+    #[error("Invalid request{}: {detail}", field.as_ref().map(|field| format!(" (field {})", field)).unwrap_or_default())]
+    #[diagnostic(
+        code("api::validation"),
+        help("Fix the request and try again.{documentation}")
+    )]
+    Validation {
+        /// Field of the request that is invalid, if Mollie returned it
+        field: Option<String>,
+        detail: String,
+        documentation: DocumentationLink,
+    },
+
+    #[error("Rate limited: {detail}")]
+    #[diagnostic(
+        code("api::rate_limited"),
+        help("Too many requests were sent to the Mollie API, wait a moment before trying again.{documentation}")
+    )]
+    RateLimited {
+        /// Delay Mollie asked to wait before sending another request
+        retry_after: Option<Duration>,
+        detail: String,
+        documentation: DocumentationLink,
+    },
+
+    #[error("Mollie API server error {status}: {detail}")]
+    #[diagnostic(
+        code("api::server"),
+        help("Something went wrong on Mollie's side, please try again later.{documentation}")
+    )]
+    Server {
+        status: u16,
+        detail: String,
+        documentation: DocumentationLink,
+    },
+
+    #[error("Mollie API Error {status}: {title} - {detail}")]
+    #[diagnostic(
+        code("api::mollie_error"),
+        help("Please try again later.{documentation}")
+    )]
     ApiError {
         status: u16,
         title: String,
         detail: String,
-        raw_response: String,
+        documentation: DocumentationLink,
+    },
+
+    #[error("Unexpected response body (status {status}): {snippet}")]
+    #[diagnostic(
+        code("api::unexpected_body"),
+        help("The response couldn't be read, the API might be unavailable or behind a proxy returning errors of its own.")
+    )]
+    UnexpectedBody {
+        status: u16,
+        /// Start of the response body
+        snippet: String,
+        #[source]
+        source: serde_json::Error,
     },
 }
+
+/// Maximum number of characters of a response body kept in an `UnexpectedBody` error
+const SNIPPET_LENGTH: usize = 200;
+
+impl Error {
+    /// Map an unsuccessful response of the Mollie API to the matching error
+    pub(crate) fn from_response(status: StatusCode, headers: &HeaderMap, body: &str) -> Self {
+        let response = match serde_json::from_str::<ErrorResponse>(body) {
+            Ok(response) => response,
+            Err(e) => return Error::unexpected_body(status, body, e),
+        };
+
+        let documentation = DocumentationLink(response.documentation().map(String::from));
+        let detail = response.detail;
+
+        match status {
+            StatusCode::UNAUTHORIZED => Error::Unauthorized {
+                detail,
+                documentation,
+            },
+            StatusCode::FORBIDDEN => Error::Forbidden {
+                missing_permission: missing_permission(&detail),
+                detail,
+                documentation,
+            },
+            StatusCode::NOT_FOUND | StatusCode::GONE => Error::NotFound {
+                detail,
+                documentation,
+            },
+            StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => Error::Validation {
+                field: response.field,
+                detail,
+                documentation,
+            },
+            StatusCode::TOO_MANY_REQUESTS => Error::RateLimited {
+                retry_after: retry_after(headers),
+                detail,
+                documentation,
+            },
+            status if status.is_server_error() => Error::Server {
+                status: status.as_u16(),
+                detail,
+                documentation,
+            },
+            status => Error::ApiError {
+                status: status.as_u16(),
+                title: response.title,
+                detail,
+                documentation,
+            },
+        }
+    }
+
+    /// Error for a response body that isn't the JSON that was expected
+    pub(crate) fn unexpected_body(
+        status: StatusCode,
+        body: &str,
+        source: serde_json::Error,
+    ) -> Self {
+        let body = body.split_whitespace().collect::<Vec<_>>().join(" ");
+        let mut snippet: String = body.chars().take(SNIPPET_LENGTH).collect();
+        if body.chars().count() > SNIPPET_LENGTH {
+            snippet.push_str("...");
+        }
+
+        Error::UnexpectedBody {
+            status: status.as_u16(),
+            snippet,
+            source,
+        }
+    }
+}
+
+/// Find the permission (e.g. `payments.read`) mentioned in the detail of a 403 response
+fn missing_permission(detail: &str) -> Option<String> {
+    detail
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_'))
+        .map(|word| word.trim_end_matches('.'))
+        .find(|word| word.ends_with(".read") || word.ends_with(".write"))
+        .map(String::from)
+}
+
+/// Link to the documentation of an error, rendered at the end of the help text
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocumentationLink(pub Option<String>);
+
+impl fmt::Display for DocumentationLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(href) => write!(f, "\nDocumentation: {}", href),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use reqwest::header::{HeaderValue, RETRY_AFTER};
+
+    fn error_body(status: u16, title: &str, detail: &str, field: Option<&str>) -> String {
+        serde_json::json!({
+            "status": status,
+            "title": title,
+            "detail": detail,
+            "field": field,
+            "_links": {
+                "documentation": {
+                    "href": "https://docs.mollie.com/overview/handling-errors",
+                    "type": "text/html"
+                }
+            }
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn should_map_validation_errors_with_field() {
+        let body = error_body(
+            422,
+            "Unprocessable Entity",
+            "The amount is higher than the maximum",
+            Some("amount"),
+        );
+
+        let error =
+            Error::from_response(StatusCode::UNPROCESSABLE_ENTITY, &HeaderMap::new(), &body);
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid request (field amount): The amount is higher than the maximum"
+        );
+        assert!(matches!(error, Error::Validation { field: Some(ref f), .. } if f == "amount"));
+        assert!(error
+            .help()
+            .unwrap()
+            .to_string()
+            .ends_with("Documentation: https://docs.mollie.com/overview/handling-errors"));
+    }
+
+    #[test]
+    fn should_map_auth_errors() {
+        let unauthorized = Error::from_response(
+            StatusCode::UNAUTHORIZED,
+            &HeaderMap::new(),
+            &error_body(
+                401,
+                "Unauthorized Request",
+                "Missing authentication, or failed to authenticate",
+                None,
+            ),
+        );
+        let forbidden = Error::from_response(
+            StatusCode::FORBIDDEN,
+            &HeaderMap::new(),
+            &error_body(
+                403,
+                "Forbidden",
+                "The access token is missing the payments.write permission.",
+                None,
+            ),
+        );
+
+        assert!(matches!(unauthorized, Error::Unauthorized { .. }));
+        assert!(matches!(
+            forbidden,
+            Error::Forbidden { missing_permission: Some(ref p), .. } if p == "payments.write"
+        ));
+    }
+
+    #[test]
+    fn should_map_rate_limit_and_server_errors() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("30"));
+
+        let rate_limited = Error::from_response(
+            StatusCode::TOO_MANY_REQUESTS,
+            &headers,
+            &error_body(429, "Too Many Requests", "Slow down", None),
+        );
+        let server = Error::from_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            &HeaderMap::new(),
+            &error_body(503, "Service Unavailable", "Down for maintenance", None),
+        );
+
+        assert!(matches!(
+            rate_limited,
+            Error::RateLimited { retry_after: Some(d), .. } if d == Duration::from_secs(30)
+        ));
+        assert!(matches!(server, Error::Server { status: 503, .. }));
+    }
+
+    #[test]
+    fn should_keep_a_snippet_of_non_json_bodies() {
+        let body = format!(
+            "<html>\n  <h1>502 Bad Gateway</h1>{}</html>",
+            " ".repeat(500)
+        );
+
+        let error = Error::from_response(StatusCode::BAD_GATEWAY, &HeaderMap::new(), &body);
+
+        match error {
+            Error::UnexpectedBody {
+                status, snippet, ..
+            } => {
+                assert_eq!(status, 502);
+                assert_eq!(snippet, "<html> <h1>502 Bad Gateway</h1> </html>");
+            }
+            e => panic!("Expected an unexpected body error, got {:?}", e),
+        }
+    }
+}
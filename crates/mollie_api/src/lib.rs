@@ -6,12 +6,17 @@
 
 #![doc(html_logo_url = "https://github.com/mollie.png")]
 
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
-use api::{balances, organizations, payments, permissions, refunds};
+use api::{
+    balances, captures, chargebacks, customers, invoices, mandates, methods, onboarding, orders,
+    organizations, payment_links, payments, permissions, profiles, refunds, settlements, shipments,
+    subscriptions,
+};
 use log::{debug, error};
-use models::error_response::ErrorResponse;
-use reqwest::{header::HeaderMap, Client};
+use models::list::{ListResource, Page};
+use reqwest::{header::HeaderMap, Client, RequestBuilder, Url};
+use retry::RetryPolicy;
 use serde::{Deserialize, Serialize};
 
 use crate::error::Error;
@@ -20,6 +25,7 @@ pub mod api;
 pub mod auth;
 pub mod error;
 pub mod models;
+pub mod retry;
 
 /// Result type used throughout the crate
 /// Errors are of type `mollie_api::errors::Error`
@@ -38,21 +44,31 @@ lazy_static::lazy_static! {
     );
 }
 
+/// Http client shared by all the resource APIs.
+///
+/// The client owns its configuration and is cheap to clone: the underlying
+/// connection pool and the auth token are reference counted.
 #[derive(Debug, Clone)]
-pub struct ApiClient<'a> {
+pub struct ApiClient {
     /// Async client
     client: Client,
 
     /// Api base url
-    base_url: &'a str,
+    base_url: Url,
 
     /// Auth token
-    auth_token: &'a str,
+    auth_token: Arc<str>,
+
+    /// Policy used to retry requests that failed because of a transient error
+    retry_policy: RetryPolicy,
+
+    /// Idempotency key sent with post, patch and delete requests, generated when not set
+    idempotency_key: Option<Arc<str>>,
 }
 
-impl<'a> ApiClient<'a> {
+impl ApiClient {
     /// Create a new api (async) client instance.
-    pub fn new(base_url: &'static str, auth_token: &'a str) -> Self {
+    pub fn new(base_url: Url, auth_token: Arc<str>, retry_policy: RetryPolicy) -> Self {
         let client = Client::builder()
             .default_headers(ApiClient::default_headers())
             .build()
@@ -62,6 +78,8 @@ impl<'a> ApiClient<'a> {
             client,
             base_url,
             auth_token,
+            retry_policy,
+            idempotency_key: None,
         }
     }
 
@@ -76,23 +94,100 @@ impl<'a> ApiClient<'a> {
     }
 
     fn build_url(&self, endpoint: &str) -> String {
-        format!("{}/{}", self.base_url, endpoint.trim_start_matches('/'))
+        format!(
+            "{}/{}",
+            self.base_url.as_str().trim_end_matches('/'),
+            endpoint.trim_start_matches('/')
+        )
+    }
+
+    /// Idempotency key for a new request: the one supplied by the caller, or a newly generated one
+    fn idempotency_key(&self) -> String {
+        match &self.idempotency_key {
+            Some(key) => key.to_string(),
+            None => uuid::Uuid::new_v4().to_string(),
+        }
+    }
+
+    /// Send a request, retrying it according to the retry policy.
+    ///
+    /// The request is rebuilt for every attempt, so that it can be sent more than once.
+    async fn send<F>(&self, request: F) -> Result<reqwest::Response>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        let mut retry = 0;
+        loop {
+            let result = request().send().await;
+
+            let delay = match &result {
+                Ok(response) => {
+                    self.retry_policy
+                        .delay_for_status(retry, response.status(), response.headers())
+                }
+                Err(e) => self.retry_policy.delay_for_error(retry, e),
+            };
+
+            match delay {
+                Some(delay) => {
+                    debug!(
+                        "Request failed, retrying in {:?} (retry {} of {})",
+                        delay,
+                        retry + 1,
+                        self.retry_policy.max_retries
+                    );
+                    tokio::time::sleep(delay).await;
+                    retry += 1;
+                }
+                None => return Ok(result?),
+            }
+        }
     }
 
-    /// Perform a post request using default headers and auth token
+    /// Perform a post request using default headers and auth token.
+    ///
+    /// An `Idempotency-Key` header is sent along, so that retrying the request can't
+    /// create the same resource twice.
     pub async fn post<T, R>(&self, endpoint: &str, body: &T) -> Result<R>
     where
         T: Serialize,
         R: for<'de> Deserialize<'de>,
     {
         let url = self.build_url(endpoint);
+        let idempotency_key = self.idempotency_key();
 
         let response = self
-            .client
-            .post(url)
-            .bearer_auth(self.auth_token)
-            .json(&body)
-            .send()
+            .send(|| {
+                self.client
+                    .post(&url)
+                    .bearer_auth(&self.auth_token)
+                    .header("Idempotency-Key", &idempotency_key)
+                    .json(&body)
+            })
+            .await?;
+
+        self.parse_response(response).await
+    }
+
+    /// Perform a patch request using default headers and auth token.
+    ///
+    /// Like post requests, an `Idempotency-Key` header is sent along.
+    pub async fn patch<T, R>(&self, endpoint: &str, body: &T) -> Result<R>
+    where
+        T: Serialize,
+        R: for<'de> Deserialize<'de>,
+    {
+        let url = self.build_url(endpoint);
+        let idempotency_key = self.idempotency_key();
+
+        let response = self
+            .send(|| {
+                self.client
+                    .patch(&url)
+                    .bearer_auth(&self.auth_token)
+                    .header("Idempotency-Key", &idempotency_key)
+                    .json(&body)
+            })
             .await?;
 
         self.parse_response(response).await
@@ -105,95 +200,367 @@ impl<'a> ApiClient<'a> {
     {
         let url = self.build_url(endpoint);
 
-        let mut req = self.client.get(url).bearer_auth(self.auth_token);
-
-        if let Some(q) = query {
-            req = req.query(&q);
-        }
+        let response = self
+            .send(|| {
+                let mut req = self.client.get(&url).bearer_auth(&self.auth_token);
+
+                if let Some(q) = &query {
+                    req = req.query(q);
+                }
+                req
+            })
+            .await?;
 
-        let response = req.send().await?;
         self.parse_response(response).await
     }
 
-    /// Performa a delete request using default headers and auth token
+    /// Perform a get request on a list endpoint, returning a page that can be used
+    /// to navigate to the previous and next pages
+    pub async fn get_page<T>(
+        &self,
+        endpoint: &str,
+        query: Option<HashMap<&str, String>>,
+    ) -> Result<Page<T>>
+    where
+        T: for<'de> Deserialize<'de> + Send + Sync + 'static,
+    {
+        let params = query
+            .iter()
+            .flatten()
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .collect();
+
+        let resource: ListResource<T> = self.get(endpoint, query).await?;
+        Ok(Page::new(self.clone(), endpoint, params, resource))
+    }
+
+    /// Performa a delete request using default headers and auth token.
+    ///
+    /// Like post requests, an `Idempotency-Key` header is sent along, so that a retried cancel
+    /// gets the original response instead of an error about the resource being canceled already.
     pub async fn delete<R>(&self, endpoint: &str, query: Option<HashMap<&str, String>>) -> Result<R>
     where
         R: for<'de> Deserialize<'de>,
     {
         let url = self.build_url(endpoint);
-        let mut req = self.client.delete(url).bearer_auth(self.auth_token);
+        let idempotency_key = self.idempotency_key();
+
+        let response = self
+            .send(|| {
+                let mut req = self
+                    .client
+                    .delete(&url)
+                    .bearer_auth(&self.auth_token)
+                    .header("Idempotency-Key", &idempotency_key);
+
+                if let Some(q) = &query {
+                    req = req.query(q);
+                }
+                req
+            })
+            .await?;
 
-        if let Some(q) = query {
-            req = req.query(&q);
-        }
-        let response = req.send().await?;
         self.parse_response(response).await
     }
 
-    async fn parse_response<R>(&self, response: reqwest::Response) -> Result<R>
+    /// Perform a delete request with a JSON body, for endpoints that need to know what to delete
+    /// (e.g. cancelling only some lines of an order). An `Idempotency-Key` header is sent along.
+    pub async fn delete_with_body<T, R>(&self, endpoint: &str, body: &T) -> Result<R>
     where
+        T: Serialize,
         R: for<'de> Deserialize<'de>,
     {
-        if response.status().is_success() {
-            return response.json::<R>().await.map_err(|e| {
-                error!("{:?}", e);
-                // Remove the url from the error to avoid leaking sensitive information
-                // that might be present in the url
-                Error::CouldNotPerformRequest(e.without_url())
-            });
+        let url = self.build_url(endpoint);
+        let idempotency_key = self.idempotency_key();
+
+        let response = self
+            .send(|| {
+                self.client
+                    .delete(&url)
+                    .bearer_auth(&self.auth_token)
+                    .header("Idempotency-Key", &idempotency_key)
+                    .json(&body)
+            })
+            .await?;
+
+        self.parse_response(response).await
+    }
+
+    /// Download the file a link of a resource points to (e.g. the PDF of an invoice).
+    ///
+    /// The auth token is only sent to the Mollie API itself: links to other hosts are signed,
+    /// and shouldn't get to see the token.
+    pub async fn download(&self, url: &str) -> Result<Vec<u8>> {
+        let with_auth = Url::parse(url)
+            .map(|url| url.origin() == self.base_url.origin())
+            .unwrap_or(false);
+
+        let response = self
+            .send(|| {
+                let req = self.client.get(url);
+                if with_auth {
+                    req.bearer_auth(&self.auth_token)
+                } else {
+                    req
+                }
+            })
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let headers = response.headers().clone();
+            let body = response.text().await.unwrap_or_default();
+            return Err(Error::from_response(status, &headers, &body));
         }
 
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| Error::CouldNotPerformRequest(e.without_url()))?;
+        Ok(bytes.to_vec())
+    }
+
+    async fn parse_response<R>(&self, response: reqwest::Response) -> Result<R>
+    where
+        R: for<'de> Deserialize<'de>,
+    {
         let status = response.status();
-        let raw_json = response.json::<ErrorResponse>().await?;
-        Err(Error::ApiError {
-            status: status.as_u16(),
-            title: raw_json.title.to_string(),
-            detail: raw_json.detail.to_string(),
-            raw_response: raw_json.to_string(),
+        let headers = response.headers().clone();
+        let body = response.text().await.map_err(|e| {
+            error!("{:?}", e);
+            // Remove the url from the error to avoid leaking sensitive information
+            // that might be present in the url
+            Error::CouldNotPerformRequest(e.without_url())
+        })?;
+
+        if !status.is_success() {
+            return Err(Error::from_response(status, &headers, &body));
+        }
+
+        // Endpoints that don't return anything (e.g. cancelling a refund) respond with a 204
+        // and an empty body, which is read as `null` so that it can be deserialized into `()`
+        let body = if body.trim().is_empty() {
+            String::from("null")
+        } else {
+            body
+        };
+
+        serde_json::from_str(&body).map_err(|e| {
+            error!("{:?}", e);
+            Error::unexpected_body(status, &body, e)
         })
     }
 }
 
-/// Mollie API client
+/// Builder used to configure a `Mollie` instance, e.g. to point it to a
+/// different base url than the production Mollie API.
 #[derive(Debug, Clone)]
-pub struct Mollie<'c> {
-    api_client: ApiClient<'c>,
+pub struct ClientBuilder {
+    /// Auth token
+    auth_token: Arc<str>,
+
+    /// Api base url
+    base_url: Url,
+
+    /// Policy used to retry requests that failed because of a transient error
+    retry_policy: RetryPolicy,
 }
 
-impl<'c> Mollie<'c> {
-    /// Create a new Mollie instance
-    pub fn build(auth_token: &'c str) -> Self {
-        debug!("Creating new Mollie instance. Base url: {}", API_BASE_URL);
+impl ClientBuilder {
+    /// Create a new `ClientBuilder` using the default Mollie API base url.
+    pub fn new(auth_token: impl AsRef<str>) -> Self {
         Self {
-            api_client: ApiClient::new(API_BASE_URL, auth_token),
+            auth_token: Arc::from(auth_token.as_ref()),
+            base_url: Url::parse(API_BASE_URL).expect("Invalid default base url"),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Override the base url all requests are sent to (e.g. a local mock server or a proxy).
+    pub fn base_url(mut self, base_url: Url) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    /// Override the policy used to retry requests that failed because of a transient error
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Build the `Mollie` instance
+    pub fn build(self) -> Mollie {
+        debug!("Creating new Mollie instance. Base url: {}", self.base_url);
+        Mollie {
+            api_client: ApiClient::new(self.base_url, self.auth_token, self.retry_policy),
         }
     }
+}
+
+/// Mollie API client
+///
+/// `Mollie` owns its configuration and is `Send + Sync + 'static`, so it can be
+/// stored in an `Arc`, shared as application state or moved into spawned tasks.
+/// Cloning it is cheap.
+#[derive(Debug, Clone)]
+pub struct Mollie {
+    api_client: ApiClient,
+}
+
+impl Mollie {
+    /// Create a new Mollie instance pointing to the production Mollie API
+    pub fn build(auth_token: impl AsRef<str>) -> Self {
+        Self::builder(auth_token).build()
+    }
+
+    /// Create a `ClientBuilder` to configure the Mollie instance before building it
+    pub fn builder(auth_token: impl AsRef<str>) -> ClientBuilder {
+        ClientBuilder::new(auth_token)
+    }
+
+    /// Use the given idempotency key for the requests made with the returned instance,
+    /// instead of generating a new one for every request.
+    ///
+    /// Useful to safely retry creating a resource (e.g. a payment or a refund) yourself:
+    /// Mollie returns the original response when a request is repeated with the same key.
+    pub fn with_idempotency_key(&self, idempotency_key: impl AsRef<str>) -> Self {
+        let mut mollie = self.clone();
+        mollie.api_client.idempotency_key = Some(Arc::from(idempotency_key.as_ref()));
+        mollie
+    }
 
     /// Organizations API
     pub fn organizations(&self) -> organizations::OrganizationsApi {
-        organizations::OrganizationsApi::new(&self.api_client)
+        organizations::OrganizationsApi::new(self.api_client.clone())
+    }
+
+    /// Onboarding API, whether the organization can receive payments and settlements yet
+    pub fn onboarding(&self) -> onboarding::OnboardingApi {
+        onboarding::OnboardingApi::new(self.api_client.clone())
+    }
+
+    /// Methods API, payment methods that can be offered to customers
+    pub fn methods(&self) -> methods::MethodsApi {
+        methods::MethodsApi::new(self.api_client.clone())
     }
 
     pub fn payments(&self) -> payments::PaymentsApi {
-        payments::PaymentsApi::new(&self.api_client)
+        payments::PaymentsApi::new(self.api_client.clone())
+    }
+
+    /// Payment Links API, links to share with customers to pay
+    pub fn payment_links(&self) -> payment_links::PaymentLinksApi {
+        payment_links::PaymentLinksApi::new(self.api_client.clone())
+    }
+
+    /// Profiles API, the websites of the organization and their payment methods
+    pub fn profiles(&self) -> profiles::ProfilesApi {
+        profiles::ProfilesApi::new(self.api_client.clone())
     }
 
     pub fn refunds(&self) -> refunds::RefundsApi {
-        refunds::RefundsApi::new(&self.api_client)
+        refunds::RefundsApi::new(self.api_client.clone())
+    }
+
+    /// Settlements API, the payouts of the organization
+    pub fn settlements(&self) -> settlements::SettlementsApi {
+        settlements::SettlementsApi::new(self.api_client.clone())
     }
 
     pub fn balances(&self) -> balances::BalancesApi {
-        balances::BalancesApi::new(&self.api_client)
+        balances::BalancesApi::new(self.api_client.clone())
     }
 
     pub fn permissions(&self) -> permissions::PermissionsApi {
-        permissions::PermissionsApi::new(&self.api_client)
+        permissions::PermissionsApi::new(self.api_client.clone())
+    }
+
+    /// Captures API, captures of authorized payments
+    pub fn captures(&self) -> captures::CapturesApi {
+        captures::CapturesApi::new(self.api_client.clone())
+    }
+
+    /// Chargebacks API, payments disputed by customers
+    pub fn chargebacks(&self) -> chargebacks::ChargebacksApi {
+        chargebacks::ChargebacksApi::new(self.api_client.clone())
+    }
+
+    pub fn customers(&self) -> customers::CustomersApi {
+        customers::CustomersApi::new(self.api_client.clone())
+    }
+
+    /// Invoices API, the invoices of Mollie's fees
+    pub fn invoices(&self) -> invoices::InvoicesApi {
+        invoices::InvoicesApi::new(self.api_client.clone())
+    }
+
+    /// Subscriptions API, the subscriptions of a customer
+    pub fn subscriptions(&self) -> subscriptions::SubscriptionsApi {
+        subscriptions::SubscriptionsApi::new(self.api_client.clone())
+    }
+
+    /// Orders API, for pay later methods like Klarna
+    pub fn orders(&self) -> orders::OrdersApi {
+        orders::OrdersApi::new(self.api_client.clone())
+    }
+
+    /// Shipments API, the shipments of an order
+    pub fn shipments(&self) -> shipments::ShipmentsApi {
+        shipments::ShipmentsApi::new(self.api_client.clone())
+    }
+
+    /// Mandates API, the mandates of a customer
+    pub fn mandates(&self) -> mandates::MandatesApi {
+        mandates::MandatesApi::new(self.api_client.clone())
     }
 }
 
 #[cfg(test)]
 mod client_tests {
-    use super::Mollie;
+    use super::{Error, Mollie};
+    use reqwest::Url;
+
+    fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+    #[test]
+    fn test_client_is_thread_safe() {
+        assert_send_sync::<Mollie>();
+        assert_send_sync::<super::payments::PaymentsApi>();
+    }
+
+    #[tokio::test]
+    async fn test_client_can_be_moved_into_spawned_tasks() {
+        let client = std::sync::Arc::new(Mollie::build(String::from("access_invalidAccessToken")));
+
+        let handle = tokio::spawn({
+            let client = client.clone();
+            async move { client.api_client.build_url("/payments") }
+        });
+
+        assert_eq!("https://api.mollie.com/v2/payments", handle.await.unwrap());
+    }
+
+    #[test]
+    fn test_builder_defaults_to_production_base_url() {
+        let client = Mollie::build("access_invalidAccessToken");
+
+        assert_eq!(
+            "https://api.mollie.com/v2/payments",
+            client.api_client.build_url("/payments")
+        );
+    }
+
+    #[test]
+    fn test_builder_uses_custom_base_url() {
+        let client = Mollie::builder("access_invalidAccessToken")
+            .base_url(Url::parse("http://localhost:8080/v2/").unwrap())
+            .build();
+
+        assert_eq!(
+            "http://localhost:8080/v2/payments",
+            client.api_client.build_url("/payments")
+        );
+    }
 
     #[tokio::test]
     async fn test_organization_api_is_unauthorized() {
@@ -203,7 +570,10 @@ mod client_tests {
         match client.organizations().me().await {
             Ok(_r) => panic!("Expected API to return a 401 error code, but got a valid response."),
             Err(e) => {
-                assert!(e.to_string().contains("Mollie API Error 401: Unauthorized Request - Missing authentication, or failed to authenticate."));
+                assert!(matches!(e, Error::Unauthorized { .. }));
+                assert!(e
+                    .to_string()
+                    .contains("Unauthorized: Missing authentication, or failed to authenticate"));
             }
         }
     }
@@ -230,6 +600,6 @@ mod client_tests {
 
         let balances_response = client.balances().list(None, &None).await.unwrap();
 
-        assert_eq!(1, balances_response.count);
+        assert_eq!(1, balances_response.resource().count);
     }
 }
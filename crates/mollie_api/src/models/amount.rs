@@ -1,14 +1,252 @@
+use crate::error::Error;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt::Display;
+use std::str::FromStr;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use super::currency;
+
+/// Monetary amount in a given currency.
+///
+/// The value is stored as a decimal, so there are no rounding errors, and is always
+/// serialized with the number of decimals of its currency (e.g. `"10.00"` for EUR and
+/// `"1000"` for JPY), as required by the Mollie API.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "RawAmount", into = "RawAmount")]
 pub struct Amount {
-    pub value: String,
-    pub currency: String,
+    value: Decimal,
+    currency: String,
+}
+
+/// Amount as sent and received by the Mollie API
+#[derive(Serialize, Deserialize)]
+struct RawAmount {
+    value: String,
+    currency: String,
+}
+
+impl Amount {
+    /// Create a new amount, validating the currency and the number of decimals of the value
+    pub fn new(value: Decimal, currency: &str) -> Result<Self, Error> {
+        let currency = currency.trim().to_uppercase();
+        let precision =
+            currency::precision(&currency).ok_or(Error::UnsupportedCurrency(currency.clone()))?;
+
+        if value.normalize().scale() > precision {
+            return Err(Error::InvalidAmountPrecision {
+                value: value.to_string(),
+                currency,
+                precision,
+            });
+        }
+
+        Ok(Self { value, currency })
+    }
+
+    /// Parse an amount from a string value like `"10.50"`
+    pub fn parse(value: &str, currency: &str) -> Result<Self, Error> {
+        let value =
+            Decimal::from_str(value.trim()).map_err(|_| Error::InvalidAmount(value.to_string()))?;
+        Self::new(value, currency)
+    }
+
+    /// Create an amount from the smallest unit of the currency (e.g. cents for EUR)
+    pub fn from_minor_units(minor_units: i64, currency: &str) -> Result<Self, Error> {
+        let currency_code = currency.trim().to_uppercase();
+        let precision = currency::precision(&currency_code)
+            .ok_or(Error::UnsupportedCurrency(currency_code.clone()))?;
+        Self::new(Decimal::new(minor_units, precision), &currency_code)
+    }
+
+    /// An amount of zero in the given currency
+    pub fn zero(currency: &str) -> Result<Self, Error> {
+        Self::new(Decimal::ZERO, currency)
+    }
+
+    /// Decimal value of the amount
+    pub fn value(&self) -> Decimal {
+        self.value
+    }
+
+    /// ISO 4217 currency code of the amount
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Value in the smallest unit of the currency (e.g. cents for EUR), `None` if it doesn't fit
+    pub fn minor_units(&self) -> Option<i64> {
+        let mut value = self.value;
+        value.rescale(self.precision());
+        value.mantissa().try_into().ok()
+    }
+
+    /// True if the value is zero
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    /// Add two amounts, fails if the currencies don't match
+    pub fn checked_add(&self, other: &Amount) -> Result<Amount, Error> {
+        self.ensure_same_currency(other)?;
+        Ok(Self {
+            value: self.value + other.value,
+            currency: self.currency.clone(),
+        })
+    }
+
+    /// Subtract an amount from this one, fails if the currencies don't match
+    pub fn checked_sub(&self, other: &Amount) -> Result<Amount, Error> {
+        self.ensure_same_currency(other)?;
+        Ok(Self {
+            value: self.value - other.value,
+            currency: self.currency.clone(),
+        })
+    }
+
+    /// Number of decimals of the amount's currency. Falls back to the number of decimals
+    /// of the value for currencies that are not known (e.g. received from the API).
+    fn precision(&self) -> u32 {
+        currency::precision(&self.currency).unwrap_or_else(|| self.value.scale())
+    }
+
+    fn ensure_same_currency(&self, other: &Amount) -> Result<(), Error> {
+        if self.currency != other.currency {
+            return Err(Error::CurrencyMismatch {
+                expected: self.currency.clone(),
+                actual: other.currency.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Value formatted with the number of decimals of the currency, e.g. `"10.00"`
+    pub fn formatted_value(&self) -> String {
+        format!("{:.*}", self.precision() as usize, self.value)
+    }
+}
+
+/// Amounts can only be compared when they are in the same currency
+impl PartialOrd for Amount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.currency != other.currency {
+            return None;
+        }
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl TryFrom<RawAmount> for Amount {
+    type Error = Error;
+
+    fn try_from(raw: RawAmount) -> Result<Self, Self::Error> {
+        let value = Decimal::from_str(raw.value.trim())
+            .map_err(|_| Error::InvalidAmount(raw.value.clone()))?;
+
+        // Amounts returned by the API are not validated against the currency table,
+        // so that a newly supported currency doesn't break deserialization.
+        Ok(Self {
+            value,
+            currency: raw.currency,
+        })
+    }
+}
+
+impl From<Amount> for RawAmount {
+    fn from(amount: Amount) -> Self {
+        Self {
+            value: amount.formatted_value(),
+            currency: amount.currency,
+        }
+    }
 }
 
 impl Display for Amount {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", format!("{} {}", self.currency, self.value))
+        write!(f, "{} {}", self.currency, self.formatted_value())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_serialize_with_currency_precision() {
+        let eur = Amount::parse("10.5", "EUR").unwrap();
+        let jpy = Amount::parse("1000", "JPY").unwrap();
+        let kwd = Amount::from_minor_units(1500, "KWD").unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&eur).unwrap(),
+            r#"{"value":"10.50","currency":"EUR"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&jpy).unwrap(),
+            r#"{"value":"1000","currency":"JPY"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&kwd).unwrap(),
+            r#"{"value":"1.500","currency":"KWD"}"#
+        );
+    }
+
+    #[test]
+    fn should_deserialize_mollie_amounts() {
+        let amount: Amount = serde_json::from_str(r#"{"value":"10.00","currency":"EUR"}"#).unwrap();
+
+        assert_eq!(amount, Amount::from_minor_units(1000, "EUR").unwrap());
+        assert_eq!(amount.minor_units(), Some(1000));
+        assert_eq!(amount.to_string(), "EUR 10.00");
+    }
+
+    #[test]
+    fn should_validate_amounts() {
+        assert!(matches!(
+            Amount::parse("10.001", "EUR"),
+            Err(Error::InvalidAmountPrecision { precision: 2, .. })
+        ));
+        assert!(matches!(
+            Amount::parse("10.5", "JPY"),
+            Err(Error::InvalidAmountPrecision { precision: 0, .. })
+        ));
+        assert!(matches!(
+            Amount::parse("ten", "EUR"),
+            Err(Error::InvalidAmount(_))
+        ));
+        assert!(matches!(
+            Amount::parse("10.00", "XYZ"),
+            Err(Error::UnsupportedCurrency(_))
+        ));
+        assert!(Amount::parse("10.500", "eur").is_ok());
+    }
+
+    #[test]
+    fn should_add_and_subtract_without_rounding_errors() {
+        let a = Amount::parse("0.10", "EUR").unwrap();
+        let b = Amount::parse("0.20", "EUR").unwrap();
+
+        let sum = a.checked_add(&b).unwrap();
+        assert_eq!(sum, Amount::parse("0.30", "EUR").unwrap());
+        assert_eq!(
+            sum.checked_sub(&b).unwrap(),
+            Amount::parse("0.1", "EUR").unwrap()
+        );
+        assert!(matches!(
+            a.checked_add(&Amount::parse("1", "USD").unwrap()),
+            Err(Error::CurrencyMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn should_only_compare_amounts_in_the_same_currency() {
+        let small = Amount::parse("5.00", "EUR").unwrap();
+        let large = Amount::parse("10.00", "EUR").unwrap();
+
+        assert!(small < large);
+        assert_eq!(
+            small.partial_cmp(&Amount::parse("5.00", "USD").unwrap()),
+            None
+        );
     }
 }
@@ -1,24 +1,19 @@
-use crate::models::amount::Amount;
+use crate::models::{
+    amount::Amount, link::Link, list::ListResource, method::PaymentMethod, mode::Mode,
+};
+use chrono::{DateTime, FixedOffset, NaiveDate};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use strum::{Display, EnumString};
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct BalancesListResource {
-    pub count: i32,
-    #[serde(rename(deserialize = "_embedded", serialize = "_embedded"))]
-    pub embedded: EmbeddedBalanceResource,
-}
-
-#[derive(Debug, Deserialize, Serialize)]
-pub struct EmbeddedBalanceResource {
-    pub balances: Vec<BalanceResource>,
-}
+pub type BalancesListResource = ListResource<BalanceResource>;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BalanceResource {
     pub id: String,
-    pub mode: String,
-    pub created_at: String,
+    pub mode: Mode,
+    pub created_at: DateTime<FixedOffset>,
     pub currency: String,
     pub status: String,
     pub available_amount: Amount,
@@ -37,3 +32,207 @@ pub struct TransferDestination {
     pub beneficiary_name: String,
     pub bank_account: String,
 }
+
+/// [Balance transaction type](https://docs.mollie.com/reference/v2/balances-api/list-balance-transactions#response)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum BalanceTransactionType {
+    Payment,
+    Capture,
+    UnauthorizedDirectDebit,
+    FailedPayment,
+    Refund,
+    ReturnedRefund,
+    Chargeback,
+    ChargebackReversal,
+    OutgoingTransfer,
+    CanceledOutgoingTransfer,
+    ReturnedTransfer,
+    InvoiceCompensation,
+    BalanceCorrection,
+    ApplicationFee,
+    SplitPayment,
+    PlatformPaymentRefund,
+    PlatformPaymentChargeback,
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// How the totals of a [balance report](https://docs.mollie.com/reference/v2/balances-api/get-balance-report) are grouped
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum BalanceReportGrouping {
+    /// Movements of the pending and available balance
+    StatusBalances,
+    /// Totals per kind of transaction, e.g. payments, refunds and fees
+    TransactionCategories,
+    #[strum(default)]
+    Unknown(String),
+}
+
+string_enum_serde!(BalanceTransactionType, BalanceReportGrouping);
+
+/// Resources a balance transaction relates to, which ones are set depends on its type
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceTransactionContext {
+    pub payment_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub capture_id: Option<String>,
+    pub chargeback_id: Option<String>,
+    pub settlement_id: Option<String>,
+    pub transfer_id: Option<String>,
+    pub invoice_id: Option<String>,
+    pub payment_description: Option<String>,
+}
+
+impl BalanceTransactionContext {
+    /// Id of the most specific resource the transaction relates to, e.g. the refund of a payment
+    pub fn resource_id(&self) -> Option<&str> {
+        [
+            &self.refund_id,
+            &self.capture_id,
+            &self.chargeback_id,
+            &self.payment_id,
+            &self.transfer_id,
+            &self.settlement_id,
+            &self.invoice_id,
+        ]
+        .into_iter()
+        .find_map(|id| id.as_deref())
+    }
+}
+
+/// Balance transaction model; More information:
+/// - <https://docs.mollie.com/reference/v2/balances-api/list-balance-transactions#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceTransactionResource {
+    /// Unique identifier of the transaction, e.g. `baltr_QM24QwzUWR4ev4Xfgyt29A`
+    pub id: String,
+    #[serde(rename = "type")]
+    pub transaction_type: BalanceTransactionType,
+    /// Amount the balance changed with, after deductions
+    pub result_amount: Amount,
+    /// Amount of the transaction before deductions
+    pub initial_amount: Amount,
+    /// Fees deducted from the initial amount
+    pub deductions: Option<Amount>,
+    pub created_at: DateTime<FixedOffset>,
+    #[serde(default)]
+    pub context: BalanceTransactionContext,
+}
+
+pub type BalanceTransactionsListResource = ListResource<BalanceTransactionResource>;
+
+/// Part of a balance report total, e.g. the payments of a single method
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceReportSubtotal {
+    pub transaction_type: Option<BalanceTransactionType>,
+    pub method: Option<PaymentMethod>,
+    /// Kind of fee, only set for the subtotals of fees
+    pub fee_type: Option<String>,
+    pub count: Option<u32>,
+    pub amount: Amount,
+    #[serde(default)]
+    pub subtotals: Vec<BalanceReportSubtotal>,
+}
+
+/// Total of a balance report
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BalanceReportTotal {
+    pub amount: Amount,
+    #[serde(default)]
+    pub subtotals: Vec<BalanceReportSubtotal>,
+}
+
+/// Balance report model; More information:
+/// - <https://docs.mollie.com/reference/v2/balances-api/get-balance-report#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceReportResource {
+    pub balance_id: String,
+    /// Time zone the dates of the report are in, e.g. `Europe/Amsterdam`
+    pub time_zone: String,
+    pub from: NaiveDate,
+    /// First day that is no longer included in the report
+    pub until: NaiveDate,
+    pub grouping: BalanceReportGrouping,
+    /// Totals per group and part of the group, e.g. `totals["pendingBalance"]["open"]`
+    /// or `totals["payments"]["pending"]`, depending on the grouping
+    pub totals: BTreeMap<String, BTreeMap<String, BalanceReportTotal>>,
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_parse_balance_transactions() {
+        let transaction: BalanceTransactionResource = serde_json::from_str(
+            r#"{
+                "resource": "balance_transaction",
+                "id": "baltr_QM24QwzUWR4ev4Xfgyt29A",
+                "type": "refund",
+                "resultAmount": { "value": "-10.25", "currency": "EUR" },
+                "initialAmount": { "value": "-10.00", "currency": "EUR" },
+                "deductions": { "value": "-0.25", "currency": "EUR" },
+                "createdAt": "2021-01-10T12:06:28+00:00",
+                "context": { "paymentId": "tr_7UhSN1zuXS", "refundId": "re_4qqhO89gsT" }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(transaction.transaction_type, BalanceTransactionType::Refund);
+        assert_eq!(transaction.context.resource_id(), Some("re_4qqhO89gsT"));
+    }
+
+    #[test]
+    fn should_parse_balance_reports() {
+        let report: BalanceReportResource = serde_json::from_str(
+            r#"{
+                "resource": "balance-report",
+                "balanceId": "bal_gVMhHKqSSRYJyPsuoPNFH",
+                "timeZone": "Europe/Amsterdam",
+                "from": "2024-01-01",
+                "until": "2024-02-01",
+                "grouping": "status-balances",
+                "totals": {
+                    "pendingBalance": {
+                        "open": { "amount": { "value": "10.00", "currency": "EUR" } },
+                        "pending": {
+                            "amount": { "value": "4.98", "currency": "EUR" },
+                            "subtotals": [{
+                                "transactionType": "payment",
+                                "count": 1,
+                                "amount": { "value": "4.98", "currency": "EUR" },
+                                "subtotals": [{
+                                    "method": "ideal",
+                                    "count": 1,
+                                    "amount": { "value": "4.98", "currency": "EUR" }
+                                }]
+                            }]
+                        }
+                    }
+                },
+                "_links": {}
+            }"#,
+        )
+        .unwrap();
+
+        let pending = &report.totals["pendingBalance"]["pending"];
+        assert_eq!(report.grouping, BalanceReportGrouping::StatusBalances);
+        assert_eq!(
+            pending.subtotals[0].transaction_type,
+            Some(BalanceTransactionType::Payment)
+        );
+        assert_eq!(
+            pending.subtotals[0].subtotals[0].method,
+            Some(PaymentMethod::Ideal)
+        );
+        assert!(report.totals["pendingBalance"]["open"].subtotals.is_empty());
+    }
+}
@@ -0,0 +1,56 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum::{Display, EnumString};
+
+use super::{amount::Amount, link::Link, list::ListResource, mode::Mode};
+
+/// [Capture status](https://docs.mollie.com/reference/v2/captures-api/get-capture#response)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum CaptureStatus {
+    Pending,
+    Succeeded,
+    Failed,
+    #[strum(default)]
+    Unknown(String),
+}
+
+string_enum_serde!(CaptureStatus);
+
+/// Capture model; More information:
+/// - <https://docs.mollie.com/reference/v2/captures-api/get-capture#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureResource {
+    /// Unique identifier of the capture, e.g. `cpt_4qqhO89gsT`
+    pub id: String,
+    pub mode: Mode,
+    pub description: Option<String>,
+    pub amount: Amount,
+    /// Amount added to the settlement, in the settlement's currency
+    pub settlement_amount: Option<Amount>,
+    pub status: CaptureStatus,
+    pub payment_id: String,
+    pub shipment_id: Option<String>,
+    pub settlement_id: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub created_at: DateTime<FixedOffset>,
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+pub type CapturesListResource = ListResource<CaptureResource>;
+
+/// Request body of [Create capture](https://docs.mollie.com/reference/v2/captures-api/create-capture),
+/// the whole remaining authorized amount is captured when no amount is set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCaptureRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+}
@@ -0,0 +1,35 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::{amount::Amount, link::Link, list::ListResource};
+
+/// Reason the customer's bank gave for a chargeback
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ChargebackReason {
+    /// Reason code of the bank, e.g. `AC01`
+    pub code: String,
+    pub description: String,
+}
+
+/// Chargeback model; More information:
+/// - <https://docs.mollie.com/reference/v2/chargebacks-api/get-payment-chargeback#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChargebackResource {
+    /// Unique identifier of the chargeback, e.g. `chb_n9z0tp`
+    pub id: String,
+    pub amount: Amount,
+    /// Amount deducted from the settlement, negative and in the settlement's currency
+    pub settlement_amount: Option<Amount>,
+    pub reason: Option<ChargebackReason>,
+    pub payment_id: String,
+    pub settlement_id: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    /// Set when the chargeback was reversed, and the amount was paid back
+    pub reversed_at: Option<DateTime<FixedOffset>>,
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+pub type ChargebacksListResource = ListResource<ChargebackResource>;
@@ -0,0 +1,26 @@
+//! [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217) currency information.
+
+/// Number of decimals (minor unit digits) of an ISO 4217 currency code,
+/// `None` if the currency is not known.
+///
+/// Covers all the [currencies supported by Mollie](https://docs.mollie.com/payments/multicurrency)
+/// and the other commonly used currencies.
+pub fn precision(currency: &str) -> Option<u32> {
+    match currency {
+        // Currencies without a minor unit
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => Some(0),
+
+        // Currencies with three decimals
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => Some(3),
+
+        // Currencies with two decimals
+        "AED" | "ARS" | "AUD" | "BGN" | "BRL" | "CAD" | "CHF" | "CNY" | "COP" | "CZK" | "DKK"
+        | "EGP" | "EUR" | "GBP" | "HKD" | "HRK" | "HUF" | "IDR" | "ILS" | "INR" | "KES" | "MAD"
+        | "MXN" | "MYR" | "NGN" | "NOK" | "NZD" | "PEN" | "PHP" | "PKR" | "PLN" | "QAR" | "RON"
+        | "RSD" | "RUB" | "SAR" | "SEK" | "SGD" | "THB" | "TRY" | "TWD" | "UAH" | "USD" | "UYU"
+        | "ZAR" => Some(2),
+
+        _ => None,
+    }
+}
@@ -0,0 +1,88 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::{link::Link, list::ListResource, mode::Mode};
+
+/// Customer model; More information:
+/// - <https://docs.mollie.com/reference/v2/customers-api/get-customer#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerResource {
+    /// Unique identifier of the customer, e.g. `cst_8wmqcHMN4U`
+    pub id: String,
+    pub mode: Mode,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    /// Preferred locale of the customer, e.g. `nl_NL`
+    pub locale: Option<String>,
+    /// Any JSON value stored with the customer
+    pub metadata: Option<serde_json::Value>,
+    pub created_at: DateTime<FixedOffset>,
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+pub type CustomersListResource = ListResource<CustomerResource>;
+
+/// Request body of [Create customer](https://docs.mollie.com/reference/v2/customers-api/create-customer)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCustomerRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+/// Request body of [Update customer](https://docs.mollie.com/reference/v2/customers-api/update-customer),
+/// only the parameters that are set are changed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCustomerRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_parse_customer() {
+        let customer: CustomerResource = serde_json::from_str(
+            r#"{
+                "resource": "customer",
+                "id": "cst_8wmqcHMN4U",
+                "mode": "test",
+                "name": "Customer A",
+                "email": "customer@example.org",
+                "locale": "nl_NL",
+                "metadata": null,
+                "createdAt": "2018-04-06T13:23:21.0Z",
+                "_links": {}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(customer.id, "cst_8wmqcHMN4U");
+        assert_eq!(customer.mode, Mode::Test);
+        assert!(customer.metadata.is_none());
+    }
+}
@@ -17,11 +17,24 @@ pub struct ErrorResponse {
     /// More detailed error message
     pub detail: String,
 
+    /// Field of the request that caused the error, returned for validation errors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+
     /// List of links to relevant documentation
-    #[serde(rename(deserialize = "_links"))]
+    #[serde(rename = "_links", default)]
     pub links: HashMap<String, Link>,
 }
 
+impl ErrorResponse {
+    /// Link to the documentation about this error
+    pub fn documentation(&self) -> Option<&str> {
+        self.links
+            .get("documentation")
+            .map(|link| link.href.as_str())
+    }
+}
+
 impl fmt::Display for ErrorResponse {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -0,0 +1,114 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum::{Display, EnumString};
+
+use super::{amount::Amount, link::Link, list::ListResource};
+
+/// [Invoice status](https://docs.mollie.com/reference/v2/invoices-api/get-invoice#response)
+#[derive(Debug, Clone, PartialEq, Eq, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum InvoiceStatus {
+    /// The invoice is not paid yet
+    Open,
+    Paid,
+    /// The due date has passed and the invoice is not paid yet
+    Overdue,
+    #[strum(default)]
+    Unknown(String),
+}
+
+string_enum_serde!(InvoiceStatus);
+
+/// A line of an invoice, e.g. the transaction fees of a payment method in a month
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceLine {
+    /// Month the line is invoiced for, e.g. `2023-09`
+    pub period: String,
+    pub description: String,
+    pub count: i64,
+    /// VAT percentage of the line, e.g. `21`
+    pub vat_percentage: Decimal,
+    /// Amount of the line, excluding VAT
+    pub amount: Amount,
+}
+
+/// Invoice model; More information:
+/// - <https://docs.mollie.com/reference/v2/invoices-api/get-invoice#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceResource {
+    /// Unique identifier of the invoice, e.g. `inv_xBEbP9rvAq`
+    pub id: String,
+    /// Reference printed on the invoice, e.g. `2023.10000`
+    pub reference: String,
+    pub vat_number: Option<String>,
+    pub status: InvoiceStatus,
+    pub issued_at: NaiveDate,
+    pub paid_at: Option<NaiveDate>,
+    pub due_at: Option<NaiveDate>,
+    /// Total of the lines, excluding VAT
+    pub net_amount: Amount,
+    pub vat_amount: Amount,
+    /// Total of the invoice, including VAT
+    pub gross_amount: Amount,
+    #[serde(default)]
+    pub lines: Vec<InvoiceLine>,
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+pub type InvoicesListResource = ListResource<InvoiceResource>;
+
+impl InvoiceResource {
+    /// Link to the PDF of the invoice, which expires 30 minutes after the invoice was retrieved
+    pub fn pdf_link(&self) -> Option<&Link> {
+        self.links.get("pdf")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_read_invoice_with_lines_and_pdf_link() {
+        let invoice: InvoiceResource = serde_json::from_value(serde_json::json!({
+            "resource": "invoice",
+            "id": "inv_xBEbP9rvAq",
+            "reference": "2023.10000",
+            "vatNumber": "NL001234567B01",
+            "status": "paid",
+            "issuedAt": "2023-10-01",
+            "paidAt": "2023-10-14",
+            "netAmount": { "value": "45.00", "currency": "EUR" },
+            "vatAmount": { "value": "9.45", "currency": "EUR" },
+            "grossAmount": { "value": "54.45", "currency": "EUR" },
+            "lines": [
+                {
+                    "period": "2023-09",
+                    "description": "iDEAL payment fees",
+                    "count": 100,
+                    "vatPercentage": 21,
+                    "amount": { "value": "45.00", "currency": "EUR" }
+                }
+            ],
+            "_links": {
+                "self": { "href": "https://api.mollie.com/v2/invoices/inv_xBEbP9rvAq", "type": "application/hal+json" },
+                "pdf": { "href": "https://www.mollie.com/merchant/download/invoice/xBEbP9rvAq/2ab44d60", "type": "application/pdf" }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(invoice.status, InvoiceStatus::Paid);
+        assert_eq!(invoice.due_at, None);
+        assert_eq!(invoice.lines[0].vat_percentage, Decimal::from(21));
+        assert_eq!(
+            invoice.lines[0].amount.to_string(),
+            invoice.net_amount.to_string()
+        );
+        assert_eq!(invoice.pdf_link().unwrap().r#type, "application/pdf");
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use futures::stream::{self, BoxStream, StreamExt};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::link::Link;
+use crate::ApiClient;
+
+/// Model used to represent the [paginated list responses](https://docs.mollie.com/overview/pagination)
+/// returned by every list endpoint of the Mollie API.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ListResource<T> {
+    /// Number of items in this page
+    pub count: i32,
+
+    /// Items of this page, embedded under the name of the resource (e.g. `payments`)
+    #[serde(rename = "_embedded")]
+    pub embedded: HashMap<String, Vec<T>>,
+
+    /// HAL links to the current, previous and next page
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Option<Link>>,
+}
+
+impl<T> ListResource<T> {
+    /// Items of this page
+    pub fn items(&self) -> &[T] {
+        self.embedded
+            .values()
+            .next()
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Consume the page and return its items
+    pub fn into_items(self) -> Vec<T> {
+        self.embedded.into_values().next().unwrap_or_default()
+    }
+
+    /// Link to the next page, if there is one
+    pub fn next_link(&self) -> Option<&Link> {
+        self.links.get("next")?.as_ref()
+    }
+
+    /// Link to the previous page, if there is one
+    pub fn previous_link(&self) -> Option<&Link> {
+        self.links.get("previous")?.as_ref()
+    }
+}
+
+/// A single page of a list endpoint, which knows how to fetch the pages around it.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    api_client: ApiClient,
+    endpoint: String,
+    query: HashMap<String, String>,
+    resource: ListResource<T>,
+}
+
+impl<T> Page<T>
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    pub(crate) fn new(
+        api_client: ApiClient,
+        endpoint: &str,
+        query: HashMap<String, String>,
+        resource: ListResource<T>,
+    ) -> Self {
+        Self {
+            api_client,
+            endpoint: endpoint.to_string(),
+            query,
+            resource,
+        }
+    }
+
+    /// The raw list response of this page
+    pub fn resource(&self) -> &ListResource<T> {
+        &self.resource
+    }
+
+    /// Items of this page
+    pub fn items(&self) -> &[T] {
+        self.resource.items()
+    }
+
+    /// Consume the page and return its items
+    pub fn into_items(self) -> Vec<T> {
+        self.resource.into_items()
+    }
+
+    /// Fetch the next page, returns `None` when this is the last page
+    pub async fn next(&self) -> crate::Result<Option<Page<T>>> {
+        match self.resource.next_link() {
+            Some(link) => Ok(Some(self.follow(link).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetch the previous page, returns `None` when this is the first page
+    pub async fn previous(&self) -> crate::Result<Option<Page<T>>> {
+        match self.resource.previous_link() {
+            Some(link) => Ok(Some(self.follow(link).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Stream every item of this and all the following pages, fetching the next
+    /// page only once all the items of the current one have been consumed.
+    pub fn into_stream(mut self) -> BoxStream<'static, crate::Result<T>> {
+        let items = self.take_items().into_iter();
+
+        stream::try_unfold((items, Some(self)), |(mut items, mut page)| async move {
+            loop {
+                if let Some(item) = items.next() {
+                    return Ok(Some((item, (items, page))));
+                }
+
+                let Some(current) = page else {
+                    return Ok(None);
+                };
+
+                match current.next().await? {
+                    Some(mut next) => {
+                        items = next.take_items().into_iter();
+                        page = Some(next);
+                    }
+                    None => return Ok(None),
+                }
+            }
+        })
+        .boxed()
+    }
+
+    fn take_items(&mut self) -> Vec<T> {
+        std::mem::take(&mut self.resource.embedded)
+            .into_values()
+            .next()
+            .unwrap_or_default()
+    }
+
+    /// Request the page a HAL link points to.
+    ///
+    /// Only the cursor (query) of the link is used: the request is always sent to the
+    /// configured base url, so the auth token is never sent to a host taken from a response.
+    /// Filters of the original request (e.g. `testmode`) are kept.
+    async fn follow(&self, link: &Link) -> crate::Result<Page<T>> {
+        let mut query: HashMap<&str, String> = self
+            .query
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.clone()))
+            .collect();
+
+        let cursor = cursor_from_link(link);
+        for (key, value) in &cursor {
+            query.insert(key.as_str(), value.clone());
+        }
+
+        self.api_client.get_page(&self.endpoint, Some(query)).await
+    }
+}
+
+fn cursor_from_link(link: &Link) -> Vec<(String, String)> {
+    reqwest::Url::parse(&link.href)
+        .map(|url| url.query_pairs().into_owned().collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PAYMENTS_PAGE: &str = r#"{
+        "count": 2,
+        "_embedded": {
+            "payments": [{ "id": "tr_1" }, { "id": "tr_2" }]
+        },
+        "_links": {
+            "self": { "href": "https://api.mollie.com/v2/payments?limit=2", "type": "application/hal+json" },
+            "previous": null,
+            "next": { "href": "https://api.mollie.com/v2/payments?from=tr_3&limit=2", "type": "application/hal+json" },
+            "documentation": { "href": "https://docs.mollie.com/reference/v2/payments-api/list-payments", "type": "text/html" }
+        }
+    }"#;
+
+    #[derive(Debug, Deserialize)]
+    struct Item {
+        id: String,
+    }
+
+    #[test]
+    fn should_parse_embedded_items() {
+        let page: ListResource<Item> = serde_json::from_str(PAYMENTS_PAGE).unwrap();
+
+        assert_eq!(page.count, 2);
+        assert_eq!(
+            page.items()
+                .iter()
+                .map(|i| i.id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["tr_1", "tr_2"]
+        );
+        assert!(page.previous_link().is_none());
+    }
+
+    #[test]
+    fn should_extract_cursor_from_next_link() {
+        let page: ListResource<Item> = serde_json::from_str(PAYMENTS_PAGE).unwrap();
+
+        let cursor = cursor_from_link(page.next_link().unwrap());
+
+        assert_eq!(
+            cursor,
+            vec![
+                ("from".to_string(), "tr_3".to_string()),
+                ("limit".to_string(), "2".to_string())
+            ]
+        );
+    }
+}
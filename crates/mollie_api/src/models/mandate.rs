@@ -0,0 +1,127 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum::{Display, EnumString};
+
+use super::{link::Link, list::ListResource, method::PaymentMethod, mode::Mode};
+
+/// [Mandate status](https://docs.mollie.com/reference/v2/mandates-api/get-mandate#response),
+/// only `valid` mandates can be used for recurring payments
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum MandateStatus {
+    Valid,
+    Pending,
+    Invalid,
+    #[strum(default)]
+    Unknown(String),
+}
+
+string_enum_serde!(MandateStatus);
+
+/// Details of a mandate, which ones are set depends on its method
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MandateDetails {
+    /// Name of the account holder (direct debit and PayPal)
+    pub consumer_name: Option<String>,
+    /// IBAN (direct debit) or email address (PayPal) of the consumer
+    pub consumer_account: Option<String>,
+    /// BIC of the consumer's bank (direct debit)
+    pub consumer_bic: Option<String>,
+    /// Name of the card holder (credit card)
+    pub card_holder: Option<String>,
+    /// Last four digits of the card number (credit card)
+    pub card_number: Option<String>,
+    /// Card brand, e.g. `Visa` or `Mastercard` (credit card)
+    pub card_label: Option<String>,
+    /// Unique identifier of the card, the same card always has the same fingerprint (credit card)
+    pub card_fingerprint: Option<String>,
+    /// Expiry date of the card (credit card)
+    pub card_expiry_date: Option<NaiveDate>,
+}
+
+/// Mandate model; More information:
+/// - <https://docs.mollie.com/reference/v2/mandates-api/get-mandate#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MandateResource {
+    /// Unique identifier of the mandate, e.g. `mdt_h3gAaD5zP`
+    pub id: String,
+    pub mode: Mode,
+    pub status: MandateStatus,
+    pub method: PaymentMethod,
+    pub details: MandateDetails,
+    pub mandate_reference: Option<String>,
+    pub signature_date: Option<NaiveDate>,
+    pub created_at: DateTime<FixedOffset>,
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+pub type MandatesListResource = ListResource<MandateResource>;
+
+/// Request body of [Create mandate](https://docs.mollie.com/reference/v2/mandates-api/create-mandate)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMandateRequest {
+    /// `directdebit` or `paypal`
+    pub method: PaymentMethod,
+    pub consumer_name: String,
+    /// IBAN of the consumer, required for direct debit mandates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumer_account: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumer_bic: Option<String>,
+    /// Email address of the consumer, required for PayPal mandates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumer_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_reference: Option<String>,
+    /// Billing agreement id, required for PayPal mandates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paypal_billing_agreement_id: Option<String>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_parse_card_mandate() {
+        let mandate: MandateResource = serde_json::from_str(
+            r#"{
+                "resource": "mandate",
+                "id": "mdt_pWUnw6pkBN",
+                "mode": "live",
+                "status": "valid",
+                "method": "creditcard",
+                "details": {
+                    "cardHolder": "John Doe",
+                    "cardNumber": "1234",
+                    "cardLabel": "Mastercard",
+                    "cardFingerprint": "fHB3CCKx9REkz8fPplT8N4nq",
+                    "cardExpiryDate": "2024-05-31"
+                },
+                "mandateReference": null,
+                "signatureDate": "2018-05-07",
+                "createdAt": "2018-05-07T10:49:08+00:00",
+                "_links": {}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(mandate.status, MandateStatus::Valid);
+        assert_eq!(mandate.method, PaymentMethod::CreditCard);
+        assert_eq!(
+            mandate.details.card_fingerprint.as_deref(),
+            Some("fHB3CCKx9REkz8fPplT8N4nq")
+        );
+        assert_eq!(mandate.signature_date, NaiveDate::from_ymd_opt(2018, 5, 7));
+    }
+}
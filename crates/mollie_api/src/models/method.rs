@@ -0,0 +1,222 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum::{Display, EnumString};
+
+use super::{amount::Amount, link::Link, list::ListResource, payment::SequenceType};
+
+/// [Payment method](https://docs.mollie.com/reference/v2/methods-api/list-methods) identifiers
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum PaymentMethod {
+    Alma,
+    ApplePay,
+    Bancomatpay,
+    Bancontact,
+    BankTransfer,
+    Belfius,
+    Billie,
+    Blik,
+    CreditCard,
+    DirectDebit,
+    Eps,
+    GiftCard,
+    Ideal,
+    In3,
+    Kbc,
+    KlarnaPayLater,
+    KlarnaPayNow,
+    KlarnaSliceIt,
+    MyBank,
+    Payconiq,
+    PayPal,
+    PaySafeCard,
+    Przelewy24,
+    Riverty,
+    Satispay,
+    Sofort,
+    Trustly,
+    Twint,
+    Voucher,
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Activation status of a payment method on a profile
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum MethodStatus {
+    Activated,
+    PendingBoarding,
+    PendingReview,
+    PendingExternal,
+    Rejected,
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Additional information that can be included when listing methods
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum MethodInclude {
+    Issuers,
+    Pricing,
+}
+
+string_enum_serde!(PaymentMethod, MethodStatus);
+
+/// Urls of the logo of a payment method or issuer
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MethodImage {
+    pub size1x: String,
+    pub size2x: String,
+    pub svg: String,
+}
+
+/// Issuer of a payment method, e.g. a bank for iDEAL or a brand of gift cards
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Issuer {
+    /// Unique identifier of the issuer, e.g. `ideal_INGBNL2A`
+    pub id: String,
+    pub name: String,
+    pub image: Option<MethodImage>,
+}
+
+/// Fee charged for payments with a method, which can differ per region or card type
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodPricing {
+    /// Where the fee applies, e.g. `Commercial & non-European cards`
+    pub description: String,
+    pub fixed: Amount,
+    /// Percentage of the amount charged on top of the fixed fee, e.g. `"2.8"`
+    pub variable: String,
+    pub fee_region: Option<String>,
+}
+
+/// Method model; More information:
+/// - <https://docs.mollie.com/reference/v2/methods-api/get-method#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodResource {
+    pub id: PaymentMethod,
+    /// Name of the method, translated to the requested locale
+    pub description: String,
+    pub minimum_amount: Amount,
+    /// Highest amount that can be paid, `None` if there is no limit
+    pub maximum_amount: Option<Amount>,
+    pub image: MethodImage,
+    /// Only returned when listing all methods or with an access token
+    pub status: Option<MethodStatus>,
+    /// Only returned when the issuers were included
+    pub issuers: Option<Vec<Issuer>>,
+    /// Only returned when the pricing was included
+    pub pricing: Option<Vec<MethodPricing>>,
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+pub type MethodsListResource = ListResource<MethodResource>;
+
+/// Filters of [List payment methods](https://docs.mollie.com/reference/v2/methods-api/list-methods),
+/// also used for [List all methods](https://docs.mollie.com/reference/v2/methods-api/list-all-methods)
+/// and [Get method](https://docs.mollie.com/reference/v2/methods-api/get-method) which support only some of them.
+#[derive(Debug, Clone, Default)]
+pub struct MethodsQuery {
+    /// Only methods that support this amount (and its currency)
+    pub amount: Option<Amount>,
+    /// Translate the descriptions, e.g. `nl_NL`
+    pub locale: Option<String>,
+    /// Only methods that support this kind of payment, e.g. recurring ones
+    pub sequence_type: Option<SequenceType>,
+    /// Only methods available to customers in this country (ISO 3166-1 alpha-2 code)
+    pub billing_country: Option<String>,
+    pub include: Vec<MethodInclude>,
+    /// Only used when authenticated with an access token
+    pub profile_id: Option<String>,
+    /// Only used when authenticated with an access token
+    pub test_mode: Option<bool>,
+}
+
+impl MethodsQuery {
+    /// Query parameters of the filters that are set
+    pub(crate) fn params(&self) -> HashMap<&'static str, String> {
+        let mut params = HashMap::new();
+        if let Some(amount) = &self.amount {
+            params.insert("amount[value]", amount.formatted_value());
+            params.insert("amount[currency]", amount.currency().to_string());
+        }
+        if let Some(locale) = &self.locale {
+            params.insert("locale", locale.clone());
+        }
+        if let Some(sequence_type) = &self.sequence_type {
+            params.insert("sequenceType", sequence_type.to_string());
+        }
+        if let Some(country) = &self.billing_country {
+            params.insert("billingCountry", country.clone());
+        }
+        if !self.include.is_empty() {
+            let include = self
+                .include
+                .iter()
+                .map(MethodInclude::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            params.insert("include", include);
+        }
+        if let Some(profile_id) = &self.profile_id {
+            params.insert("profileId", profile_id.clone());
+        }
+        if let Some(test_mode) = self.test_mode {
+            params.insert("testmode", test_mode.to_string());
+        }
+        params
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_parse_known_payment_methods() {
+        let methods: Vec<PaymentMethod> =
+            serde_json::from_str(r#"["ideal", "creditcard", "klarnapaylater"]"#).unwrap();
+
+        assert_eq!(
+            methods,
+            vec![
+                PaymentMethod::Ideal,
+                PaymentMethod::CreditCard,
+                PaymentMethod::KlarnaPayLater
+            ]
+        );
+    }
+
+    #[test]
+    fn should_build_the_methods_query() {
+        let query = MethodsQuery {
+            amount: Some(Amount::parse("10", "EUR").unwrap()),
+            sequence_type: Some(SequenceType::First),
+            include: vec![MethodInclude::Issuers, MethodInclude::Pricing],
+            ..Default::default()
+        };
+
+        let params = query.params();
+        assert_eq!(params.get("amount[value]").unwrap(), "10.00");
+        assert_eq!(params.get("amount[currency]").unwrap(), "EUR");
+        assert_eq!(params.get("sequenceType").unwrap(), "first");
+        assert_eq!(params.get("include").unwrap(), "issuers,pricing");
+        assert!(!params.contains_key("locale"));
+    }
+
+    #[test]
+    fn should_keep_unknown_payment_methods() {
+        let method: PaymentMethod = serde_json::from_str(r#""brandnewmethod""#).unwrap();
+
+        assert_eq!(method, PaymentMethod::Unknown("brandnewmethod".to_string()));
+        assert_eq!(
+            serde_json::to_string(&method).unwrap(),
+            r#""brandnewmethod""#
+        );
+    }
+}
@@ -1,10 +1,50 @@
+/// Implement `Serialize` and `Deserialize` for enums that represent string values of the
+/// Mollie API, using their (strum derived) `Display` and `FromStr` implementations.
+///
+/// Combined with a `#[strum(default)] Unknown(String)` variant, values introduced by Mollie
+/// after this crate was released are deserialized instead of failing the whole response.
+macro_rules! string_enum_serde {
+    ($($name:ty),+ $(,)?) => {
+        $(
+            impl serde::Serialize for $name {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for $name {
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let value = String::deserialize(deserializer)?;
+                    value.parse().map_err(serde::de::Error::custom)
+                }
+            }
+        )+
+    };
+}
+
 pub mod address;
 pub mod amount;
 pub mod balance;
+pub mod capture;
+pub mod chargeback;
+pub mod currency;
+pub mod customer;
 pub mod error_response;
+pub mod invoice;
 pub mod link;
+pub mod list;
+pub mod mandate;
+pub mod method;
+pub mod mode;
+pub mod onboarding;
+pub mod order;
 pub mod organization;
 pub mod partner;
 pub mod payment;
+pub mod payment_link;
 pub mod permission;
+pub mod profile;
 pub mod refund;
+pub mod settlement;
+pub mod shipment;
+pub mod subscription;
@@ -0,0 +1,13 @@
+use strum::{Display, EnumString};
+
+/// Mode of a resource, either created with a live or a test API key (or in test mode).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum Mode {
+    Live,
+    Test,
+    #[strum(default)]
+    Unknown(String),
+}
+
+string_enum_serde!(Mode);
@@ -0,0 +1,127 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum::{Display, EnumString};
+
+use super::{address::Address, link::Link};
+
+/// [Onboarding status](https://docs.mollie.com/reference/v2/onboarding-api/get-onboarding-status#response)
+#[derive(Debug, Clone, PartialEq, Eq, Display, EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum OnboardingStatus {
+    /// The merchant still has to provide information before Mollie can review the organization
+    NeedsData,
+    /// Mollie is reviewing the information the merchant provided
+    InReview,
+    Completed,
+    #[strum(default)]
+    Unknown(String),
+}
+
+string_enum_serde!(OnboardingStatus);
+
+/// Onboarding status model; More information:
+/// - <https://docs.mollie.com/reference/v2/onboarding-api/get-onboarding-status#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnboardingResource {
+    /// Name of the organization
+    pub name: String,
+    pub signed_up_at: DateTime<FixedOffset>,
+    pub status: OnboardingStatus,
+    pub can_receive_payments: bool,
+    pub can_receive_settlements: bool,
+    /// Links to the organization and to the dashboard, where the merchant can complete the onboarding
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+/// Organization details to prefill in the onboarding of a merchant
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnboardingOrganization {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    /// Registration number at the chamber of commerce
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registration_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vat_number: Option<String>,
+    /// Either `shifted` or `dutch`, for organizations based in the EU
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vat_regulation: Option<String>,
+}
+
+/// Details of the first website profile to prefill in the onboarding of a merchant
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnboardingProfile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Url of the website, e.g. `https://www.mywebsite.com`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /// [Business category](https://docs.mollie.com/overview/common-data-types#business-category), e.g. `MONEY_SERVICES`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_category: Option<String>,
+}
+
+/// Request used to [submit onboarding data](https://docs.mollie.com/reference/v2/onboarding-api/submit-onboarding-data),
+/// only possible while the onboarding status is `needs-data`
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SubmitOnboardingRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<OnboardingOrganization>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<OnboardingProfile>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_read_onboarding_status() {
+        let onboarding: OnboardingResource = serde_json::from_value(serde_json::json!({
+            "resource": "onboarding",
+            "name": "Mollie B.V.",
+            "signedUpAt": "2018-12-20T10:49:08+00:00",
+            "status": "in-review",
+            "canReceivePayments": true,
+            "canReceiveSettlements": false,
+            "_links": {}
+        }))
+        .unwrap();
+
+        assert_eq!(onboarding.status, OnboardingStatus::InReview);
+        assert!(onboarding.can_receive_payments);
+        assert!(!onboarding.can_receive_settlements);
+    }
+
+    #[test]
+    fn should_only_submit_given_onboarding_data() {
+        let request = SubmitOnboardingRequest {
+            organization: Some(OnboardingOrganization {
+                name: Some(String::from("Mollie B.V.")),
+                vat_regulation: Some(String::from("dutch")),
+                ..Default::default()
+            }),
+            profile: None,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "organization": { "name": "Mollie B.V.", "vatRegulation": "dutch" }
+            })
+        );
+    }
+}
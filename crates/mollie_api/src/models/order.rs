@@ -0,0 +1,445 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+use strum::{Display, EnumString};
+
+use super::{
+    address::Address, amount::Amount, currency, link::Link, list::ListResource,
+    method::PaymentMethod, mode::Mode, payment::LineType, payment::PaymentResource,
+    refund::RefundResource, shipment::ShipmentResource,
+};
+use crate::error::Error;
+
+/// [Order status](https://docs.mollie.com/orders/status-changes)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum OrderStatus {
+    Created,
+    Pending,
+    Authorized,
+    Paid,
+    Shipping,
+    Completed,
+    Canceled,
+    Expired,
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Status of a single line of an order
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum OrderLineStatus {
+    Created,
+    Pending,
+    Authorized,
+    Paid,
+    Shipping,
+    Completed,
+    Canceled,
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Related resources that can be embedded when getting an order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum OrderEmbed {
+    Payments,
+    Refunds,
+    Shipments,
+}
+
+string_enum_serde!(OrderStatus, OrderLineStatus);
+
+/// Line of an order; More information:
+/// - <https://docs.mollie.com/reference/v2/orders-api/get-order#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderLine {
+    /// Unique identifier of the order line, e.g. `odl_dgtxyl`
+    pub id: String,
+    pub order_id: String,
+    #[serde(rename = "type")]
+    pub line_type: LineType,
+    pub name: String,
+    pub status: OrderLineStatus,
+    #[serde(default)]
+    pub is_cancelable: bool,
+    pub quantity: u32,
+    #[serde(default)]
+    pub quantity_shipped: u32,
+    pub amount_shipped: Option<Amount>,
+    #[serde(default)]
+    pub quantity_refunded: u32,
+    pub amount_refunded: Option<Amount>,
+    #[serde(default)]
+    pub quantity_canceled: u32,
+    pub amount_canceled: Option<Amount>,
+    #[serde(default)]
+    pub shippable_quantity: u32,
+    #[serde(default)]
+    pub refundable_quantity: u32,
+    #[serde(default)]
+    pub cancelable_quantity: u32,
+    pub unit_price: Amount,
+    pub discount_amount: Option<Amount>,
+    pub total_amount: Amount,
+    /// VAT rate as a percentage string, e.g. `"21.00"`
+    pub vat_rate: String,
+    pub vat_amount: Amount,
+    pub sku: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub created_at: DateTime<FixedOffset>,
+    #[serde(rename = "_links", default)]
+    pub links: HashMap<String, Link>,
+}
+
+/// Resources embedded in an order when requested with [`OrderEmbed`]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct OrderEmbedded {
+    #[serde(default)]
+    pub payments: Vec<PaymentResource>,
+    #[serde(default)]
+    pub refunds: Vec<RefundResource>,
+    #[serde(default)]
+    pub shipments: Vec<ShipmentResource>,
+}
+
+/// Order model; More information:
+/// - <https://docs.mollie.com/reference/v2/orders-api/get-order#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderResource {
+    /// Unique identifier of the order, e.g. `ord_kEn1PlbGa`
+    pub id: String,
+    pub profile_id: String,
+    pub mode: Mode,
+    pub created_at: DateTime<FixedOffset>,
+    pub status: OrderStatus,
+    #[serde(default)]
+    pub is_cancelable: bool,
+    pub amount: Amount,
+    pub amount_captured: Option<Amount>,
+    pub amount_refunded: Option<Amount>,
+    pub order_number: String,
+    pub lines: Vec<OrderLine>,
+    pub billing_address: Address,
+    pub shipping_address: Option<Address>,
+    pub consumer_date_of_birth: Option<NaiveDate>,
+    pub locale: String,
+    pub method: Option<PaymentMethod>,
+    pub redirect_url: Option<String>,
+    pub cancel_url: Option<String>,
+    pub webhook_url: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub expires_at: Option<DateTime<FixedOffset>>,
+    pub authorized_at: Option<DateTime<FixedOffset>>,
+    pub paid_at: Option<DateTime<FixedOffset>>,
+    pub canceled_at: Option<DateTime<FixedOffset>>,
+    pub expired_at: Option<DateTime<FixedOffset>>,
+    pub completed_at: Option<DateTime<FixedOffset>>,
+    #[serde(rename = "_embedded", skip_serializing_if = "Option::is_none")]
+    pub embedded: Option<OrderEmbedded>,
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+pub type OrdersListResource = ListResource<OrderResource>;
+
+/// Line of the order to create, describing a product, fee or discount; More information:
+/// - <https://docs.mollie.com/reference/v2/orders-api/create-order#order-lines-details>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrderLine {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub line_type: Option<LineType>,
+    pub name: String,
+    pub quantity: u32,
+    pub unit_price: Amount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount_amount: Option<Amount>,
+    /// `unit_price × quantity - discount_amount`
+    pub total_amount: Amount,
+    /// VAT rate as a percentage string, e.g. `"21.00"`
+    pub vat_rate: String,
+    /// `total_amount × (vat_rate / (100 + vat_rate))`
+    pub vat_amount: Amount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+}
+
+impl CreateOrderLine {
+    /// Line with the total and VAT amount calculated the way Mollie validates them
+    pub fn new(
+        name: impl Into<String>,
+        quantity: u32,
+        unit_price: Amount,
+        vat_rate: &str,
+    ) -> Result<Self, Error> {
+        let total = unit_price.value() * Decimal::from(quantity);
+        let total_amount = Amount::new(total, unit_price.currency())?;
+        let vat_amount = vat_amount(&total_amount, vat_rate)?;
+
+        Ok(Self {
+            line_type: None,
+            name: name.into(),
+            quantity,
+            unit_price,
+            discount_amount: None,
+            total_amount,
+            vat_rate: vat_rate.to_string(),
+            vat_amount,
+            sku: None,
+            image_url: None,
+            product_url: None,
+            metadata: None,
+        })
+    }
+
+    /// Apply a discount to the line, recalculating its total and VAT amount
+    pub fn with_discount(self, discount_amount: Amount) -> Result<Self, Error> {
+        let undiscounted = Amount::new(
+            self.unit_price.value() * Decimal::from(self.quantity),
+            self.unit_price.currency(),
+        )?;
+        let total_amount = undiscounted.checked_sub(&discount_amount)?;
+        let vat_amount = vat_amount(&total_amount, &self.vat_rate)?;
+
+        Ok(Self {
+            discount_amount: Some(discount_amount),
+            total_amount,
+            vat_amount,
+            ..self
+        })
+    }
+}
+
+/// VAT included in a total amount, rounded to the precision of its currency
+pub fn vat_amount(total_amount: &Amount, vat_rate: &str) -> Result<Amount, Error> {
+    let rate = Decimal::from_str(vat_rate.trim())
+        .ok()
+        .filter(|rate| !rate.is_sign_negative())
+        .ok_or_else(|| Error::InvalidVatRate(vat_rate.to_string()))?;
+    let precision = currency::precision(total_amount.currency()).unwrap_or(2);
+
+    let vat = total_amount.value() * rate / (Decimal::ONE_HUNDRED + rate);
+    Amount::new(
+        vat.round_dp_with_strategy(precision, RoundingStrategy::MidpointAwayFromZero),
+        total_amount.currency(),
+    )
+}
+
+/// Request body of [Create order](https://docs.mollie.com/reference/v2/orders-api/create-order)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrderRequest {
+    /// Total amount of the order, the sum of the total amounts of its lines
+    pub amount: Amount,
+    pub order_number: String,
+    pub lines: Vec<CreateOrderLine>,
+    pub billing_address: Address,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumer_date_of_birth: Option<NaiveDate>,
+    /// Locale of the checkout, e.g. `nl_NL`
+    pub locale: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    /// Payment methods the customer can choose from, all enabled methods when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<Vec<PaymentMethod>>,
+    /// Parameters passed on to the payment of the order, e.g. `customerId` or `sequenceType`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    /// Date the order expires, at most 100 days after it's created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<NaiveDate>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_id: Option<String>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+impl CreateOrderRequest {
+    /// Request for an order of the given lines, its amount is the sum of their totals
+    pub fn new(
+        order_number: impl Into<String>,
+        lines: Vec<CreateOrderLine>,
+        billing_address: Address,
+        locale: impl Into<String>,
+    ) -> Result<Self, Error> {
+        let (first, rest) = lines.split_first().ok_or(Error::NoOrderLines)?;
+        let amount = rest
+            .iter()
+            .try_fold(first.total_amount.clone(), |sum, line| {
+                sum.checked_add(&line.total_amount)
+            })?;
+
+        Ok(Self {
+            amount,
+            order_number: order_number.into(),
+            lines,
+            billing_address,
+            shipping_address: None,
+            consumer_date_of_birth: None,
+            locale: locale.into(),
+            redirect_url: None,
+            cancel_url: None,
+            webhook_url: None,
+            method: None,
+            payment: None,
+            metadata: None,
+            expires_at: None,
+            profile_id: None,
+            testmode: None,
+        })
+    }
+}
+
+/// Request body of [Update order](https://docs.mollie.com/reference/v2/orders-api/update-order),
+/// only the parameters that are set are changed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateOrderRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+/// Request body of [Update order line](https://docs.mollie.com/reference/v2/orders-api/update-orderline),
+/// only the parameters that are set are changed. When changing the amounts, Mollie requires
+/// the total and VAT amount to still add up.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateOrderLineRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_price: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount_amount: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_amount: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vat_rate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vat_amount: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+/// Line of an order to ship or cancel, the whole remaining quantity when `quantity` is not set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderLineQuantity {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u32>,
+    /// Only needed when a line is partially canceled and had a discount
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Amount>,
+}
+
+/// Request body of [Cancel order lines](https://docs.mollie.com/reference/v2/orders-api/cancel-order-lines)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderLinesRequest {
+    pub lines: Vec<OrderLineQuantity>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_calculate_line_totals_and_vat() {
+        let line = CreateOrderLine::new(
+            "LEGO 4440",
+            2,
+            Amount::parse("399.00", "EUR").unwrap(),
+            "21.00",
+        )
+        .unwrap()
+        .with_discount(Amount::parse("100.00", "EUR").unwrap())
+        .unwrap();
+
+        assert_eq!(line.total_amount, Amount::parse("698.00", "EUR").unwrap());
+        assert_eq!(line.vat_amount, Amount::parse("121.14", "EUR").unwrap());
+        assert!(
+            CreateOrderLine::new("Gift", 1, Amount::parse("1.00", "EUR").unwrap(), "high").is_err()
+        );
+    }
+
+    #[test]
+    fn should_sum_the_lines_of_an_order() {
+        let line = |price: &str, currency: &str| {
+            CreateOrderLine::new("Item", 1, Amount::parse(price, currency).unwrap(), "0").unwrap()
+        };
+        let address = Address {
+            country: String::from("NL"),
+            ..Default::default()
+        };
+
+        let order = CreateOrderRequest::new(
+            "1337",
+            vec![line("10.00", "EUR"), line("4.95", "EUR")],
+            address.clone(),
+            "nl_NL",
+        )
+        .unwrap();
+
+        assert_eq!(order.amount, Amount::parse("14.95", "EUR").unwrap());
+        assert!(CreateOrderRequest::new("1", vec![], address.clone(), "nl_NL").is_err());
+        assert!(CreateOrderRequest::new(
+            "1",
+            vec![line("10.00", "EUR"), line("10.00", "USD")],
+            address,
+            "nl_NL"
+        )
+        .is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, FixedOffset};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -18,7 +18,7 @@ pub struct Partner {
 
     /// Date and time at which the partner contract was signed. [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format.
     #[serde(rename(deserialize = "partnerContractSignedAt"))]
-    pub partner_contract_signed_at: Option<NaiveDateTime>,
+    pub partner_contract_signed_at: Option<DateTime<FixedOffset>>,
 
     /// True if contract update is available
     #[serde(rename(deserialize = "partnerContractUpdateAvailable"))]
@@ -26,14 +26,42 @@ pub struct Partner {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct UserAgentToken {
     /// Unique user agent token
     pub token: String,
 
     /// Date and time from which the token is active. [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format.
-    pub starts_at: NaiveDateTime,
+    pub starts_at: DateTime<FixedOffset>,
 
     /// Date and time at which the token expires. [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format.
     /// Can be null if date is not set.
-    pub ends_at: Option<NaiveDateTime>,
+    pub ends_at: Option<DateTime<FixedOffset>>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_keep_timezone_offsets() {
+        let partner: Partner = serde_json::from_str(
+            r#"{
+                "partnerType": "useragent",
+                "isCommissionPartner": true,
+                "userAgentTokens": [
+                    { "token": "unique-token", "startsAt": "2018-03-20T13:13:37+02:00", "endsAt": null }
+                ],
+                "partnerContractSignedAt": "2018-03-20T13:13:37+00:00",
+                "partnerContractUpdateAvailable": false
+            }"#,
+        )
+        .unwrap();
+
+        let token = &partner.user_agent_tokens.unwrap()[0];
+        assert_eq!(token.starts_at.offset().local_minus_utc(), 2 * 3600);
+        assert_eq!(token.starts_at.to_rfc3339(), "2018-03-20T13:13:37+02:00");
+        assert!(token.ends_at.is_none());
+        assert!(partner.partner_contract_signed_at.is_some());
+    }
 }
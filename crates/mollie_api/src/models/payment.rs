@@ -1,7 +1,52 @@
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use strum::{Display, EnumString};
 
-use super::{amount::Amount, link::Link};
+use crate::error::Error;
+
+use super::{
+    address::Address, amount::Amount, link::Link, list::ListResource, method::PaymentMethod,
+    mode::Mode,
+};
+
+/// [Payment status](https://docs.mollie.com/payments/status-changes)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum PaymentStatus {
+    Open,
+    Canceled,
+    Pending,
+    Authorized,
+    Expired,
+    Failed,
+    Paid,
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Indicates which type of payment this is in a recurring sequence
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum SequenceType {
+    OneOff,
+    First,
+    Recurring,
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Indicates whether the payment is captured automatically or has to be captured manually
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum CaptureMode {
+    Automatic,
+    Manual,
+    #[strum(default)]
+    Unknown(String),
+}
+
+string_enum_serde!(PaymentStatus, SequenceType, CaptureMode);
 
 /// Organization model; More information:
 /// - <https://docs.mollie.com/reference/v2/organizations-api/get-organization#response>
@@ -10,15 +55,15 @@ use super::{amount::Amount, link::Link};
 pub struct PaymentResource {
     /// Unique identifier of the organization
     pub id: String,
-    pub mode: String,
-    pub created_at: String,
-    pub status: String,
+    pub mode: Mode,
+    pub created_at: DateTime<FixedOffset>,
+    pub status: PaymentStatus,
     pub is_cancelable: Option<bool>,
-    pub authorized_at: Option<String>,
-    pub paid_at: Option<String>,
-    pub expires_at: Option<String>,
-    pub expired_at: Option<String>,
-    pub failed_at: Option<String>,
+    pub authorized_at: Option<DateTime<FixedOffset>>,
+    pub paid_at: Option<DateTime<FixedOffset>>,
+    pub expires_at: Option<DateTime<FixedOffset>>,
+    pub expired_at: Option<DateTime<FixedOffset>>,
+    pub failed_at: Option<DateTime<FixedOffset>>,
     pub amount: Amount,
     pub amount_refunded: Option<Amount>,
     pub amount_remaining: Option<Amount>,
@@ -26,37 +71,461 @@ pub struct PaymentResource {
     pub amount_chargedback: Option<Amount>,
     pub settlement_amount: Option<Amount>,
     pub description: String,
-    pub redirect_url: String,
+    /// Not set for recurring payments, nor for the payments of an order
+    pub redirect_url: Option<String>,
     pub webhook_url: Option<String>,
     pub locale: Option<String>,
     pub country_code: Option<String>,
-    pub method: Option<String>,
+    pub method: Option<PaymentMethod>,
     pub restrict_payment_methods_to_country: Option<String>,
     pub profile_id: String,
+    pub sequence_type: Option<SequenceType>,
+    pub capture_mode: Option<CaptureMode>,
     pub settlement_id: Option<String>,
     #[serde(rename = "_links")]
     pub links: HashMap<String, Link>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct PaymentsListResource {
-    pub count: i32,
-    #[serde(rename = "_embedded")]
-    pub embedded: EmbeddedPayments,
-    #[serde(rename = "_links")]
-    pub links: HashMap<String, Option<Link>>,
+pub type PaymentsListResource = ListResource<PaymentResource>;
+
+impl PaymentResource {
+    /// Part of the authorized amount that hasn't been captured yet, `None` when the payment isn't authorized
+    pub fn capturable_amount(&self) -> Option<Amount> {
+        if self.status != PaymentStatus::Authorized {
+            return None;
+        }
+
+        match &self.amount_captured {
+            Some(captured) => self.amount.checked_sub(captured).ok(),
+            None => Some(self.amount.clone()),
+        }
+    }
+
+    /// Check an amount can be captured, the whole remaining authorization when no amount is given.
+    /// Returns the amount that will be captured.
+    pub fn validate_capture(&self, amount: Option<&Amount>) -> Result<Amount, Error> {
+        let remaining = self
+            .capturable_amount()
+            .filter(|remaining| !remaining.is_zero())
+            .ok_or_else(|| Error::NotCapturable {
+                id: self.id.clone(),
+                status: self.status.to_string(),
+            })?;
+
+        let Some(amount) = amount else {
+            return Ok(remaining);
+        };
+
+        if amount.currency() != remaining.currency() {
+            return Err(Error::CurrencyMismatch {
+                expected: remaining.currency().to_string(),
+                actual: amount.currency().to_string(),
+            });
+        }
+        if amount.is_zero() || amount > &remaining {
+            return Err(Error::InvalidCaptureAmount {
+                amount: amount.to_string(),
+                remaining: remaining.to_string(),
+            });
+        }
+
+        Ok(amount.clone())
+    }
+}
+
+/// Type of a payment or order line
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum LineType {
+    Physical,
+    Digital,
+    ShippingFee,
+    Discount,
+    StoreCredit,
+    GiftCard,
+    Surcharge,
+    #[strum(default)]
+    Unknown(String),
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct EmbeddedPayments {
-    pub payments: Vec<PaymentResource>,
+string_enum_serde!(LineType);
+
+/// Line of a payment, describing a product or fee; More information:
+/// - <https://docs.mollie.com/reference/v2/payments-api/create-payment#parameters>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentLine {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub line_type: Option<LineType>,
+    pub description: String,
+    pub quantity: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity_unit: Option<String>,
+    pub unit_price: Amount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount_amount: Option<Amount>,
+    pub total_amount: Amount,
+    /// VAT rate as a percentage string, e.g. `"21.00"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vat_rate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vat_amount: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_url: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Request body of [Create payment](https://docs.mollie.com/reference/v2/payments-api/create-payment),
+/// use [`CreatePaymentRequest::builder`] to set the optional parameters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreatePaymentRequest {
     pub amount: Amount,
     pub description: String,
-    pub redirect_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lines: Option<Vec<PaymentLine>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<Address>,
+    /// Locale of the checkout, e.g. `nl_NL`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// Payment methods the customer can choose from, all enabled methods when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<Vec<PaymentMethod>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restrict_payment_methods_to_country: Option<String>,
+    /// Any JSON value, returned as is when fetching the payment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence_type: Option<SequenceType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_mode: Option<CaptureMode>,
+    /// Delay before an authorized payment is captured, e.g. `8 hours` or `2 days`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_delay: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<FixedOffset>>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub profile_id: Option<String>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+impl CreatePaymentRequest {
+    /// Start building a request for a payment of the given amount
+    pub fn builder(amount: Amount, description: impl Into<String>) -> CreatePaymentRequestBuilder {
+        CreatePaymentRequestBuilder::new(amount, description)
+    }
+}
+
+/// Builder used to create a `CreatePaymentRequest` with any of its optional parameters
+#[derive(Debug, Clone)]
+pub struct CreatePaymentRequestBuilder {
+    request: CreatePaymentRequest,
+}
+
+impl CreatePaymentRequestBuilder {
+    pub fn new(amount: Amount, description: impl Into<String>) -> Self {
+        Self {
+            request: CreatePaymentRequest {
+                amount,
+                description: description.into(),
+                redirect_url: None,
+                cancel_url: None,
+                webhook_url: None,
+                lines: None,
+                billing_address: None,
+                shipping_address: None,
+                locale: None,
+                method: None,
+                restrict_payment_methods_to_country: None,
+                metadata: None,
+                sequence_type: None,
+                customer_id: None,
+                capture_mode: None,
+                capture_delay: None,
+                expires_at: None,
+                profile_id: None,
+                testmode: None,
+            },
+        }
+    }
+
+    /// Url the customer is redirected to after the payment
+    pub fn redirect_url(mut self, redirect_url: impl Into<String>) -> Self {
+        self.request.redirect_url = Some(redirect_url.into());
+        self
+    }
+
+    /// Url the customer is redirected to after canceling the payment
+    pub fn cancel_url(mut self, cancel_url: impl Into<String>) -> Self {
+        self.request.cancel_url = Some(cancel_url.into());
+        self
+    }
+
+    /// Url Mollie calls when the status of the payment changes
+    pub fn webhook_url(mut self, webhook_url: impl Into<String>) -> Self {
+        self.request.webhook_url = Some(webhook_url.into());
+        self
+    }
+
+    pub fn lines(mut self, lines: Vec<PaymentLine>) -> Self {
+        self.request.lines = Some(lines);
+        self
+    }
+
+    pub fn billing_address(mut self, address: Address) -> Self {
+        self.request.billing_address = Some(address);
+        self
+    }
+
+    pub fn shipping_address(mut self, address: Address) -> Self {
+        self.request.shipping_address = Some(address);
+        self
+    }
+
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.request.locale = Some(locale.into());
+        self
+    }
+
+    /// Only allow this payment method, can be called more than once to allow several methods
+    pub fn method(mut self, method: PaymentMethod) -> Self {
+        self.request
+            .method
+            .get_or_insert_with(Vec::new)
+            .push(method);
+        self
+    }
+
+    /// Only allow these payment methods
+    pub fn methods(mut self, methods: impl IntoIterator<Item = PaymentMethod>) -> Self {
+        self.request
+            .method
+            .get_or_insert_with(Vec::new)
+            .extend(methods);
+        self
+    }
+
+    pub fn restrict_payment_methods_to_country(mut self, country: impl Into<String>) -> Self {
+        self.request.restrict_payment_methods_to_country = Some(country.into());
+        self
+    }
+
+    pub fn metadata(mut self, metadata: serde_json::Value) -> Self {
+        self.request.metadata = Some(metadata);
+        self
+    }
+
+    pub fn sequence_type(mut self, sequence_type: SequenceType) -> Self {
+        self.request.sequence_type = Some(sequence_type);
+        self
+    }
+
+    pub fn customer_id(mut self, customer_id: impl Into<String>) -> Self {
+        self.request.customer_id = Some(customer_id.into());
+        self
+    }
+
+    pub fn capture_mode(mut self, capture_mode: CaptureMode) -> Self {
+        self.request.capture_mode = Some(capture_mode);
+        self
+    }
+
+    pub fn capture_delay(mut self, capture_delay: impl Into<String>) -> Self {
+        self.request.capture_delay = Some(capture_delay.into());
+        self
+    }
+
+    pub fn expires_at(mut self, expires_at: DateTime<FixedOffset>) -> Self {
+        self.request.expires_at = Some(expires_at);
+        self
+    }
+
+    pub fn profile_id(mut self, profile_id: impl Into<String>) -> Self {
+        self.request.profile_id = Some(profile_id.into());
+        self
+    }
+
+    pub fn testmode(mut self, testmode: bool) -> Self {
+        self.request.testmode = Some(testmode);
+        self
+    }
+
+    pub fn build(self) -> CreatePaymentRequest {
+        self.request
+    }
+}
+
+/// Request body of [Update payment](https://docs.mollie.com/reference/v2/payments-api/update-payment),
+/// only the parameters that are set are changed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePaymentRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<Vec<PaymentMethod>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restrict_payment_methods_to_country: Option<String>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_parse_payment_enums() {
+        let payment: PaymentResource = serde_json::from_str(
+            r#"{
+                "id": "tr_7UhSN1zuXS",
+                "mode": "test",
+                "createdAt": "2018-03-20T09:13:37+00:00",
+                "status": "canceled",
+                "amount": { "value": "10.00", "currency": "EUR" },
+                "description": "Order #12345",
+                "redirectUrl": "https://webshop.example.org/order/12345/",
+                "method": "ideal",
+                "profileId": "pfl_QkEhN94Ba",
+                "sequenceType": "oneoff",
+                "captureMode": "manual",
+                "expiresAt": "2018-03-20T09:28:37+01:00",
+                "_links": {}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(payment.mode, Mode::Test);
+        assert_eq!(payment.status, PaymentStatus::Canceled);
+        assert_eq!(payment.method, Some(PaymentMethod::Ideal));
+        assert_eq!(payment.sequence_type, Some(SequenceType::OneOff));
+        assert_eq!(payment.capture_mode, Some(CaptureMode::Manual));
+        assert_eq!(payment.created_at.to_rfc3339(), "2018-03-20T09:13:37+00:00");
+        assert_eq!(
+            payment.expires_at.unwrap().offset(),
+            &FixedOffset::east_opt(3600).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_validate_captures_against_the_remaining_authorization() {
+        let payment: PaymentResource = serde_json::from_str(
+            r#"{
+                "id": "tr_7UhSN1zuXS",
+                "mode": "test",
+                "createdAt": "2018-03-20T09:13:37+00:00",
+                "status": "authorized",
+                "amount": { "value": "10.00", "currency": "EUR" },
+                "amountCaptured": { "value": "4.00", "currency": "EUR" },
+                "description": "Order #12345",
+                "profileId": "pfl_QkEhN94Ba",
+                "_links": {}
+            }"#,
+        )
+        .unwrap();
+        let eur = |value| Amount::parse(value, "EUR").unwrap();
+
+        assert_eq!(payment.validate_capture(None).unwrap(), eur("6.00"));
+        assert_eq!(
+            payment.validate_capture(Some(&eur("2.50"))).unwrap(),
+            eur("2.50")
+        );
+        assert!(matches!(
+            payment.validate_capture(Some(&eur("6.01"))),
+            Err(Error::InvalidCaptureAmount { .. })
+        ));
+        assert!(matches!(
+            payment.validate_capture(Some(&Amount::parse("1.00", "USD").unwrap())),
+            Err(Error::CurrencyMismatch { .. })
+        ));
+
+        let paid = PaymentResource {
+            status: PaymentStatus::Paid,
+            ..payment
+        };
+        assert!(matches!(
+            paid.validate_capture(None),
+            Err(Error::NotCapturable { .. })
+        ));
+    }
+
+    #[test]
+    fn should_keep_unknown_payment_status() {
+        let status: PaymentStatus = serde_json::from_str(r#""refunding""#).unwrap();
+
+        assert_eq!(status, PaymentStatus::Unknown("refunding".to_string()));
+        assert_eq!(serde_json::to_string(&status).unwrap(), r#""refunding""#);
+    }
+
+    #[test]
+    fn should_only_serialize_the_parameters_that_are_set() {
+        let request =
+            CreatePaymentRequest::builder(Amount::parse("10.00", "EUR").unwrap(), "Order #12345")
+                .redirect_url("https://webshop.example.org/order/12345/")
+                .method(PaymentMethod::Ideal)
+                .method(PaymentMethod::CreditCard)
+                .metadata(serde_json::json!({ "order_id": "12345" }))
+                .sequence_type(SequenceType::First)
+                .build();
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "amount": { "value": "10.00", "currency": "EUR" },
+                "description": "Order #12345",
+                "redirectUrl": "https://webshop.example.org/order/12345/",
+                "method": ["ideal", "creditcard"],
+                "metadata": { "order_id": "12345" },
+                "sequenceType": "first"
+            })
+        );
+    }
+
+    #[test]
+    fn should_only_send_the_updated_parameters() {
+        let request = UpdatePaymentRequest {
+            description: Some(String::from("Order #98765")),
+            webhook_url: Some(String::from(
+                "https://webshop.example.org/payments/webhook/",
+            )),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "description": "Order #98765",
+                "webhookUrl": "https://webshop.example.org/payments/webhook/"
+            })
+        );
+    }
 }
@@ -0,0 +1,196 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::{amount::Amount, link::Link, list::ListResource, method::PaymentMethod, mode::Mode};
+
+/// Payment link model; More information:
+/// - <https://docs.mollie.com/reference/v2/payment-links-api/get-payment-link#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentLinkResource {
+    /// Unique identifier of the payment link, e.g. `pl_4Y0eZitmBnQ6IDoMqZQKh`
+    pub id: String,
+    pub mode: Mode,
+    pub description: String,
+    /// Amount to pay, the customer chooses the amount when not set
+    pub amount: Option<Amount>,
+    /// Lowest amount the customer can choose, when the link has no fixed amount
+    pub minimum_amount: Option<Amount>,
+    /// Archived links can no longer be paid
+    #[serde(default)]
+    pub archived: bool,
+    /// Reusable links can be paid more than once
+    #[serde(default)]
+    pub reusable: bool,
+    pub redirect_url: Option<String>,
+    pub webhook_url: Option<String>,
+    pub profile_id: Option<String>,
+    pub allowed_methods: Option<Vec<PaymentMethod>>,
+    pub created_at: DateTime<FixedOffset>,
+    pub paid_at: Option<DateTime<FixedOffset>>,
+    pub updated_at: Option<DateTime<FixedOffset>>,
+    pub expires_at: Option<DateTime<FixedOffset>>,
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+pub type PaymentLinksListResource = ListResource<PaymentLinkResource>;
+
+impl PaymentLinkResource {
+    /// Url to share with the customer to pay
+    pub fn payment_link(&self) -> Option<&str> {
+        self.links.get("paymentLink").map(|link| link.href.as_str())
+    }
+}
+
+/// Request body of [Create payment link](https://docs.mollie.com/reference/v2/payment-links-api/create-payment-link)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePaymentLinkRequest {
+    pub amount: Amount,
+    pub description: String,
+    /// When the link expires, it never expires when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<FixedOffset>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reusable: Option<bool>,
+    /// Payment methods the customer can choose from, all enabled methods when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_methods: Option<Vec<PaymentMethod>>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_id: Option<String>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+impl CreatePaymentLinkRequest {
+    /// Request for a link to pay the amount once, that never expires
+    pub fn new(amount: Amount, description: impl Into<String>) -> Self {
+        Self {
+            amount,
+            description: description.into(),
+            expires_at: None,
+            redirect_url: None,
+            webhook_url: None,
+            reusable: None,
+            allowed_methods: None,
+            profile_id: None,
+            testmode: None,
+        }
+    }
+}
+
+/// Request body of [Update payment link](https://docs.mollie.com/reference/v2/payment-links-api/update-payment-link),
+/// only the parameters that are set are changed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePaymentLinkRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_amount: Option<Amount>,
+    /// Archive the link so it can no longer be paid, or unarchive it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_methods: Option<Vec<PaymentMethod>>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_parse_payment_link() {
+        let link: PaymentLinkResource = serde_json::from_str(
+            r#"{
+                "resource": "payment-link",
+                "id": "pl_4Y0eZitmBnQ6IDoMqZQKh",
+                "mode": "live",
+                "description": "Bicycle tires",
+                "amount": { "currency": "EUR", "value": "24.95" },
+                "archived": false,
+                "redirectUrl": "https://webshop.example.org/thanks",
+                "webhookUrl": "https://webshop.example.org/payment-links/webhook",
+                "profileId": "pfl_QkEhN94Ba",
+                "createdAt": "2021-03-20T09:29:56+00:00",
+                "expiresAt": "2023-06-06T11:00:00+00:00",
+                "_links": {
+                    "self": {
+                        "href": "https://api.mollie.com/v2/payment-links/pl_4Y0eZitmBnQ6IDoMqZQKh",
+                        "type": "application/hal+json"
+                    },
+                    "paymentLink": {
+                        "href": "https://paymentlink.mollie.com/payment/4Y0eZitmBnQ6IDoMqZQKh/",
+                        "type": "text/html"
+                    },
+                    "documentation": {
+                        "href": "https://docs.mollie.com/reference/v2/payment-links-api/get-payment-link",
+                        "type": "text/html"
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(link.amount, Some(Amount::parse("24.95", "EUR").unwrap()));
+        assert!(!link.reusable);
+        assert!(link.paid_at.is_none());
+        assert_eq!(
+            link.expires_at.map(|expires_at| expires_at.to_rfc3339()),
+            Some(String::from("2023-06-06T11:00:00+00:00"))
+        );
+        assert_eq!(
+            link.payment_link(),
+            Some("https://paymentlink.mollie.com/payment/4Y0eZitmBnQ6IDoMqZQKh/")
+        );
+
+        let paid: PaymentLinkResource = serde_json::from_value(serde_json::json!({
+            "id": "pl_4Y0eZitmBnQ6IDoMqZQKh",
+            "mode": "test",
+            "description": "Bicycle tires",
+            "amount": null,
+            "minimumAmount": { "currency": "EUR", "value": "5.00" },
+            "createdAt": "2021-03-20T09:29:56+00:00",
+            "paidAt": "2021-03-21T10:00:00+01:00",
+            "expiresAt": null,
+            "_links": {}
+        }))
+        .unwrap();
+
+        assert!(paid.amount.is_none());
+        assert!(paid.expires_at.is_none());
+        assert_eq!(
+            paid.paid_at.map(|paid_at| paid_at.to_rfc3339()),
+            Some(String::from("2021-03-21T10:00:00+01:00"))
+        );
+        assert_eq!(paid.payment_link(), None);
+    }
+
+    #[test]
+    fn should_only_serialize_the_parameters_that_are_set() {
+        let request = CreatePaymentLinkRequest {
+            redirect_url: Some(String::from("https://webshop.example.org/thanks")),
+            ..CreatePaymentLinkRequest::new(Amount::parse("24.95", "EUR").unwrap(), "Bicycle tires")
+        };
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "amount": { "currency": "EUR", "value": "24.95" },
+                "description": "Bicycle tires",
+                "redirectUrl": "https://webshop.example.org/thanks"
+            })
+        );
+    }
+}
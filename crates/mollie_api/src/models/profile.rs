@@ -0,0 +1,169 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum::{Display, EnumString};
+
+use super::{link::Link, list::ListResource, mode::Mode};
+
+/// [Profile status](https://docs.mollie.com/reference/v2/profiles-api/get-profile#response)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum ProfileStatus {
+    Unverified,
+    Verified,
+    Blocked,
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Status of a gift card issuer enabled on a profile
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum IssuerStatus {
+    Activated,
+    /// The issuer still has to approve the profile
+    PendingIssuer,
+    #[strum(default)]
+    Unknown(String),
+}
+
+string_enum_serde!(ProfileStatus, IssuerStatus);
+
+/// Review of changes made to a profile, which Mollie has to approve
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProfileReview {
+    /// Either `pending` or `rejected`
+    pub status: String,
+}
+
+/// Profile model; More information:
+/// - <https://docs.mollie.com/reference/v2/profiles-api/get-profile#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileResource {
+    /// Unique identifier of the profile, e.g. `pfl_v9hTwCvYqw`
+    pub id: String,
+    pub mode: Mode,
+    pub name: String,
+    pub website: String,
+    pub email: String,
+    pub phone: String,
+    pub description: Option<String>,
+    /// ISO 3166-1 alpha-2 codes of the countries the profile sells in
+    pub countries_of_activity: Option<Vec<String>>,
+    /// Industry of the profile, e.g. `RETAIL`
+    pub business_category: Option<String>,
+    pub status: ProfileStatus,
+    /// Only set when changes of the profile are being reviewed
+    pub review: Option<ProfileReview>,
+    pub created_at: DateTime<FixedOffset>,
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+pub type ProfilesListResource = ListResource<ProfileResource>;
+
+/// Gift card issuer enabled on a profile; More information:
+/// - <https://docs.mollie.com/reference/v2/profiles-api/enable-gift-card-issuer#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GiftCardIssuerResource {
+    /// Identifier of the issuer, e.g. `festivalcadeau`
+    pub id: String,
+    pub description: String,
+    pub status: IssuerStatus,
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+/// Request body of [Create profile](https://docs.mollie.com/reference/v2/profiles-api/create-profile)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProfileRequest {
+    pub name: String,
+    pub website: String,
+    pub email: String,
+    pub phone: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countries_of_activity: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_category: Option<String>,
+    /// Create the profile in `live` (default) or `test` mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<Mode>,
+}
+
+impl CreateProfileRequest {
+    pub fn new(
+        name: impl Into<String>,
+        website: impl Into<String>,
+        email: impl Into<String>,
+        phone: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            website: website.into(),
+            email: email.into(),
+            phone: phone.into(),
+            description: None,
+            countries_of_activity: None,
+            business_category: None,
+            mode: None,
+        }
+    }
+}
+
+/// Request body of [Update profile](https://docs.mollie.com/reference/v2/profiles-api/update-profile),
+/// only the parameters that are set are changed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateProfileRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countries_of_activity: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<Mode>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_parse_profile() {
+        let profile: ProfileResource = serde_json::from_str(
+            r#"{
+                "resource": "profile",
+                "id": "pfl_v9hTwCvYqw",
+                "mode": "live",
+                "name": "My website name",
+                "website": "https://shop.example.org",
+                "email": "info@example.org",
+                "phone": "+31208202070",
+                "businessCategory": "OTHER_MERCHANDISE",
+                "status": "verified",
+                "review": { "status": "pending" },
+                "createdAt": "2018-03-20T09:28:37+00:00",
+                "_links": {}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(profile.id, "pfl_v9hTwCvYqw");
+        assert_eq!(profile.status, ProfileStatus::Verified);
+        assert_eq!(profile.review.unwrap().status, "pending");
+        assert!(profile.countries_of_activity.is_none());
+    }
+}
@@ -1,8 +1,10 @@
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Display;
+use strum::{Display, EnumString};
 
-use super::{amount::Amount, link::Link};
+use super::{amount::Amount, link::Link, list::ListResource};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RefundPaymentRequest {
@@ -10,26 +12,87 @@ pub struct RefundPaymentRequest {
     pub description: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+/// [Refund status](https://docs.mollie.com/reference/v2/refunds-api/get-payment-refund#response)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum RefundStatus {
+    Queued,
+    Pending,
+    Processing,
+    Refunded,
+    Failed,
+    Canceled,
+    #[strum(default)]
+    Unknown(String),
+}
+
+string_enum_serde!(RefundStatus);
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 #[allow(dead_code)]
 pub struct RefundResource {
     pub id: String,
     pub amount: Amount,
-    pub status: String,
-    pub created_at: String,
+    pub settlement_amount: Option<Amount>,
+    pub status: RefundStatus,
+    pub created_at: DateTime<FixedOffset>,
     pub description: String,
+    pub metadata: Option<serde_json::Value>,
     pub payment_id: String,
+    pub order_id: Option<String>,
+    pub settlement_id: Option<String>,
     #[serde(rename = "_links")]
     pub links: HashMap<String, Link>,
 }
 
+pub type RefundsListResource = ListResource<RefundResource>;
+
+impl RefundResource {
+    /// Only queued and pending refunds can still be canceled
+    pub fn is_cancelable(&self) -> bool {
+        matches!(self.status, RefundStatus::Queued | RefundStatus::Pending)
+    }
+}
+
 impl Display for RefundResource {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
             "[{}] {} | {} | {} | {} | {} |",
-            self.id, self.status, self.amount, self.created_at, self.description, self.payment_id,
+            self.id,
+            self.status,
+            self.amount,
+            self.created_at.to_rfc3339(),
+            self.description,
+            self.payment_id,
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_parse_refund() {
+        let refund: RefundResource = serde_json::from_str(
+            r#"{
+                "resource": "refund",
+                "id": "re_4qqhO89gsT",
+                "amount": { "currency": "EUR", "value": "5.95" },
+                "status": "pending",
+                "createdAt": "2018-03-14T17:09:02+00:00",
+                "description": "Order #33",
+                "metadata": { "bookkeeping_id": 12345 },
+                "paymentId": "tr_WDqYK6vllg",
+                "_links": {}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(refund.status, RefundStatus::Pending);
+        assert!(refund.is_cancelable());
+        assert_eq!(refund.amount.to_string(), "EUR 5.95");
+    }
+}
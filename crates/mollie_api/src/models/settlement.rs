@@ -0,0 +1,225 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use strum::{Display, EnumString};
+
+use super::{amount::Amount, link::Link, list::ListResource, method::PaymentMethod};
+use crate::error::Error;
+
+/// [Settlement status](https://docs.mollie.com/reference/v2/settlements-api/get-settlement#response)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum SettlementStatus {
+    /// Not yet closed, new payments are still added to it
+    Open,
+    Pending,
+    Paidout,
+    Failed,
+    #[strum(default)]
+    Unknown(String),
+}
+
+string_enum_serde!(SettlementStatus);
+
+/// Revenue of a payment method in a settlement period
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementRevenue {
+    pub description: String,
+    /// `None` for revenue that isn't tied to a payment method, e.g. refunds
+    pub method: Option<PaymentMethod>,
+    pub count: u32,
+    pub amount_net: Amount,
+    pub amount_vat: Option<Amount>,
+    pub amount_gross: Amount,
+}
+
+/// Rate Mollie charges for a cost of a settlement
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SettlementRate {
+    pub fixed: Option<Amount>,
+    /// Percentage of the amount, e.g. `"1.8"`
+    pub percentage: Option<String>,
+}
+
+/// Costs of a payment method in a settlement period
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementCost {
+    pub description: String,
+    pub method: Option<PaymentMethod>,
+    pub count: u32,
+    pub rate: Option<SettlementRate>,
+    pub amount_net: Amount,
+    pub amount_vat: Option<Amount>,
+    pub amount_gross: Amount,
+}
+
+/// Revenue and costs of a settlement in a single month
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementPeriod {
+    #[serde(default)]
+    pub revenue: Vec<SettlementRevenue>,
+    #[serde(default)]
+    pub costs: Vec<SettlementCost>,
+    /// Invoice the costs of the period were added to
+    pub invoice_id: Option<String>,
+    pub invoice_reference: Option<String>,
+}
+
+/// Revenue and costs of a payment method, summed over the periods of a settlement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettlementMethodTotals {
+    /// Number of transactions that made up the revenue
+    pub count: u32,
+    pub revenue: Option<Amount>,
+    pub costs: Option<Amount>,
+}
+
+/// Settlement model; More information:
+/// - <https://docs.mollie.com/reference/v2/settlements-api/get-settlement#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementResource {
+    /// Unique identifier of the settlement, e.g. `stl_jDk30akdN`
+    pub id: String,
+    /// Reference shown on the bank statement, e.g. `1234567.1804.03`
+    pub reference: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    /// Not set while the settlement is still open
+    pub settled_at: Option<DateTime<FixedOffset>>,
+    pub status: SettlementStatus,
+    /// Total amount paid out, which can be negative
+    pub amount: Amount,
+    /// Revenue and costs per year and month, e.g. `periods["2018"]["04"]`
+    #[serde(default)]
+    pub periods: BTreeMap<String, BTreeMap<String, SettlementPeriod>>,
+    pub invoice_id: Option<String>,
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+pub type SettlementsListResource = ListResource<SettlementResource>;
+
+impl SettlementResource {
+    /// Gross revenue and costs per payment method, summed over every period.
+    /// Revenue and costs that aren't tied to a method are grouped under `None`.
+    pub fn totals_by_method(
+        &self,
+    ) -> Result<BTreeMap<Option<String>, SettlementMethodTotals>, Error> {
+        let mut totals = BTreeMap::<Option<String>, SettlementMethodTotals>::new();
+        let periods = self.periods.values().flat_map(BTreeMap::values);
+
+        for period in periods {
+            for revenue in &period.revenue {
+                let method = revenue.method.as_ref().map(PaymentMethod::to_string);
+                let total = totals.entry(method).or_insert_with(empty_totals);
+                total.count += revenue.count;
+                total.revenue = Some(add(&total.revenue, &revenue.amount_gross)?);
+            }
+            for cost in &period.costs {
+                let method = cost.method.as_ref().map(PaymentMethod::to_string);
+                let total = totals.entry(method).or_insert_with(empty_totals);
+                total.costs = Some(add(&total.costs, &cost.amount_gross)?);
+            }
+        }
+
+        Ok(totals)
+    }
+}
+
+fn empty_totals() -> SettlementMethodTotals {
+    SettlementMethodTotals {
+        count: 0,
+        revenue: None,
+        costs: None,
+    }
+}
+
+fn add(total: &Option<Amount>, amount: &Amount) -> Result<Amount, Error> {
+    match total {
+        Some(total) => total.checked_add(amount),
+        None => Ok(amount.clone()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_sum_periods_per_method() {
+        let settlement: SettlementResource = serde_json::from_str(
+            r#"{
+                "resource": "settlement",
+                "id": "stl_jDk30akdN",
+                "reference": "1234567.1804.03",
+                "createdAt": "2018-04-06T06:00:01.0Z",
+                "settledAt": "2018-04-06T09:41:44.0Z",
+                "status": "paidout",
+                "amount": { "value": "39.75", "currency": "EUR" },
+                "periods": {
+                    "2018": {
+                        "03": {
+                            "revenue": [{
+                                "description": "iDEAL",
+                                "method": "ideal",
+                                "count": 2,
+                                "amountNet": { "value": "20.00", "currency": "EUR" },
+                                "amountVat": null,
+                                "amountGross": { "value": "20.00", "currency": "EUR" }
+                            }],
+                            "costs": [{
+                                "description": "iDEAL",
+                                "method": "ideal",
+                                "count": 2,
+                                "rate": { "fixed": { "value": "0.29", "currency": "EUR" }, "percentage": null },
+                                "amountNet": { "value": "0.58", "currency": "EUR" },
+                                "amountVat": { "value": "0.12", "currency": "EUR" },
+                                "amountGross": { "value": "0.70", "currency": "EUR" }
+                            }],
+                            "invoiceId": "inv_FrvewDA3Pr"
+                        },
+                        "04": {
+                            "revenue": [{
+                                "description": "iDEAL",
+                                "method": "ideal",
+                                "count": 1,
+                                "amountNet": { "value": "10.00", "currency": "EUR" },
+                                "amountVat": null,
+                                "amountGross": { "value": "10.00", "currency": "EUR" }
+                            }, {
+                                "description": "Refunds iDEAL",
+                                "method": null,
+                                "count": 1,
+                                "amountNet": { "value": "-5.00", "currency": "EUR" },
+                                "amountVat": null,
+                                "amountGross": { "value": "-5.00", "currency": "EUR" }
+                            }]
+                        }
+                    }
+                },
+                "_links": {}
+            }"#,
+        )
+        .unwrap();
+
+        let totals = settlement.totals_by_method().unwrap();
+
+        assert_eq!(settlement.status, SettlementStatus::Paidout);
+        assert_eq!(
+            totals[&Some("ideal".to_string())],
+            SettlementMethodTotals {
+                count: 3,
+                revenue: Some(Amount::parse("30.00", "EUR").unwrap()),
+                costs: Some(Amount::parse("0.70", "EUR").unwrap()),
+            }
+        );
+        assert_eq!(
+            totals[&None].revenue,
+            Some(Amount::parse("-5.00", "EUR").unwrap())
+        );
+        assert_eq!(totals[&None].costs, None);
+    }
+}
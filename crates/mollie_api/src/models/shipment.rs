@@ -0,0 +1,88 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::{
+    link::Link,
+    list::ListResource,
+    order::{OrderLine, OrderLineQuantity},
+};
+
+/// Track & trace information of a shipment
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tracking {
+    /// Name of the postal carrier, e.g. `PostNL`
+    pub carrier: String,
+    /// Track & trace code of the carrier
+    pub code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Shipment model; More information:
+/// - <https://docs.mollie.com/reference/v2/shipments-api/get-shipment#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipmentResource {
+    /// Unique identifier of the shipment, e.g. `shp_3wmsgCJN4U`
+    pub id: String,
+    pub order_id: String,
+    pub created_at: DateTime<FixedOffset>,
+    pub tracking: Option<Tracking>,
+    /// Order lines (partially) shipped, with the shipped quantity and amount
+    #[serde(default)]
+    pub lines: Vec<OrderLine>,
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+pub type ShipmentsListResource = ListResource<ShipmentResource>;
+
+/// Request body of [Create shipment](https://docs.mollie.com/reference/v2/shipments-api/create-shipment)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateShipmentRequest {
+    /// Lines to ship, all shippable lines of the order when empty
+    pub lines: Vec<OrderLineQuantity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking: Option<Tracking>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+/// Request body of [Update shipment](https://docs.mollie.com/reference/v2/shipments-api/update-shipment)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateShipmentRequest {
+    pub tracking: Tracking,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_ship_all_lines_by_default() {
+        let request = CreateShipmentRequest {
+            tracking: Some(Tracking {
+                carrier: String::from("PostNL"),
+                code: String::from("3SKABA000000000"),
+                url: None,
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "lines": [],
+                "tracking": { "carrier": "PostNL", "code": "3SKABA000000000" }
+            })
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use chrono::{DateTime, Days, FixedOffset, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use strum::{Display, EnumString};
+
+use super::{amount::Amount, link::Link, list::ListResource, method::PaymentMethod, mode::Mode};
+use crate::error::Error;
+
+/// [Subscription status](https://docs.mollie.com/reference/v2/subscriptions-api/get-subscription#response)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum SubscriptionStatus {
+    Pending,
+    Active,
+    Canceled,
+    Suspended,
+    Completed,
+    #[strum(default)]
+    Unknown(String),
+}
+
+string_enum_serde!(SubscriptionStatus);
+
+/// Unit of a subscription interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntervalUnit {
+    Day,
+    Week,
+    Month,
+}
+
+/// Interval between two charges of a subscription, e.g. `1 month` or `14 days`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Interval {
+    pub count: u32,
+    pub unit: IntervalUnit,
+}
+
+impl Interval {
+    pub fn new(count: u32, unit: IntervalUnit) -> Self {
+        Self { count, unit }
+    }
+
+    /// Date the given number of intervals after the date, `None` if it's out of range
+    pub fn date_after(&self, date: NaiveDate, intervals: u32) -> Option<NaiveDate> {
+        let count = self.count.checked_mul(intervals)?;
+        match self.unit {
+            IntervalUnit::Day => date.checked_add_days(Days::new(count.into())),
+            IntervalUnit::Week => date.checked_add_days(Days::new(u64::from(count) * 7)),
+            IntervalUnit::Month => date.checked_add_months(Months::new(count)),
+        }
+    }
+}
+
+impl FromStr for Interval {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidInterval(value.to_string());
+
+        let (count, unit) = value.trim().split_once(' ').ok_or_else(invalid)?;
+        let count = count.parse::<u32>().map_err(|_| invalid())?;
+        let unit = match unit.trim() {
+            "day" | "days" => IntervalUnit::Day,
+            "week" | "weeks" => IntervalUnit::Week,
+            "month" | "months" => IntervalUnit::Month,
+            _ => return Err(invalid()),
+        };
+
+        if count == 0 {
+            return Err(invalid());
+        }
+
+        Ok(Self { count, unit })
+    }
+}
+
+impl TryFrom<String> for Interval {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Interval> for String {
+    fn from(interval: Interval) -> Self {
+        interval.to_string()
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unit = match self.unit {
+            IntervalUnit::Day => "day",
+            IntervalUnit::Week => "week",
+            IntervalUnit::Month => "month",
+        };
+        let plural = if self.count == 1 { "" } else { "s" };
+
+        write!(f, "{} {}{}", self.count, unit, plural)
+    }
+}
+
+/// Subscription model; More information:
+/// - <https://docs.mollie.com/reference/v2/subscriptions-api/get-subscription#response>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionResource {
+    /// Unique identifier of the subscription, e.g. `sub_rVKGtNd6s3`
+    pub id: String,
+    pub mode: Mode,
+    pub created_at: DateTime<FixedOffset>,
+    pub status: SubscriptionStatus,
+    pub amount: Amount,
+    /// Total number of charges, `None` for subscriptions that run until they are canceled
+    pub times: Option<u32>,
+    /// Number of charges left, `None` for subscriptions that run until they are canceled
+    pub times_remaining: Option<u32>,
+    pub interval: Interval,
+    pub start_date: NaiveDate,
+    /// Date of the next charge, not set when the subscription is no longer active
+    pub next_payment_date: Option<NaiveDate>,
+    pub description: String,
+    pub method: Option<PaymentMethod>,
+    pub mandate_id: Option<String>,
+    pub canceled_at: Option<DateTime<FixedOffset>>,
+    pub webhook_url: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub customer_id: String,
+    #[serde(rename = "_links")]
+    pub links: HashMap<String, Link>,
+}
+
+pub type SubscriptionsListResource = ListResource<SubscriptionResource>;
+
+impl SubscriptionResource {
+    /// Date of the last charge, `None` if the subscription runs until it is canceled
+    /// or has no charges left
+    pub fn last_payment_date(&self) -> Option<NaiveDate> {
+        if self.times_remaining? == 0 {
+            return None;
+        }
+        // Counted from the start date, stepping from charge to charge drifts at month ends
+        let intervals = self.times?.checked_sub(1)?;
+        self.interval.date_after(self.start_date, intervals)
+    }
+}
+
+/// Request body of [Create subscription](https://docs.mollie.com/reference/v2/subscriptions-api/create-subscription)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSubscriptionRequest {
+    pub amount: Amount,
+    pub interval: Interval,
+    pub description: String,
+    /// Total number of charges, the subscription runs until it's canceled when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<u32>,
+    /// Date of the first charge, today when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<PaymentMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+impl CreateSubscriptionRequest {
+    /// Request for a subscription charging the amount every interval until it's canceled
+    pub fn new(amount: Amount, interval: Interval, description: impl Into<String>) -> Self {
+        Self {
+            amount,
+            interval,
+            description: description.into(),
+            times: None,
+            start_date: None,
+            method: None,
+            mandate_id: None,
+            webhook_url: None,
+            metadata: None,
+            testmode: None,
+        }
+    }
+}
+
+/// Request body of [Update subscription](https://docs.mollie.com/reference/v2/subscriptions-api/update-subscription),
+/// only the parameters that are set are changed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSubscriptionRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<Interval>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    /// Only used when authenticated with an access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testmode: Option<bool>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_parse_and_format_intervals() {
+        assert_eq!(
+            "1 month".parse::<Interval>().unwrap(),
+            Interval::new(1, IntervalUnit::Month)
+        );
+        assert_eq!(
+            "14 days".parse::<Interval>().unwrap(),
+            Interval::new(14, IntervalUnit::Day)
+        );
+        assert_eq!(Interval::new(2, IntervalUnit::Week).to_string(), "2 weeks");
+        assert_eq!(Interval::new(1, IntervalUnit::Day).to_string(), "1 day");
+
+        for invalid in ["month", "0 days", "1 year", "one month"] {
+            assert!(invalid.parse::<Interval>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn should_compute_the_last_payment_date() {
+        let subscription: SubscriptionResource = serde_json::from_str(
+            r#"{
+                "resource": "subscription",
+                "id": "sub_rVKGtNd6s3",
+                "mode": "live",
+                "createdAt": "2016-06-01T12:23:34+00:00",
+                "status": "active",
+                "amount": { "value": "25.00", "currency": "EUR" },
+                "times": 4,
+                "timesRemaining": 3,
+                "interval": "1 month",
+                "startDate": "2016-08-31",
+                "nextPaymentDate": "2016-09-30",
+                "description": "Quarterly payment",
+                "method": null,
+                "customerId": "cst_stTC2WHAuS",
+                "_links": {}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(subscription.interval, Interval::new(1, IntervalUnit::Month));
+        assert_eq!(
+            subscription.last_payment_date(),
+            NaiveDate::from_ymd_opt(2016, 11, 30)
+        );
+
+        // Charges on the 31st continue on the 31st after a shorter month
+        let subscription = SubscriptionResource {
+            times: Some(6),
+            ..subscription
+        };
+        assert_eq!(
+            subscription.last_payment_date(),
+            NaiveDate::from_ymd_opt(2017, 1, 31)
+        );
+    }
+}
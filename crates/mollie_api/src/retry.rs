@@ -0,0 +1,195 @@
+//! Retry policy module
+//!
+//! Used to decide whether a failed request should be retried and how long to wait before doing so.
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::{header::HeaderMap, StatusCode};
+
+/// Policy used by the `ApiClient` to retry requests that failed because of a
+/// transient error: connection issues, rate limiting (429) or server errors (5xx).
+///
+/// The delay between two attempts grows exponentially and is jittered, unless the
+/// API returned a `Retry-After` header, in which case that delay is honored. A `Retry-After`
+/// longer than `max_backoff` isn't waited for: the request fails with `Error::RateLimited`
+/// (or the error matching its status), so that the caller can decide what to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of times a request is retried after the first attempt
+    pub max_retries: u32,
+
+    /// Delay before the first retry, doubled for every following retry
+    pub initial_backoff: Duration,
+
+    /// Upper bound for the delay between two attempts, including the one asked for with `Retry-After`
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that never retries a request
+    pub fn never() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Jittered exponential delay to wait before the given retry (starting at 0)
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponential = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_backoff);
+
+        // "Equal jitter": wait at least half of the delay, plus a random part of the other half
+        let half = exponential / 2;
+        half + rand::thread_rng().gen_range(Duration::ZERO..=half)
+    }
+
+    /// Delay to wait before retrying a request that returned the given response,
+    /// `None` if the request should not be retried (e.g. because Mollie asked to wait longer
+    /// than `max_backoff`).
+    pub(crate) fn delay_for_status(
+        &self,
+        retry: u32,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) -> Option<Duration> {
+        if retry >= self.max_retries || !is_retryable_status(status) {
+            return None;
+        }
+
+        match retry_after(headers) {
+            Some(delay) if delay > self.max_backoff => None,
+            Some(delay) => Some(delay),
+            None => Some(self.backoff(retry)),
+        }
+    }
+
+    /// Delay to wait before retrying a request that could not be performed,
+    /// `None` if the request should not be retried.
+    pub(crate) fn delay_for_error(&self, retry: u32, error: &reqwest::Error) -> Option<Duration> {
+        if retry >= self.max_retries {
+            return None;
+        }
+
+        if error.is_connect() || error.is_timeout() || error.is_request() {
+            return Some(self.backoff(retry));
+        }
+
+        None
+    }
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Parse the `Retry-After` header, only the delay in seconds format is supported
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use reqwest::header::{HeaderValue, RETRY_AFTER};
+
+    #[test]
+    fn should_grow_backoff_exponentially_up_to_the_maximum() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+        };
+
+        for retry in 0..10 {
+            let expected = Duration::from_millis(100 * 2u64.pow(retry)).min(Duration::from_secs(1));
+            let backoff = policy.backoff(retry);
+
+            assert!(
+                backoff >= expected / 2,
+                "{:?} < {:?}",
+                backoff,
+                expected / 2
+            );
+            assert!(backoff <= expected, "{:?} > {:?}", backoff, expected);
+        }
+    }
+
+    #[test]
+    fn should_retry_rate_limited_and_server_errors() {
+        let policy = RetryPolicy::default();
+        let headers = HeaderMap::new();
+
+        assert!(policy
+            .delay_for_status(0, StatusCode::TOO_MANY_REQUESTS, &headers)
+            .is_some());
+        assert!(policy
+            .delay_for_status(0, StatusCode::BAD_GATEWAY, &headers)
+            .is_some());
+        assert!(policy
+            .delay_for_status(0, StatusCode::UNPROCESSABLE_ENTITY, &headers)
+            .is_none());
+        assert!(policy
+            .delay_for_status(0, StatusCode::UNAUTHORIZED, &headers)
+            .is_none());
+    }
+
+    #[test]
+    fn should_stop_retrying_after_max_retries() {
+        let policy = RetryPolicy::default();
+        let headers = HeaderMap::new();
+
+        assert!(policy
+            .delay_for_status(2, StatusCode::SERVICE_UNAVAILABLE, &headers)
+            .is_some());
+        assert!(policy
+            .delay_for_status(3, StatusCode::SERVICE_UNAVAILABLE, &headers)
+            .is_none());
+        assert!(RetryPolicy::never()
+            .delay_for_status(0, StatusCode::SERVICE_UNAVAILABLE, &headers)
+            .is_none());
+    }
+
+    #[test]
+    fn should_honor_retry_after_header() {
+        let policy = RetryPolicy::default();
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+
+        assert_eq!(
+            policy.delay_for_status(0, StatusCode::TOO_MANY_REQUESTS, &headers),
+            Some(Duration::from_secs(7))
+        );
+    }
+
+    #[test]
+    fn should_not_wait_for_retry_after_longer_than_max_backoff() {
+        let policy = RetryPolicy::default();
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("86400"));
+
+        assert_eq!(
+            policy.delay_for_status(0, StatusCode::TOO_MANY_REQUESTS, &headers),
+            None
+        );
+    }
+}
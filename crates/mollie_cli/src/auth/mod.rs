@@ -92,7 +92,7 @@ pub async fn command(
                 );
 
             if let Some(finish) = finish {
-                let url = Url::parse(&finish).expect("Invalid finish url");
+                let url = Url::parse(finish).expect("Invalid finish url");
                 let code = url.query_pairs().find(|(key, _)| key == "code").unwrap().1;
 
                 let request = client
@@ -106,10 +106,7 @@ pub async fn command(
                 if let Ok(new_config) = config_service.update(&|config| {
                     let res = result.as_ref();
                     let old_connect = config.auth.connect.clone().unwrap();
-                    let expires_at: Option<DateTime<Utc>> = match res.unwrap().expires_in() {
-                        Some(dur) => Some(Utc::now() + dur),
-                        None => None
-                    };
+                    let expires_at: Option<DateTime<Utc>> = res.unwrap().expires_in().map(|dur| Utc::now() + dur);
                     config.auth.connect = Some(ConnectConfig{
                         client_id: old_connect.client_id,
                         client_secret: old_connect.client_secret,
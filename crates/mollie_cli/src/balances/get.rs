@@ -1,5 +1,6 @@
 use crate::balances::Balance;
 use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
 use colored::Colorize;
 use colored_json::ToColoredJson;
 use log::{debug, info};
@@ -9,17 +10,20 @@ pub async fn command(
     config: &MollieConfig,
     balance_id: &String,
     with_response: bool,
+    time_format: TimeFormat,
 ) -> miette::Result<()> {
 
     debug!("Running Get API Balance for balance: {}", balance_id);
     let token = config.bearer_token()?;
-    let balance = Mollie::build(token.as_str())
+    let balance = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
         .balances()
         .get_by_id(balance_id)
         .await?;
 
     info!("{}", Colorize::bright_black(&*Balance::header()));
-    info!("{}", Balance::from(balance.clone()).to_string());
+    info!("{}", Balance::new(balance.clone(), time_format).to_string());
     debug!("{:#?}", balance);
 
     if with_response {
@@ -1,5 +1,7 @@
 use crate::balances::Balance;
 use crate::config::MollieConfig;
+use crate::datetime::{DateRange, TimeFormat};
+use crate::pagination;
 use colored::Colorize;
 use colored_json::ToColoredJson;
 use log::{debug, info};
@@ -9,33 +11,36 @@ pub async fn command(
     config: &MollieConfig,
     limit: &Option<i32>,
     from: &Option<String>,
+    all: bool,
+    range: &DateRange,
     with_response: bool,
+    time_format: TimeFormat,
 ) -> miette::Result<()> {
     debug!("Listing balances");
     let token = config.bearer_token()?;
-    let balances = Mollie::build(token.as_str())
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
         .balances()
         .list(*limit, from)
         .await?;
 
+    let (mut balances, response) = pagination::collect_page(page, all).await?;
+    balances.retain(|balance| range.contains(&balance.created_at));
+
     info!("Listing balances");
     info!("   {}", Colorize::bright_black(&*Balance::header()));
-    balances
-        .embedded
-        .balances
-        .iter()
-        .enumerate()
-        .for_each(|(index, balance)| {
-            info!(
-                "{}. {}",
-                index + 1,
-                Balance::from(balance.clone()).to_string()
-            );
-        });
+    balances.iter().enumerate().for_each(|(index, balance)| {
+        info!(
+            "{}. {}",
+            index + 1,
+            Balance::new(balance.clone(), time_format).to_string()
+        );
+    });
     debug!("{:?}", balances);
 
     if with_response {
-        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&balances).unwrap()).unwrap();
+        let pretty_json = jsonxf::pretty_print(&response).unwrap();
         info!("{}", pretty_json.to_colored_json_auto().unwrap());
     }
 
@@ -1,11 +1,19 @@
 use crate::config::ConfigurationService;
+use crate::datetime::{self, DateRange, TimeFormat};
+use chrono::{DateTime, FixedOffset, NaiveDate};
 use clap::{Parser, Subcommand};
 use colored::Colorize;
-use mollie_api::models::balance::BalanceResource;
+use mollie_api::models::amount::Amount;
+use mollie_api::models::balance::{
+    BalanceReportGrouping, BalanceResource, BalanceTransactionResource, BalanceTransactionType,
+};
+use mollie_api::models::mode::Mode;
 use std::fmt::Display;
 
 mod get;
 mod list;
+mod report;
+mod transactions;
 
 #[derive(Parser)]
 #[clap(version, about)]
@@ -18,6 +26,9 @@ pub struct BalancesCommand {
     #[clap(long = "withResponse", global = true)]
     with_response: bool,
 
+    /// How to render timestamps
+    #[clap(long, value_enum, global = true, default_value_t)]
+    time_format: TimeFormat,
 
     #[clap(subcommand)]
     command: Option<BalanceCommands>,
@@ -36,18 +47,118 @@ pub enum BalanceCommands {
         limit: Option<i32>,
         #[clap(short, long)]
         from: Option<String>,
+        /// Follow the pagination links and list the balances of every page
+        #[clap(long)]
+        all: bool,
+        /// Only list balances created on or after this date (2024-01-31) or ISO 8601 timestamp
+        #[clap(long, value_parser = datetime::parse_since)]
+        since: Option<DateTime<FixedOffset>>,
+        /// Only list balances created on or before this date (2024-01-31) or before this ISO 8601 timestamp
+        #[clap(long, value_parser = datetime::parse_until)]
+        until: Option<DateTime<FixedOffset>>,
+    },
+    /// List the transactions that moved money in or out of a balance, newest first
+    Transactions {
+        #[clap(default_value = "primary")]
+        id: String,
+        #[clap(short, long)]
+        limit: Option<i32>,
+        /// Id of the transaction to start listing from
+        #[clap(short, long)]
+        from: Option<String>,
+        /// Follow the pagination links and list the transactions of every page
+        #[clap(long)]
+        all: bool,
+    },
+    /// Show the totals of a balance over a period of days
+    #[clap(arg_required_else_help(true))]
+    Report {
+        #[clap(default_value = "primary")]
+        id: String,
+        /// First day of the report (2024-01-01)
+        #[clap(long, value_parser = datetime::parse_date)]
+        from: NaiveDate,
+        /// Last day of the report (2024-01-31)
+        #[clap(long, value_parser = datetime::parse_date)]
+        until: NaiveDate,
+        #[clap(long, value_enum)]
+        grouping: Option<Grouping>,
     },
 }
 
+#[derive(clap::ValueEnum, Clone)]
+pub enum Grouping {
+    /// Movements of the pending and available balance
+    StatusBalances,
+    /// Totals per kind of transaction, e.g. payments, refunds and fees
+    TransactionCategories,
+}
+
+impl From<&Grouping> for BalanceReportGrouping {
+    fn from(grouping: &Grouping) -> Self {
+        match grouping {
+            Grouping::StatusBalances => BalanceReportGrouping::StatusBalances,
+            Grouping::TransactionCategories => BalanceReportGrouping::TransactionCategories,
+        }
+    }
+}
+
 pub async fn command(
     command: &BalancesCommand,
     config_service: &dyn ConfigurationService,
 ) -> miette::Result<()> {
     let config = config_service.read();
     match command.command.as_ref() {
-        Some(BalanceCommands::Get { id }) => get::command(config, id, command.with_response).await,
-        Some(BalanceCommands::List { limit, from }) => {
-            list::command(config, limit, from, command.with_response).await
+        Some(BalanceCommands::Get { id }) => {
+            get::command(config, id, command.with_response, command.time_format).await
+        }
+        Some(BalanceCommands::List {
+            limit,
+            from,
+            all,
+            since,
+            until,
+        }) => {
+            let range = DateRange {
+                since: *since,
+                until: *until,
+            };
+            list::command(
+                config,
+                limit,
+                from,
+                *all,
+                &range,
+                command.with_response,
+                command.time_format,
+            )
+            .await
+        }
+        Some(BalanceCommands::Transactions {
+            id,
+            limit,
+            from,
+            all,
+        }) => {
+            transactions::command(
+                config,
+                id,
+                *limit,
+                from,
+                *all,
+                command.with_response,
+                command.time_format,
+            )
+            .await
+        }
+        Some(BalanceCommands::Report {
+            id,
+            from,
+            until,
+            grouping,
+        }) => {
+            let grouping = grouping.as_ref().map(BalanceReportGrouping::from);
+            report::command(config, id, *from, *until, grouping, command.with_response).await
         }
         None => Ok(()),
     }
@@ -55,29 +166,31 @@ pub async fn command(
 
 pub struct Balance {
     pub id: String,
-    pub mode: String,
+    pub mode: Mode,
     pub status: String,
-    pub available_amount: String,
-    pub pending_amount: String,
+    pub created_at: String,
+    pub available_amount: Amount,
+    pub pending_amount: Amount,
 }
 
 impl Balance {
     pub fn header() -> String {
         format!(
-            "|  {:^24} {:^4} {:^12} {} |",
-            "ID", "MODE", "AVAILABLE", "PENDING"
+            "|  {:^24} {:^4} {:^19} {:^12} {} |",
+            "ID", "MODE", "CREATED_AT", "AVAILABLE", "PENDING"
         )
     }
 }
 
-impl From<BalanceResource> for Balance {
-    fn from(balance: BalanceResource) -> Self {
+impl Balance {
+    pub fn new(balance: BalanceResource, time_format: TimeFormat) -> Self {
         Self {
             id: balance.id,
             mode: balance.mode,
             status: balance.status,
-            available_amount: balance.available_amount.to_string(),
-            pending_amount: balance.pending_amount.to_string(),
+            created_at: time_format.format(&balance.created_at),
+            available_amount: balance.available_amount,
+            pending_amount: balance.pending_amount,
         }
     }
 }
@@ -86,19 +199,80 @@ impl Display for Balance {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{} | {} | {} | {}",
+            "{} | {} | {} | {} | {}",
             if self.status == "active" {
                 Colorize::green(&*self.id)
             } else {
                 Colorize::blink(&*self.id)
             },
-            if self.mode == "live" {
+            if self.mode == Mode::Live {
                 Colorize::bright_green("LIVE")
             } else {
                 Colorize::bright_black("TEST")
             },
+            Colorize::blue(&*self.created_at),
             Colorize::green(&*self.available_amount.to_string()),
             Colorize::yellow(&*self.pending_amount.to_string()),
         )
     }
 }
+
+pub struct Transaction {
+    pub id: String,
+    pub transaction_type: BalanceTransactionType,
+    pub result_amount: Amount,
+    pub initial_amount: Amount,
+    pub deductions: String,
+    pub created_at: String,
+    pub context: String,
+}
+
+impl Transaction {
+    pub fn new(transaction: &BalanceTransactionResource, time_format: TimeFormat) -> Self {
+        Self {
+            id: transaction.id.clone(),
+            transaction_type: transaction.transaction_type.clone(),
+            result_amount: transaction.result_amount.clone(),
+            initial_amount: transaction.initial_amount.clone(),
+            deductions: transaction
+                .deductions
+                .as_ref()
+                .map(|deductions| deductions.to_string())
+                .unwrap_or_else(|| String::from("-")),
+            created_at: time_format.format(&transaction.created_at),
+            context: transaction
+                .context
+                .resource_id()
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^30} {:^20} {:^14} {:^14} {:^12} {:^19} {} |",
+            "ID", "TYPE", "AMOUNT", "INITIAL", "DEDUCTIONS", "CREATED_AT", "RELATED_TO"
+        )
+    }
+}
+
+impl Display for Transaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let amount = if self.result_amount.value().is_sign_negative() {
+            Colorize::red(&*self.result_amount.to_string())
+        } else {
+            Colorize::green(&*self.result_amount.to_string())
+        };
+        write!(
+            f,
+            "{} | {} | {} | {} | {} | {} | {}",
+            self.id,
+            self.transaction_type,
+            amount,
+            self.initial_amount,
+            self.deductions,
+            Colorize::blue(&*self.created_at),
+            self.context,
+        )
+    }
+}
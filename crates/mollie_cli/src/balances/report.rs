@@ -0,0 +1,110 @@
+use crate::config::MollieConfig;
+use chrono::{Days, NaiveDate};
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use miette::miette;
+use mollie_api::models::balance::{
+    BalanceReportGrouping, BalanceReportResource, BalanceReportSubtotal, BalanceReportTotal,
+};
+use mollie_api::Mollie;
+use std::collections::BTreeMap;
+
+pub async fn command(
+    config: &MollieConfig,
+    balance_id: &str,
+    from: NaiveDate,
+    until: NaiveDate,
+    grouping: Option<BalanceReportGrouping>,
+    with_response: bool,
+) -> miette::Result<()> {
+    debug!("Getting the report of balance {}", balance_id);
+    // The API excludes the until date, the cli includes it like the other date filters
+    let last_day = until;
+    let until = last_day
+        .checked_add_days(Days::new(1))
+        .ok_or_else(|| miette!("The report can't end on {}", last_day))?;
+    if from >= until {
+        return Err(miette!(
+            code = "balances::report::period",
+            help = "Pass a --from date on or before the --until date",
+            "The report can't start on {} when it ends on {}",
+            from,
+            last_day
+        ));
+    }
+
+    let token = config.bearer_token()?;
+    let report = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .balances()
+        .get_report(balance_id, from, until, grouping.as_ref())
+        .await?;
+
+    print_report(&report);
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&report).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
+
+fn print_report(report: &BalanceReportResource) {
+    info!(
+        "Report of balance {} from {} through {} ({}), grouped by {}",
+        Colorize::green(&*report.balance_id),
+        report.from,
+        report.until.pred_opt().unwrap_or(report.until),
+        report.time_zone,
+        report.grouping
+    );
+
+    for (group, totals) in &report.totals {
+        info!("{}", Colorize::bold(&**group));
+        for (name, total) in in_report_order(totals) {
+            info!("  {}: {}", name, Colorize::blue(&*total.amount.to_string()));
+            print_subtotals(&total.subtotals, 2);
+        }
+    }
+}
+
+/// Totals ordered the way a balance moves: opening balance first, closing balance last
+fn in_report_order(
+    totals: &BTreeMap<String, BalanceReportTotal>,
+) -> Vec<(&String, &BalanceReportTotal)> {
+    let mut totals: Vec<_> = totals.iter().collect();
+    totals.sort_by_key(|(name, _)| match name.as_str() {
+        "open" => 0,
+        "close" => 2,
+        _ => 1,
+    });
+    totals
+}
+
+fn print_subtotals(subtotals: &[BalanceReportSubtotal], depth: usize) {
+    for subtotal in subtotals {
+        let name = subtotal
+            .transaction_type
+            .as_ref()
+            .map(|transaction_type| transaction_type.to_string())
+            .or_else(|| subtotal.method.as_ref().map(|method| method.to_string()))
+            .or_else(|| subtotal.fee_type.clone())
+            .unwrap_or_else(|| String::from("other"));
+        let count = subtotal
+            .count
+            .map(|count| format!(" ({})", count))
+            .unwrap_or_default();
+
+        info!(
+            "{}{}{}: {}",
+            "  ".repeat(depth),
+            name,
+            count,
+            subtotal.amount
+        );
+        print_subtotals(&subtotal.subtotals, depth + 1);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::balances::Transaction;
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::pagination;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    balance_id: &str,
+    limit: Option<i32>,
+    from: &Option<String>,
+    all: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing transactions of balance {}", balance_id);
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .balances()
+        .list_transactions(balance_id, limit, from)
+        .await?;
+
+    let (transactions, response) = pagination::collect_page(page, all).await?;
+
+    info!("   {}", Colorize::bright_black(&*Transaction::header()));
+    transactions
+        .iter()
+        .enumerate()
+        .for_each(|(index, transaction)| {
+            info!(
+                "{}. {}",
+                index + 1,
+                Transaction::new(transaction, time_format)
+            );
+        });
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&response).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
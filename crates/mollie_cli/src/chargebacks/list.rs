@@ -0,0 +1,103 @@
+use crate::chargebacks::{group_by_reason, Chargeback};
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::pagination;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::models::chargeback::ChargebackResource;
+use mollie_api::models::list::Page;
+use mollie_api::Mollie;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn command(
+    config: &MollieConfig,
+    payment_id: &Option<String>,
+    limit: Option<i32>,
+    from: &Option<String>,
+    profile_id: &Option<String>,
+    test_mode: Option<bool>,
+    all: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    let token = config.bearer_token()?;
+    let chargebacks_api = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .chargebacks();
+
+    let page = match payment_id {
+        Some(payment_id) => {
+            debug!("Listing chargebacks of payment {}", payment_id);
+            chargebacks_api
+                .list_for_payment(payment_id, limit, from, test_mode)
+                .await?
+        }
+        None => {
+            debug!("Listing chargebacks");
+            chargebacks_api
+                .list(limit, from, &config.profile_id(profile_id), test_mode)
+                .await?
+        }
+    };
+
+    list_chargebacks(page, all, with_response, time_format).await
+}
+
+/// Print a page of chargebacks, or every chargeback starting from this page with `all`,
+/// followed by the number of open and reversed chargebacks per reason
+pub async fn list_chargebacks(
+    page: Page<ChargebackResource>,
+    all: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    let (chargebacks, response) = pagination::collect_page(page, all).await?;
+
+    info!("   {}", Colorize::bright_black(&*Chargeback::header()));
+    chargebacks
+        .iter()
+        .enumerate()
+        .for_each(|(index, chargeback)| {
+            info!(
+                "{}. {}",
+                index + 1,
+                Chargeback::new(chargeback, time_format)
+            );
+        });
+
+    let reasons = group_by_reason(&chargebacks)?;
+    if !reasons.is_empty() {
+        info!(
+            "{}",
+            Colorize::bright_black(&*format!(
+                "|{:^8} {:^6} {:^8} {:^16} {} |",
+                "REASON", "OPEN", "REVERSED", "OPEN_AMOUNT", "DESCRIPTION"
+            ))
+        );
+    }
+    for (code, summary) in reasons {
+        let open_amount = summary
+            .open_amounts
+            .values()
+            .map(|amount| amount.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        info!(
+            "{} | {} | {} | {} | {}",
+            code,
+            Colorize::red(&*summary.open.to_string()),
+            Colorize::yellow(&*summary.reversed.to_string()),
+            open_amount,
+            summary.description,
+        );
+    }
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&response).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
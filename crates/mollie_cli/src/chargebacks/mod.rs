@@ -0,0 +1,229 @@
+use crate::config::ConfigurationService;
+use crate::datetime::TimeFormat;
+use clap::{Parser, Subcommand};
+use colored::Colorize;
+use mollie_api::models::amount::Amount;
+use mollie_api::models::chargeback::ChargebackResource;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+pub mod list;
+
+#[derive(Parser)]
+#[clap(version, about, arg_required_else_help(true))]
+pub struct ChargebacksCommand {
+    /// Enable debug logging
+    #[clap(short, long, global = true)]
+    debug: bool,
+
+    /// Print the API response after performing an API call
+    #[clap(long = "withResponse", global = true)]
+    with_response: bool,
+
+    /// How to render timestamps
+    #[clap(long, value_enum, global = true, default_value_t)]
+    time_format: TimeFormat,
+
+    #[clap(subcommand)]
+    command: Option<ChargebacksCommands>,
+}
+
+#[derive(Subcommand)]
+pub enum ChargebacksCommands {
+    /// List chargebacks, grouped by reason and whether they were reversed
+    List {
+        /// Only list the chargebacks of this payment
+        #[clap(long)]
+        payment_id: Option<String>,
+        #[clap(short, long)]
+        limit: Option<i32>,
+        #[clap(short, long)]
+        from: Option<String>,
+        #[clap(short, long)]
+        profile_id: Option<String>,
+        #[clap(short, long)]
+        test_mode: Option<bool>,
+        /// Follow the pagination links and list the chargebacks of every page
+        #[clap(long)]
+        all: bool,
+    },
+}
+
+pub async fn command(
+    chargebacks_command: &ChargebacksCommand,
+    config_service: &dyn ConfigurationService,
+) -> miette::Result<()> {
+    let config = config_service.read();
+    match chargebacks_command.command.as_ref() {
+        Some(ChargebacksCommands::List {
+            payment_id,
+            limit,
+            from,
+            profile_id,
+            test_mode,
+            all,
+        }) => {
+            list::command(
+                config,
+                payment_id,
+                *limit,
+                from,
+                profile_id,
+                *test_mode,
+                *all,
+                chargebacks_command.with_response,
+                chargebacks_command.time_format,
+            )
+            .await
+        }
+        None => Ok(()),
+    }
+}
+
+/// Chargebacks with the same reason code
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReasonSummary {
+    pub description: String,
+    /// Chargebacks that still stand
+    pub open: u32,
+    pub reversed: u32,
+    /// Total amount of the open chargebacks, per currency
+    pub open_amounts: BTreeMap<String, Amount>,
+}
+
+/// Group chargebacks by the reason code of the bank, `unknown` when no reason was given
+pub fn group_by_reason(
+    chargebacks: &[ChargebackResource],
+) -> miette::Result<BTreeMap<String, ReasonSummary>> {
+    let mut reasons = BTreeMap::<String, ReasonSummary>::new();
+
+    for chargeback in chargebacks {
+        let (code, description) = match &chargeback.reason {
+            Some(reason) => (reason.code.clone(), reason.description.clone()),
+            None => (String::from("unknown"), String::new()),
+        };
+        let summary = reasons.entry(code).or_insert_with(|| ReasonSummary {
+            description,
+            open: 0,
+            reversed: 0,
+            open_amounts: BTreeMap::new(),
+        });
+
+        if chargeback.reversed_at.is_some() {
+            summary.reversed += 1;
+            continue;
+        }
+
+        summary.open += 1;
+        let currency = chargeback.amount.currency().to_string();
+        let total = match summary.open_amounts.get(&currency) {
+            Some(total) => total.checked_add(&chargeback.amount)?,
+            None => chargeback.amount.clone(),
+        };
+        summary.open_amounts.insert(currency, total);
+    }
+
+    Ok(reasons)
+}
+
+pub struct Chargeback {
+    pub id: String,
+    pub amount: Amount,
+    pub settlement_amount: String,
+    pub reason: String,
+    pub payment_id: String,
+    pub created_at: String,
+    pub reversed: bool,
+}
+
+impl Chargeback {
+    pub fn new(chargeback: &ChargebackResource, time_format: TimeFormat) -> Self {
+        Self {
+            id: chargeback.id.clone(),
+            amount: chargeback.amount.clone(),
+            settlement_amount: chargeback
+                .settlement_amount
+                .as_ref()
+                .map(|amount| amount.to_string())
+                .unwrap_or_else(|| String::from("-")),
+            reason: chargeback
+                .reason
+                .as_ref()
+                .map(|reason| format!("{} {}", reason.code, reason.description))
+                .unwrap_or_default(),
+            payment_id: chargeback.payment_id.clone(),
+            created_at: time_format.format(&chargeback.created_at),
+            reversed: chargeback.reversed_at.is_some(),
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^14} {:^12} {:^16} {:^14} {:^19} {:^8} {} |",
+            "ID", "AMOUNT", "SETTLEMENT_AMOUNT", "PAYMENT_ID", "CREATED_AT", "REVERSED", "REASON"
+        )
+    }
+}
+
+impl Display for Chargeback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let id = match self.reversed {
+            true => Colorize::yellow(&*self.id),
+            false => Colorize::red(&*self.id),
+        };
+        write!(
+            f,
+            "{} | {} | {} | {} | {} | {} | {}",
+            id,
+            Colorize::red(&*self.amount.to_string()),
+            self.settlement_amount,
+            self.payment_id,
+            Colorize::blue(&*self.created_at),
+            if self.reversed { "yes" } else { "no" },
+            self.reason,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn chargeback(code: Option<&str>, amount: &str, reversed: bool) -> ChargebackResource {
+        serde_json::from_value(serde_json::json!({
+            "id": "chb_n9z0tp",
+            "amount": { "value": amount, "currency": "EUR" },
+            "settlementAmount": null,
+            "reason": code.map(|code| serde_json::json!({ "code": code, "description": "Reason" })),
+            "paymentId": "tr_WDqYK6vllg",
+            "settlementId": null,
+            "createdAt": "2018-03-14T17:00:52.0Z",
+            "reversedAt": reversed.then_some("2018-03-20T09:00:00.0Z"),
+            "_links": {}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn should_group_chargebacks_by_reason() {
+        let chargebacks = vec![
+            chargeback(Some("AC01"), "10.00", false),
+            chargeback(Some("AC01"), "5.50", false),
+            chargeback(Some("AC01"), "7.00", true),
+            chargeback(Some("MD06"), "3.00", true),
+            chargeback(None, "1.00", false),
+        ];
+
+        let reasons = group_by_reason(&chargebacks).unwrap();
+
+        let ac01 = &reasons["AC01"];
+        assert_eq!((ac01.open, ac01.reversed), (2, 1));
+        assert_eq!(
+            ac01.open_amounts["EUR"],
+            Amount::parse("15.50", "EUR").unwrap()
+        );
+        assert_eq!((reasons["MD06"].open, reasons["MD06"].reversed), (0, 1));
+        assert!(reasons["MD06"].open_amounts.is_empty());
+        assert_eq!(reasons["unknown"].open, 1);
+    }
+}
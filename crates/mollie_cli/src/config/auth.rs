@@ -23,14 +23,8 @@ pub(crate) fn refresh_token(config: &mut ConnectConfig) {
     match client.exchange_refresh_token(&RefreshToken::new(refresh_token)).request(http_client) {
         Ok(res) => {
             config.access_token = Some(res.access_token().secret().to_string());
-            config.refresh_token = match res.refresh_token() {
-                Some(token) => Some(token.secret().to_string()),
-                _ => None
-            };
-            config.expires_at = match res.expires_in() {
-                Some(duration) => Some(Utc::now() + duration),
-                _ => None
-            };
+            config.refresh_token = res.refresh_token().map(|token| token.secret().to_string());
+            config.expires_at = res.expires_in().map(|duration| Utc::now() + duration);
         },
         _ => panic!("failed to refresh token")
     }
@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use log::debug;
 use mollie_api::auth::{AccessCode, ApiBearerToken, ApiKey};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use url::Url;
 
 use super::error::{ConfigError, ConfigResult};
@@ -52,12 +53,51 @@ impl MollieConfig {
 
         Err(ConfigError::NoAuthenticationMethodSet)
     }
+
+    /// Profile to use when none was passed, set with `mol profiles use`.
+    /// API keys belong to a single profile, so it's only used with access tokens.
+    pub fn profile_id(&self, profile_id: &Option<String>) -> Option<String> {
+        if profile_id.is_some() {
+            return profile_id.clone();
+        }
+
+        match self.bearer_token() {
+            Ok(ApiBearerToken::ApiKey(_)) | Err(_) => None,
+            Ok(_) => self.auth.profile_id.clone(),
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct ApiConfig {
     #[serde(default = "default_api_url")]
     pub url: Url,
+
+    /// Named environments the CLI can switch between with `mol env use`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub environments: BTreeMap<String, Url>,
+}
+
+impl ApiConfig {
+    /// Name of the built-in environment pointing to the production Mollie API
+    pub const PRODUCTION_ENVIRONMENT: &'static str = "production";
+
+    /// All known environments, including the built-in production environment
+    pub fn all_environments(&self) -> BTreeMap<String, Url> {
+        let mut environments = self.environments.clone();
+        environments
+            .entry(Self::PRODUCTION_ENVIRONMENT.to_string())
+            .or_insert_with(default_api_url);
+        environments
+    }
+
+    /// Name of the environment the configured url belongs to, if any
+    pub fn current_environment(&self) -> Option<String> {
+        self.all_environments()
+            .into_iter()
+            .find(|(_, url)| url == &self.url)
+            .map(|(name, _)| name)
+    }
 }
 
 #[derive(Default, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
@@ -65,6 +105,10 @@ pub struct AuthConfig {
     pub api_keys: Option<ApiKeysConfig>,
     pub access_code: Option<AccessCodeConfig>,
     pub connect: Option<ConnectConfig>,
+
+    /// Default profile used with access tokens, set with `mol profiles use`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_id: Option<String>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
@@ -97,7 +141,7 @@ impl ConnectConfig {
 
     pub fn is_valid(&self) -> bool {
         match &self.access_token {
-            Some(_) => return !self.is_expired(),
+            Some(_) => !self.is_expired(),
             None => false,
         }
     }
@@ -106,6 +150,7 @@ impl ConnectConfig {
 fn default_api_config() -> ApiConfig {
     ApiConfig {
         url: default_api_url(),
+        environments: BTreeMap::new(),
     }
 }
 
@@ -114,6 +159,7 @@ fn default_auth_config() -> AuthConfig {
         api_keys: None,
         access_code: None,
         connect: None,
+        profile_id: None,
     }
 }
 
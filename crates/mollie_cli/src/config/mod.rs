@@ -13,6 +13,7 @@ use std::sync::OnceLock;
 pub use crate::config::config::*;
 
 mod auth;
+#[allow(clippy::module_inception)]
 mod config;
 mod error;
 
@@ -46,11 +47,12 @@ impl FigmentConfigurationService {
         config_path
     }
 
-    fn map_env_variables(str: &UncasedStr) -> Uncased {
+    fn map_env_variables(str: &UncasedStr) -> Uncased<'_> {
         match str {
             _ if str == "api_url" => "api.url".into(),
             _ if str == "api_key" => "auth.api_keys.live".into(),
             _ if str == "access_token" => "auth.access_code.token".into(),
+            _ if str == "profile_id" => "auth.profile_id".into(),
             _ => str.as_str().replace("__", ".").into(),
         }
     }
@@ -136,9 +138,11 @@ impl ConfigurationService for FigmentConfigurationService {
 }
 
 #[cfg(test)]
+#[allow(clippy::result_large_err)]
 mod test {
     use super::*;
     use mollie_api::auth;
+    use std::collections::BTreeMap;
     use url::Url;
 
     #[test]
@@ -174,6 +178,7 @@ mod test {
                 &MollieConfig {
                     api: ApiConfig {
                         url: Url::parse("https://test.com/").unwrap(),
+                        environments: BTreeMap::new(),
                     },
                     auth: AuthConfig {
                         access_code: Some(AccessCodeConfig {
@@ -199,6 +204,7 @@ mod test {
                             access_token: Some("access_token".to_string()),
                             expires_at: None,
                         }),
+                        profile_id: None,
                     },
                 }
             );
@@ -237,6 +243,7 @@ mod test {
                 &MollieConfig {
                     api: ApiConfig {
                         url: Url::parse("https://env.com/").unwrap(),
+                        environments: BTreeMap::new(),
                     },
                     auth: AuthConfig {
                         api_keys: Some(ApiKeysConfig {
@@ -253,6 +260,7 @@ mod test {
                             },
                         }),
                         connect: None,
+                        profile_id: None,
                     }
                 }
             );
@@ -288,6 +296,7 @@ mod test {
                 &MollieConfig {
                     api: ApiConfig {
                         url: Url::parse("https://env.com/").unwrap(),
+                        environments: BTreeMap::new(),
                     },
                     auth: AuthConfig {
                         api_keys: Some(ApiKeysConfig {
@@ -304,6 +313,7 @@ mod test {
                             },
                         }),
                         connect: None,
+                        profile_id: None,
                     }
                 }
             );
@@ -311,4 +321,66 @@ mod test {
             Ok(())
         });
     }
+
+    #[test]
+    fn should_read_environments() {
+        figment::Jail::expect_with(|jail| {
+            jail.clear_env();
+            jail.create_file(
+                "conf.toml",
+                r#"
+                    [api]
+                    url = "http://localhost:8080/v2"
+
+                    [api.environments]
+                    mock = "http://localhost:8080/v2"
+                    staging = "https://staging-proxy.example.com/v2"
+                "#,
+            )?;
+
+            let service = FigmentConfigurationService::new();
+            let config = service.read();
+
+            assert_eq!(config.api.current_environment(), Some("mock".to_string()));
+            assert_eq!(
+                config.api.all_environments().keys().collect::<Vec<_>>(),
+                vec!["mock", "production", "staging"]
+            );
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn should_only_use_default_profile_with_access_tokens() {
+        figment::Jail::expect_with(|jail| {
+            jail.clear_env();
+            jail.create_file(
+                "conf.toml",
+                r#"
+                    [auth]
+                    profile_id = "pfl_default"
+
+                    [auth.api_keys]
+                    live = "live_xxxxxxxxxxxxxxxxxxxxxxxxxxx123"
+                "#,
+            )?;
+
+            let config = FigmentConfigurationService::new().read().clone();
+            assert_eq!(config.profile_id(&None), None);
+
+            jail.set_env(
+                "MOLLIE_ACCESS_TOKEN",
+                "access_xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx123",
+            );
+            let config = FigmentConfigurationService::new().read().clone();
+            assert_eq!(config.profile_id(&None), Some("pfl_default".to_string()));
+            assert_eq!(
+                config.profile_id(&Some("pfl_other".to_string())),
+                Some("pfl_other".to_string())
+            );
+
+            Ok(())
+        });
+    }
 }
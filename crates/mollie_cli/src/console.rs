@@ -19,7 +19,7 @@ pub fn handle_mollie_client_error(err: errors::ApiClientError) {
 
             if i.status == 401 {
                 warn!("Run mol auth to set up your authentication with the Mollie API");
-                warn!("Run mol env url {{prod|dev}} to switch environments");
+                warn!("Run mol env use {{name}} to switch environments");
             }
         }
         errors::ApiClientError::CouldNotFindValidAuthorizationMethodToPerformRequest() => {
@@ -68,7 +68,7 @@ mod console_tests {
             assert_eq!(captured_logs[3].level, Level::Warn);
             assert_eq!(
                 captured_logs[4].body,
-                "Run mol env url {prod|dev} to switch environments"
+                "Run mol env use {name} to switch environments"
             );
             assert_eq!(captured_logs[4].level, Level::Warn);
         });
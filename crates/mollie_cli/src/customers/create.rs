@@ -0,0 +1,105 @@
+use crate::config::MollieConfig;
+use crate::customers::Customer;
+use crate::datetime::TimeFormat;
+use crate::input;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use miette::miette;
+use mollie_api::models::customer::CreateCustomerRequest;
+use mollie_api::Mollie;
+use requestty::Question;
+
+pub fn request(
+    name: &Option<String>,
+    email: &Option<String>,
+    locale: &Option<String>,
+    metadata: &Option<serde_json::Value>,
+    testmode: bool,
+) -> CreateCustomerRequest {
+    CreateCustomerRequest {
+        name: name.clone(),
+        email: email.clone(),
+        locale: locale.clone(),
+        metadata: metadata.clone(),
+        testmode: testmode.then_some(true),
+    }
+}
+
+pub async fn command(
+    config: &MollieConfig,
+    request: &CreateCustomerRequest,
+    with_request: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Running Create Customer Command");
+
+    if with_request {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(request).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    let token = config.bearer_token()?;
+    let customer = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .customers()
+        .create(request)
+        .await?;
+
+    info!("{}", Colorize::green("Customer created"));
+    info!("{}", Colorize::bright_black(&*Customer::header()));
+    info!(
+        "{}",
+        Customer::new(customer.clone(), time_format).to_string()
+    );
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&customer).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
+
+/// Prompt for the customer to create
+pub fn ask_request() -> miette::Result<CreateCustomerRequest> {
+    debug!("Running interactive Create Customer Command");
+
+    Ok(CreateCustomerRequest {
+        name: ask_optional("name", "Full name", |_| Ok(()))?,
+        email: ask_optional("email", "Email", |value| match value.contains('@') {
+            true => Ok(()),
+            false => Err(format!("'{}' is not an email address", value)),
+        })?,
+        locale: ask_optional("locale", "Locale (e.g. nl_NL)", |_| Ok(()))?,
+        metadata: ask_optional("metadata", "Metadata (JSON or @file.json)", |value| {
+            input::parse_json::<serde_json::Value>(value).map(|_| ())
+        })?
+        .map(|value| input::parse_json(&value))
+        .transpose()
+        .map_err(|e| miette!(e))?,
+        testmode: None,
+    })
+}
+
+/// Ask for an optional value, an empty answer means the value is not set
+fn ask_optional(
+    name: &str,
+    message: &str,
+    validate: impl Fn(&str) -> Result<(), String> + 'static,
+) -> miette::Result<Option<String>> {
+    let question = Question::input(name)
+        .message(format!("{} (leave empty to skip)", message))
+        .validate(move |value, _| match value.trim() {
+            "" => Ok(()),
+            value => validate(value),
+        })
+        .build();
+
+    let answer = requestty::prompt_one(question).map_err(|e| miette!("{}", e))?;
+    let answer = answer.as_string().unwrap().trim();
+
+    Ok((!answer.is_empty()).then(|| String::from(answer)))
+}
@@ -0,0 +1,22 @@
+use crate::config::MollieConfig;
+use colored::Colorize;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(config: &MollieConfig, customer_id: &str) -> miette::Result<()> {
+    debug!("Running Delete API Customer for customer: {}", customer_id);
+    let token = config.bearer_token()?;
+    Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .customers()
+        .delete(customer_id)
+        .await?;
+
+    info!(
+        "{}",
+        Colorize::red(&*format!("Customer {} deleted", customer_id))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::config::MollieConfig;
+use crate::customers::Customer;
+use crate::datetime::TimeFormat;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    customer_id: &str,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Running Get API Customer for customer: {}", customer_id);
+    let token = config.bearer_token()?;
+    let customer = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .customers()
+        .get_by_id(customer_id)
+        .await?;
+
+    info!("{}", Colorize::bright_black(&*Customer::header()));
+    info!(
+        "{}",
+        Customer::new(customer.clone(), time_format).to_string()
+    );
+    debug!("{:?}", customer);
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&customer).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
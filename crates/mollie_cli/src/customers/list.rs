@@ -0,0 +1,48 @@
+use crate::config::MollieConfig;
+use crate::customers::Customer;
+use crate::datetime::{DateRange, TimeFormat};
+use crate::pagination;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn command(
+    config: &MollieConfig,
+    limit: Option<i32>,
+    from: &Option<String>,
+    test_mode: Option<bool>,
+    all: bool,
+    range: &DateRange,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing customers");
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .customers()
+        .list(limit, from, test_mode)
+        .await?;
+
+    let (mut customers, response) = pagination::collect_page(page, all).await?;
+    customers.retain(|customer| range.contains(&customer.created_at));
+
+    info!("   {}", Colorize::bright_black(&*Customer::header()));
+    customers.iter().enumerate().for_each(|(index, customer)| {
+        info!(
+            "{}. {}",
+            index + 1,
+            Customer::new(customer.clone(), time_format).to_string()
+        );
+    });
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&response).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
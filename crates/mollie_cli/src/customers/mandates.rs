@@ -0,0 +1,237 @@
+use crate::config::MollieConfig;
+use crate::customers::Customer;
+use crate::datetime::TimeFormat;
+use colored::{ColoredString, Colorize};
+use colored_json::ToColoredJson;
+use futures::TryStreamExt;
+use log::{debug, info, warn};
+use mollie_api::models::customer::CustomerResource;
+use mollie_api::models::mandate::{MandateResource, MandateStatus};
+use mollie_api::Mollie;
+use std::fmt::Display;
+
+pub async fn command(
+    config: &MollieConfig,
+    customer_id: &Option<String>,
+    test_mode: Option<bool>,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    let token = config.bearer_token()?;
+    let mollie = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build();
+
+    match customer_id {
+        Some(customer_id) => {
+            list_mandates(&mollie, customer_id, test_mode, with_response, time_format).await
+        }
+        None => check_all_customers(&mollie, test_mode, with_response, time_format).await,
+    }
+}
+
+/// List the mandates of a single customer
+async fn list_mandates(
+    mollie: &Mollie,
+    customer_id: &str,
+    test_mode: Option<bool>,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing mandates of customer {}", customer_id);
+    let mandates = fetch_mandates(mollie, customer_id, test_mode).await?;
+
+    info!("   {}", Colorize::bright_black(&*Mandate::header()));
+    mandates.iter().enumerate().for_each(|(index, mandate)| {
+        info!(
+            "{}. {}",
+            index + 1,
+            Mandate::new(mandate.clone(), time_format)
+        );
+    });
+
+    if !has_valid_mandate(&mandates) {
+        warn!(
+            "{}",
+            Colorize::red(&*format!("Customer {} has no valid mandate", customer_id))
+        );
+    }
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&mandates).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
+
+/// List every customer with the state of their mandates, highlighting the ones without a valid mandate
+async fn check_all_customers(
+    mollie: &Mollie,
+    test_mode: Option<bool>,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Checking the mandates of all customers");
+    let customers: Vec<CustomerResource> = mollie
+        .customers()
+        .list(Some(250), &None, test_mode)
+        .await?
+        .into_stream()
+        .try_collect()
+        .await?;
+
+    info!(
+        "   {} {}",
+        Colorize::bright_black(&*Customer::header()),
+        Colorize::bright_black("MANDATES")
+    );
+
+    let mut without_valid_mandate = 0;
+    let mut response = serde_json::Map::new();
+    for (index, customer) in customers.iter().enumerate() {
+        let mandates = fetch_mandates(mollie, &customer.id, test_mode).await?;
+        let summary = if has_valid_mandate(&mandates) {
+            Colorize::green(&*summarize(&mandates))
+        } else {
+            without_valid_mandate += 1;
+            Colorize::red(&*format!("NO VALID MANDATE ({})", summarize(&mandates)))
+        };
+
+        info!(
+            "{}. {} | {}",
+            index + 1,
+            Customer::new(customer.clone(), time_format),
+            summary
+        );
+
+        if with_response {
+            response.insert(
+                customer.id.clone(),
+                serde_json::to_value(&mandates).unwrap(),
+            );
+        }
+    }
+
+    info!(
+        "{} of {} customers have no valid mandate",
+        without_valid_mandate,
+        customers.len()
+    );
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&response).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
+
+async fn fetch_mandates(
+    mollie: &Mollie,
+    customer_id: &str,
+    test_mode: Option<bool>,
+) -> miette::Result<Vec<MandateResource>> {
+    Ok(mollie
+        .mandates()
+        .list(customer_id, Some(250), &None, test_mode)
+        .await?
+        .into_stream()
+        .try_collect()
+        .await?)
+}
+
+fn has_valid_mandate(mandates: &[MandateResource]) -> bool {
+    mandates
+        .iter()
+        .any(|mandate| mandate.status == MandateStatus::Valid)
+}
+
+/// Number of mandates per status, e.g. "1 valid, 2 invalid"
+fn summarize(mandates: &[MandateResource]) -> String {
+    if mandates.is_empty() {
+        return String::from("no mandates");
+    }
+
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for mandate in mandates {
+        let status = mandate.status.to_string();
+        match counts.iter_mut().find(|(s, _)| *s == status) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((status, 1)),
+        }
+    }
+
+    counts
+        .iter()
+        .map(|(status, count)| format!("{} {}", count, status))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub struct Mandate {
+    pub id: String,
+    pub status: MandateStatus,
+    pub method: String,
+    pub details: String,
+    pub signature_date: String,
+    pub created_at: String,
+}
+
+impl Mandate {
+    pub fn new(mandate: MandateResource, time_format: TimeFormat) -> Self {
+        let details = &mandate.details;
+        let details = match (&details.consumer_account, &details.card_number) {
+            (Some(account), _) => account.clone(),
+            (None, Some(number)) => format!(
+                "{} **** {}",
+                details.card_label.clone().unwrap_or_default(),
+                number
+            ),
+            (None, None) => String::new(),
+        };
+
+        Self {
+            id: mandate.id,
+            status: mandate.status,
+            method: mandate.method.to_string(),
+            details,
+            signature_date: mandate
+                .signature_date
+                .map(|date| date.to_string())
+                .unwrap_or_default(),
+            created_at: time_format.format(&mandate.created_at),
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^14} {:^8} {:^12} {:^24} {:^10} {} |",
+            "ID", "STATUS", "METHOD", "DETAILS", "SIGNED", "CREATED_AT"
+        )
+    }
+
+    fn colorize(&self, value: &str) -> ColoredString {
+        match self.status {
+            MandateStatus::Valid => Colorize::green(value),
+            MandateStatus::Pending => Colorize::yellow(value),
+            MandateStatus::Invalid => Colorize::red(value),
+            MandateStatus::Unknown(_) => Colorize::blink(value),
+        }
+    }
+}
+
+impl Display for Mandate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {} | {} | {} | {} | {}",
+            self.colorize(&self.id),
+            self.colorize(&self.status.to_string()),
+            self.method,
+            self.details,
+            self.signature_date,
+            Colorize::blue(&*self.created_at),
+        )
+    }
+}
@@ -0,0 +1,284 @@
+use crate::config::ConfigurationService;
+use crate::datetime::{self, DateRange, TimeFormat};
+use crate::input;
+use chrono::{DateTime, FixedOffset};
+use clap::{Parser, Subcommand};
+use colored::Colorize;
+use mollie_api::models::customer::CustomerResource;
+use mollie_api::models::mode::Mode;
+use std::fmt::Display;
+
+mod create;
+mod delete;
+mod get;
+mod list;
+mod mandates;
+mod payments;
+mod update;
+
+#[derive(Parser)]
+#[clap(version, about, arg_required_else_help(true))]
+pub struct CustomersCommand {
+    /// Enable debug logging
+    #[clap(short, long, global = true)]
+    debug: bool,
+
+    /// Print the API response after performing an API call
+    #[clap(long = "withResponse", global = true)]
+    with_response: bool,
+
+    /// Print the API request before performing an API call
+    #[clap(long = "withRequest", global = true)]
+    with_request: bool,
+
+    /// How to render timestamps
+    #[clap(long, value_enum, global = true, default_value_t)]
+    time_format: TimeFormat,
+
+    #[clap(subcommand)]
+    command: Option<CustomersCommands>,
+}
+
+#[derive(Subcommand)]
+pub enum CustomersCommands {
+    /// Create a new customer
+    Create {
+        #[clap(short, long)]
+        interactive: bool,
+
+        /// Full name of the customer
+        #[clap(long)]
+        name: Option<String>,
+
+        #[clap(long)]
+        email: Option<String>,
+
+        /// Preferred locale of the customer, e.g. nl_NL
+        #[clap(long)]
+        locale: Option<String>,
+
+        /// Metadata to store with the customer, as JSON or @file.json
+        #[clap(long, value_parser = input::parse_json::<serde_json::Value>)]
+        metadata: Option<serde_json::Value>,
+
+        /// Create a test mode customer, only used when authenticated with an access token
+        #[clap(long)]
+        testmode: bool,
+    },
+    /// Get a customer's info
+    #[clap(arg_required_else_help(true))]
+    Get { id: String },
+    /// Update the name, email, locale or metadata of a customer
+    #[clap(arg_required_else_help(true))]
+    Update {
+        id: String,
+
+        /// Full name of the customer
+        #[clap(long)]
+        name: Option<String>,
+
+        #[clap(long)]
+        email: Option<String>,
+
+        /// Preferred locale of the customer, e.g. nl_NL
+        #[clap(long)]
+        locale: Option<String>,
+
+        /// Metadata to store with the customer, as JSON or @file.json
+        #[clap(long, value_parser = input::parse_json::<serde_json::Value>)]
+        metadata: Option<serde_json::Value>,
+    },
+    /// Delete a customer, its mandates and subscriptions
+    #[clap(arg_required_else_help(true))]
+    Delete { id: String },
+    /// List customers
+    List {
+        #[clap(short, long)]
+        limit: Option<i32>,
+        #[clap(short, long)]
+        from: Option<String>,
+        #[clap(short, long)]
+        test_mode: Option<bool>,
+        /// Follow the pagination links and list the customers of every page
+        #[clap(long)]
+        all: bool,
+        /// Only list customers created on or after this date (2024-01-31) or ISO 8601 timestamp
+        #[clap(long, value_parser = datetime::parse_since)]
+        since: Option<DateTime<FixedOffset>>,
+        /// Only list customers created on or before this date (2024-01-31) or before this ISO 8601 timestamp
+        #[clap(long, value_parser = datetime::parse_until)]
+        until: Option<DateTime<FixedOffset>>,
+    },
+    /// List the mandates of a customer, or check which customers have no valid mandate
+    Mandates {
+        /// Customer to list the mandates of, all customers are checked when not set
+        id: Option<String>,
+        #[clap(short, long)]
+        test_mode: Option<bool>,
+    },
+    /// List the payments of a customer
+    #[clap(arg_required_else_help(true))]
+    Payments {
+        id: String,
+        #[clap(short, long)]
+        limit: Option<i32>,
+        #[clap(short, long)]
+        from: Option<String>,
+        #[clap(short, long)]
+        test_mode: Option<bool>,
+        /// Follow the pagination links and list the payments of every page
+        #[clap(long)]
+        all: bool,
+    },
+}
+
+pub async fn command(
+    customers_command: &CustomersCommand,
+    config_service: &dyn ConfigurationService,
+) -> miette::Result<()> {
+    let config = config_service.read();
+    let with_response = customers_command.with_response;
+    let time_format = customers_command.time_format;
+    match customers_command.command.as_ref() {
+        Some(CustomersCommands::Create {
+            interactive,
+            name,
+            email,
+            locale,
+            metadata,
+            testmode,
+        }) => {
+            let request = if *interactive {
+                create::ask_request()?
+            } else {
+                create::request(name, email, locale, metadata, *testmode)
+            };
+            create::command(
+                config,
+                &request,
+                customers_command.with_request,
+                with_response,
+                time_format,
+            )
+            .await
+        }
+        Some(CustomersCommands::Get { id }) => {
+            get::command(config, id, with_response, time_format).await
+        }
+        Some(CustomersCommands::Update {
+            id,
+            name,
+            email,
+            locale,
+            metadata,
+        }) => {
+            update::command(
+                config,
+                id,
+                name,
+                email,
+                locale,
+                metadata,
+                with_response,
+                time_format,
+            )
+            .await
+        }
+        Some(CustomersCommands::Delete { id }) => delete::command(config, id).await,
+        Some(CustomersCommands::List {
+            limit,
+            from,
+            test_mode,
+            all,
+            since,
+            until,
+        }) => {
+            let range = DateRange {
+                since: *since,
+                until: *until,
+            };
+            list::command(
+                config,
+                *limit,
+                from,
+                *test_mode,
+                *all,
+                &range,
+                with_response,
+                time_format,
+            )
+            .await
+        }
+        Some(CustomersCommands::Mandates { id, test_mode }) => {
+            mandates::command(config, id, *test_mode, with_response, time_format).await
+        }
+        Some(CustomersCommands::Payments {
+            id,
+            limit,
+            from,
+            test_mode,
+            all,
+        }) => {
+            payments::command(
+                config,
+                id,
+                *limit,
+                from,
+                *test_mode,
+                *all,
+                with_response,
+                time_format,
+            )
+            .await
+        }
+        None => Ok(()),
+    }
+}
+
+pub struct Customer {
+    pub id: String,
+    pub mode: Mode,
+    pub name: String,
+    pub email: String,
+    pub locale: String,
+    pub created_at: String,
+}
+
+impl Customer {
+    pub fn new(customer: CustomerResource, time_format: TimeFormat) -> Self {
+        Self {
+            id: customer.id,
+            mode: customer.mode,
+            name: customer.name.unwrap_or_default(),
+            email: customer.email.unwrap_or_default(),
+            locale: customer.locale.unwrap_or_default(),
+            created_at: time_format.format(&customer.created_at),
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^14} {:^4} {:^19} {:^20} {:^30} {} |",
+            "ID", "MODE", "CREATED_AT", "NAME", "EMAIL", "LOCALE"
+        )
+    }
+}
+
+impl Display for Customer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {} | {} | {} | {} | {}",
+            Colorize::blue(&*self.id),
+            if self.mode == Mode::Live {
+                Colorize::bright_green("LIVE")
+            } else {
+                Colorize::bright_black("TEST")
+            },
+            Colorize::blue(&*self.created_at),
+            self.name,
+            self.email,
+            self.locale,
+        )
+    }
+}
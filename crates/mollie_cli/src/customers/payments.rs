@@ -0,0 +1,36 @@
+use crate::config::MollieConfig;
+use crate::datetime::{DateRange, TimeFormat};
+use crate::payments::list::list_payments;
+use log::debug;
+use mollie_api::Mollie;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn command(
+    config: &MollieConfig,
+    customer_id: &str,
+    limit: Option<i32>,
+    from: &Option<String>,
+    test_mode: Option<bool>,
+    all: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing payments of customer {}", customer_id);
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .customers()
+        .list_payments(customer_id, limit, from, test_mode)
+        .await?;
+
+    list_payments(
+        page,
+        all,
+        with_response,
+        &None,
+        &DateRange::default(),
+        time_format,
+    )
+    .await
+}
@@ -0,0 +1,62 @@
+use crate::config::MollieConfig;
+use crate::customers::Customer;
+use crate::datetime::TimeFormat;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use miette::miette;
+use mollie_api::models::customer::UpdateCustomerRequest;
+use mollie_api::Mollie;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn command(
+    config: &MollieConfig,
+    customer_id: &str,
+    name: &Option<String>,
+    email: &Option<String>,
+    locale: &Option<String>,
+    metadata: &Option<serde_json::Value>,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Running Update API Customer for customer: {}", customer_id);
+
+    if name.is_none() && email.is_none() && locale.is_none() && metadata.is_none() {
+        return Err(miette!(
+            code = "customers::update::nothing_to_update",
+            help = "Pass at least one of --name, --email, --locale or --metadata",
+            "Nothing to update for customer {}",
+            customer_id
+        ));
+    }
+
+    let request = UpdateCustomerRequest {
+        name: name.clone(),
+        email: email.clone(),
+        locale: locale.clone(),
+        metadata: metadata.clone(),
+        testmode: None,
+    };
+
+    let token = config.bearer_token()?;
+    let customer = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .customers()
+        .update(customer_id, &request)
+        .await?;
+
+    info!("{}", Colorize::green("Customer updated"));
+    info!("{}", Colorize::bright_black(&*Customer::header()));
+    info!(
+        "{}",
+        Customer::new(customer.clone(), time_format).to_string()
+    );
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&customer).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,178 @@
+use chrono::{DateTime, Duration, FixedOffset, Local, NaiveDate, TimeZone, Utc};
+
+/// How timestamps are rendered in the output tables
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeFormat {
+    /// In your local timezone, e.g. 2024-01-31 14:05:00
+    #[default]
+    Local,
+    /// Relative to now, e.g. 3h ago
+    Relative,
+    /// ISO 8601 as returned by the Mollie API, e.g. 2024-01-31T13:05:00+00:00
+    Iso,
+}
+
+impl TimeFormat {
+    pub fn format(&self, timestamp: &DateTime<FixedOffset>) -> String {
+        match self {
+            TimeFormat::Local => timestamp
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string(),
+            TimeFormat::Relative => relative(timestamp, Utc::now()),
+            TimeFormat::Iso => timestamp.to_rfc3339(),
+        }
+    }
+}
+
+/// Human readable distance between a timestamp and now, e.g. "3h ago" or "in 2d"
+fn relative(timestamp: &DateTime<FixedOffset>, now: DateTime<Utc>) -> String {
+    let elapsed = now.signed_duration_since(*timestamp);
+    let distance = elapsed.abs();
+
+    let short = if distance < Duration::minutes(1) {
+        return String::from("just now");
+    } else if distance < Duration::hours(1) {
+        format!("{}m", distance.num_minutes())
+    } else if distance < Duration::days(1) {
+        format!("{}h", distance.num_hours())
+    } else if distance < Duration::days(30) {
+        format!("{}d", distance.num_days())
+    } else if distance < Duration::days(365) {
+        format!("{}mo", distance.num_days() / 30)
+    } else {
+        format!("{}y", distance.num_days() / 365)
+    };
+
+    if elapsed < Duration::zero() {
+        format!("in {}", short)
+    } else {
+        format!("{} ago", short)
+    }
+}
+
+/// Range of timestamps used to filter lists, `since` is inclusive and `until` exclusive
+#[derive(Clone, Debug, Default)]
+pub struct DateRange {
+    pub since: Option<DateTime<FixedOffset>>,
+    pub until: Option<DateTime<FixedOffset>>,
+}
+
+impl DateRange {
+    pub fn contains(&self, timestamp: &DateTime<FixedOffset>) -> bool {
+        !self.is_before(timestamp) && !self.is_after(timestamp)
+    }
+
+    /// True if the timestamp is before the start of the range, so that a list
+    /// sorted from new to old can stop once it gets there
+    pub fn is_before(&self, timestamp: &DateTime<FixedOffset>) -> bool {
+        self.since.is_some_and(|since| timestamp < &since)
+    }
+
+    /// True if the timestamp is at or past the end of the range
+    pub fn is_after(&self, timestamp: &DateTime<FixedOffset>) -> bool {
+        self.until.is_some_and(|until| timestamp >= &until)
+    }
+}
+
+/// Parse an ISO 8601 timestamp or a date (start of the day, local time)
+pub fn parse_timestamp(value: &str) -> Result<DateTime<FixedOffset>, String> {
+    parse(value, |date| date)
+}
+
+/// Parse the start of a date range: an ISO 8601 timestamp or a date (start of the day, local time)
+pub fn parse_since(value: &str) -> Result<DateTime<FixedOffset>, String> {
+    parse_timestamp(value)
+}
+
+/// Parse the end of a date range: an ISO 8601 timestamp or a date (the whole day is included)
+pub fn parse_until(value: &str) -> Result<DateTime<FixedOffset>, String> {
+    parse(value, |date| date + Duration::days(1))
+}
+
+/// Parse a date, e.g. 2024-01-31
+pub fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| format!("'{}' is not a date (2024-01-31)", value))
+}
+
+fn parse(
+    value: &str,
+    adjust_date: impl Fn(NaiveDate) -> NaiveDate,
+) -> Result<DateTime<FixedOffset>, String> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Ok(timestamp);
+    }
+
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        format!(
+            "'{}' is not a date (2024-01-31) or ISO 8601 timestamp (2024-01-31T13:05:00+01:00)",
+            value
+        )
+    })?;
+
+    Local
+        .from_local_datetime(&adjust_date(date).and_hms_opt(0, 0, 0).unwrap())
+        .earliest()
+        .map(|timestamp| timestamp.fixed_offset())
+        .ok_or(format!("'{}' does not exist in your local timezone", value))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn timestamp(value: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(value).unwrap()
+    }
+
+    #[test]
+    fn should_render_relative_timestamps() {
+        let now = timestamp("2024-01-31T12:00:00+00:00").with_timezone(&Utc);
+
+        assert_eq!(
+            relative(&timestamp("2024-01-31T11:59:30+00:00"), now),
+            "just now"
+        );
+        assert_eq!(
+            relative(&timestamp("2024-01-31T11:45:00+00:00"), now),
+            "15m ago"
+        );
+        assert_eq!(
+            relative(&timestamp("2024-01-31T12:00:00+03:00"), now),
+            "3h ago"
+        );
+        assert_eq!(
+            relative(&timestamp("2024-01-29T12:00:00+00:00"), now),
+            "2d ago"
+        );
+        assert_eq!(
+            relative(&timestamp("2024-01-31T14:00:00+00:00"), now),
+            "in 2h"
+        );
+    }
+
+    #[test]
+    fn should_filter_date_ranges() {
+        let range = DateRange {
+            since: Some(parse_since("2024-01-01T00:00:00+00:00").unwrap()),
+            until: Some(parse_until("2024-02-01T00:00:00+00:00").unwrap()),
+        };
+
+        assert!(range.contains(&timestamp("2024-01-01T00:00:00+00:00")));
+        assert!(range.contains(&timestamp("2024-01-31T23:59:59+00:00")));
+        assert!(!range.contains(&timestamp("2024-02-01T00:00:00+00:00")));
+        assert!(!range.contains(&timestamp("2023-12-31T23:59:59+00:00")));
+        assert!(range.is_before(&timestamp("2023-12-31T23:59:59+00:00")));
+        assert!(DateRange::default().contains(&timestamp("1970-01-01T00:00:00+00:00")));
+    }
+
+    #[test]
+    fn should_include_the_whole_until_date() {
+        let since = parse_since("2024-01-31").unwrap();
+        let until = parse_until("2024-01-31").unwrap();
+
+        assert_eq!(until - since, Duration::days(1));
+        assert!(parse_since("31-01-2024").is_err());
+    }
+}
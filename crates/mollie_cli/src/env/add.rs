@@ -0,0 +1,38 @@
+use crate::config::{ApiConfig, ConfigurationService};
+use log::info;
+use miette::miette;
+use url::Url;
+
+pub fn command(
+    config_service: &mut dyn ConfigurationService,
+    name: &str,
+    url: &Url,
+    switch: bool,
+) -> miette::Result<()> {
+    if name == ApiConfig::PRODUCTION_ENVIRONMENT {
+        return Err(miette!(
+            code = "env::add",
+            help = "Pick a different name for your environment",
+            "The '{}' environment is built-in and cannot be overridden",
+            name
+        ));
+    }
+
+    config_service.update(&|config| {
+        config
+            .api
+            .environments
+            .insert(name.to_string(), url.clone());
+
+        if switch {
+            config.api.url = url.clone();
+        }
+    })?;
+
+    info!("Added environment {} ({})", name, url);
+    if switch {
+        info!("Switched to environment {}", name);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use crate::config::MollieConfig;
+use colored::Colorize;
+use log::info;
+
+pub fn command(config: &MollieConfig) -> miette::Result<()> {
+    let current = config.api.current_environment();
+
+    for (name, url) in config.api.all_environments() {
+        if current.as_ref() == Some(&name) {
+            info!("* {} | {}", Colorize::green(&*name), url);
+        } else {
+            info!("  {} | {}", name, url);
+        }
+    }
+
+    if current.is_none() {
+        info!("* {} | {}", Colorize::yellow("custom"), config.api.url);
+    }
+
+    Ok(())
+}
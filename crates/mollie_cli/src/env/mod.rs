@@ -0,0 +1,56 @@
+use crate::config::ConfigurationService;
+use clap::{Parser, Subcommand};
+use url::Url;
+
+mod add;
+mod list;
+mod switch;
+
+#[derive(Parser)]
+#[clap(version, about, arg_required_else_help(true))]
+pub struct EnvCommand {
+    #[clap(short, long, global = true)]
+    debug: bool,
+
+    #[clap(subcommand)]
+    command: Option<EnvCommands>,
+}
+
+#[derive(Subcommand)]
+pub enum EnvCommands {
+    /// List the available environments and highlight the one currently in use
+    List,
+    /// Add a new named environment (e.g. a local mock server or a staging proxy)
+    #[clap(arg_required_else_help(true))]
+    Add {
+        /// Name of the environment
+        name: String,
+
+        /// Base url of the API in this environment (e.g. http://localhost:8080/v2)
+        url: Url,
+
+        /// Immediately switch to the new environment
+        #[clap(long = "use")]
+        switch: bool,
+    },
+    /// Switch to a named environment
+    #[clap(arg_required_else_help(true))]
+    Use {
+        /// Name of the environment
+        name: String,
+    },
+}
+
+pub async fn command(
+    command: &EnvCommand,
+    config_service: &mut dyn ConfigurationService,
+) -> miette::Result<()> {
+    match command.command.as_ref() {
+        Some(EnvCommands::List) => list::command(config_service.read()),
+        Some(EnvCommands::Add { name, url, switch }) => {
+            add::command(config_service, name, url, *switch)
+        }
+        Some(EnvCommands::Use { name }) => switch::command(config_service, name),
+        None => Ok(()),
+    }
+}
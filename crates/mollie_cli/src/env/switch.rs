@@ -0,0 +1,26 @@
+use crate::config::ConfigurationService;
+use log::info;
+use miette::miette;
+
+pub fn command(config_service: &mut dyn ConfigurationService, name: &str) -> miette::Result<()> {
+    let url = config_service
+        .read()
+        .api
+        .all_environments()
+        .remove(name)
+        .ok_or_else(|| {
+            miette!(
+                code = "env::use",
+                help = "Run mol env list to see the available environments or mol env add to create a new one",
+                "Unknown environment '{}'",
+                name
+            )
+        })?;
+
+    config_service.update(&|config| {
+        config.api.url = url.clone();
+    })?;
+
+    info!("Switched to environment {} ({})", name, url);
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use serde::de::DeserializeOwned;
+
+/// Parse a JSON argument, given inline or as `@path/to/file.json`
+pub fn parse_json<T: DeserializeOwned>(value: &str) -> Result<T, String> {
+    let json = match value.strip_prefix('@') {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|e| format!("Could not read '{}': {}", path, e))?,
+        None => value.to_string(),
+    };
+
+    serde_json::from_str(&json).map_err(|e| format!("Invalid JSON: {}", e))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn should_parse_inline_json() {
+        let value: HashMap<String, i32> = parse_json(r#"{ "order_id": 12345 }"#).unwrap();
+
+        assert_eq!(value.get("order_id"), Some(&12345));
+        assert!(parse_json::<HashMap<String, i32>>("{ order_id }").is_err());
+    }
+
+    #[test]
+    fn should_parse_json_file() {
+        let path = std::env::temp_dir().join("mol-input-test.json");
+        std::fs::write(&path, r#"[1, 2, 3]"#).unwrap();
+
+        let value: Vec<i32> = parse_json(&format!("@{}", path.display())).unwrap();
+
+        assert_eq!(value, vec![1, 2, 3]);
+        assert!(parse_json::<Vec<i32>>("@/does/not/exist.json").is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use crate::config::MollieConfig;
+use futures::TryStreamExt;
+use log::{debug, info, warn};
+use miette::miette;
+use mollie_api::api::invoices::InvoicesApi;
+use mollie_api::models::invoice::InvoiceResource;
+use mollie_api::Mollie;
+use std::path::Path;
+
+pub async fn command(
+    config: &MollieConfig,
+    invoice_id: &Option<String>,
+    year: Option<i32>,
+    dir: &Path,
+) -> miette::Result<()> {
+    let token = config.bearer_token()?;
+    let invoices_api = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .invoices();
+
+    // The PDF links expire 30 minutes after the invoices were retrieved,
+    // so they are fetched right before downloading
+    let invoices = match invoice_id {
+        Some(invoice_id) => vec![invoices_api.get_by_id(invoice_id).await?],
+        None => {
+            debug!("Listing invoices issued in {:?}", year);
+            invoices_api
+                .list(&None, year, None, &None)
+                .await?
+                .into_stream()
+                .try_collect()
+                .await?
+        }
+    };
+
+    if invoices.is_empty() {
+        info!("No invoices to download");
+        return Ok(());
+    }
+
+    std::fs::create_dir_all(dir)
+        .map_err(|e| miette!("Could not create '{}': {}", dir.display(), e))?;
+
+    for invoice in &invoices {
+        download(&invoices_api, invoice, dir).await?;
+    }
+
+    Ok(())
+}
+
+async fn download(
+    invoices_api: &InvoicesApi,
+    invoice: &InvoiceResource,
+    dir: &Path,
+) -> miette::Result<()> {
+    let Some(pdf_link) = invoice.pdf_link() else {
+        warn!("Invoice {} has no PDF to download", invoice.id);
+        return Ok(());
+    };
+
+    debug!("Downloading the PDF of invoice {}", invoice.id);
+    let pdf = invoices_api.download_pdf(pdf_link).await?;
+
+    let path = dir.join(file_name(&invoice.reference));
+    std::fs::write(&path, pdf)
+        .map_err(|e| miette!("Could not write '{}': {}", path.display(), e))?;
+    info!("Saved invoice {} to {}", invoice.reference, path.display());
+
+    Ok(())
+}
+
+/// Name of the PDF of an invoice, its reference without characters that can't be used in paths
+fn file_name(reference: &str) -> String {
+    let name: String = reference
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' | '_' => c,
+            _ => '_',
+        })
+        .collect();
+
+    format!("{}.pdf", name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_name_pdfs_after_the_reference() {
+        assert_eq!(file_name("2023.10000"), "2023.10000.pdf");
+        assert_eq!(file_name("2023/10 000"), "2023_10_000.pdf");
+    }
+}
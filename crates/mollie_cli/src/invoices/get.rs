@@ -0,0 +1,33 @@
+use crate::config::MollieConfig;
+use crate::invoices::{print_lines, Invoice};
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    invoice_id: &str,
+    with_response: bool,
+) -> miette::Result<()> {
+    debug!("Running Get API Invoice for invoice: {}", invoice_id);
+    let token = config.bearer_token()?;
+    let invoice = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .invoices()
+        .get_by_id(invoice_id)
+        .await?;
+
+    info!("{}", Colorize::bright_black(&*Invoice::header()));
+    info!("{}", Invoice::new(&invoice));
+    print_lines(&invoice);
+    debug!("{:?}", invoice);
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&invoice).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::config::MollieConfig;
+use crate::invoices::Invoice;
+use crate::pagination;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    reference: &Option<String>,
+    year: Option<i32>,
+    limit: Option<i32>,
+    from: &Option<String>,
+    all: bool,
+    with_response: bool,
+) -> miette::Result<()> {
+    debug!("Listing invoices");
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .invoices()
+        .list(reference, year, limit, from)
+        .await?;
+
+    let (invoices, response) = pagination::collect_page(page, all).await?;
+
+    info!("   {}", Colorize::bright_black(&*Invoice::header()));
+    invoices.iter().enumerate().for_each(|(index, invoice)| {
+        info!("{}. {}", index + 1, Invoice::new(invoice));
+    });
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&response).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use crate::config::ConfigurationService;
+use clap::{Parser, Subcommand};
+use colored::{ColoredString, Colorize};
+use log::info;
+use mollie_api::models::invoice::{InvoiceResource, InvoiceStatus};
+use std::fmt::Display;
+use std::path::PathBuf;
+
+mod download;
+mod get;
+mod list;
+
+#[derive(Parser)]
+#[clap(version, about, arg_required_else_help(true))]
+pub struct InvoicesCommand {
+    /// Enable debug logging
+    #[clap(short, long, global = true)]
+    debug: bool,
+
+    /// Print the API response after performing an API call
+    #[clap(long = "withResponse", global = true)]
+    with_response: bool,
+
+    #[clap(subcommand)]
+    command: Option<InvoicesCommands>,
+}
+
+#[derive(Subcommand)]
+pub enum InvoicesCommands {
+    /// List invoices, newest first
+    List {
+        /// Only list the invoice with this reference, e.g. 2023.10000
+        #[clap(long)]
+        reference: Option<String>,
+        /// Only list the invoices issued in this year
+        #[clap(long)]
+        year: Option<i32>,
+        #[clap(short, long)]
+        limit: Option<i32>,
+        #[clap(short, long)]
+        from: Option<String>,
+        /// Follow the pagination links and list the invoices of every page
+        #[clap(long)]
+        all: bool,
+    },
+    /// Get an invoice and its lines
+    Get {
+        #[clap(help = "Id of the invoice, e.g. inv_xBEbP9rvAq")]
+        id: String,
+    },
+    /// Download the PDF of an invoice, or of every invoice issued in a year
+    Download {
+        #[clap(
+            required_unless_present = "year",
+            conflicts_with = "year",
+            help = "Id of the invoice, e.g. inv_xBEbP9rvAq"
+        )]
+        id: Option<String>,
+        /// Download every invoice issued in this year
+        #[clap(long)]
+        year: Option<i32>,
+        /// Directory to save the PDFs in, named after the invoice reference
+        #[clap(long, default_value = ".")]
+        dir: PathBuf,
+    },
+}
+
+pub async fn command(
+    invoices_command: &InvoicesCommand,
+    config_service: &dyn ConfigurationService,
+) -> miette::Result<()> {
+    let config = config_service.read();
+    match invoices_command.command.as_ref() {
+        Some(InvoicesCommands::List {
+            reference,
+            year,
+            limit,
+            from,
+            all,
+        }) => {
+            list::command(
+                config,
+                reference,
+                *year,
+                *limit,
+                from,
+                *all,
+                invoices_command.with_response,
+            )
+            .await
+        }
+        Some(InvoicesCommands::Get { id }) => {
+            get::command(config, id, invoices_command.with_response).await
+        }
+        Some(InvoicesCommands::Download { id, year, dir }) => {
+            download::command(config, id, *year, dir).await
+        }
+        None => Ok(()),
+    }
+}
+
+/// Print the lines of an invoice, followed by its totals
+pub fn print_lines(invoice: &InvoiceResource) {
+    info!(
+        "{}",
+        Colorize::bright_black(&*format!(
+            "|{:^8} {:^6} {:^6} {:^12} {} |",
+            "PERIOD", "COUNT", "VAT", "AMOUNT", "DESCRIPTION"
+        ))
+    );
+    for line in &invoice.lines {
+        info!(
+            "{} | {} | {}% | {} | {}",
+            line.period, line.count, line.vat_percentage, line.amount, line.description,
+        );
+    }
+
+    info!("Net:   {}", invoice.net_amount);
+    info!("VAT:   {}", invoice.vat_amount);
+    info!(
+        "Gross: {}",
+        Colorize::bold(&*invoice.gross_amount.to_string())
+    );
+}
+
+pub struct Invoice {
+    pub id: String,
+    pub reference: String,
+    pub status: InvoiceStatus,
+    pub issued_at: String,
+    pub due_at: String,
+    pub net_amount: String,
+    pub vat_amount: String,
+    pub gross_amount: String,
+}
+
+impl Invoice {
+    pub fn new(invoice: &InvoiceResource) -> Self {
+        Self {
+            id: invoice.id.clone(),
+            reference: invoice.reference.clone(),
+            status: invoice.status.clone(),
+            issued_at: invoice.issued_at.to_string(),
+            due_at: invoice
+                .due_at
+                .map(|due_at| due_at.to_string())
+                .unwrap_or_else(|| String::from("-")),
+            net_amount: invoice.net_amount.to_string(),
+            vat_amount: invoice.vat_amount.to_string(),
+            gross_amount: invoice.gross_amount.to_string(),
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^14} {:^10} {:^7} {:^10} {:^10} {:^12} {:^12} {:^12} |",
+            "ID", "REFERENCE", "STATUS", "ISSUED_AT", "DUE_AT", "NET", "VAT", "GROSS"
+        )
+    }
+
+    fn colorize_status(&self) -> ColoredString {
+        let status = self.status.to_string();
+        match self.status {
+            InvoiceStatus::Paid => status.green(),
+            InvoiceStatus::Open => status.yellow(),
+            InvoiceStatus::Overdue => status.red(),
+            InvoiceStatus::Unknown(_) => status.normal(),
+        }
+    }
+}
+
+impl Display for Invoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {} | {} | {} | {} | {} | {} | {}",
+            self.id,
+            self.reference,
+            self.colorize_status(),
+            Colorize::blue(&*self.issued_at),
+            self.due_at,
+            self.net_amount,
+            self.vat_amount,
+            Colorize::bold(&*self.gross_amount),
+        )
+    }
+}
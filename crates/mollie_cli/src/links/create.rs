@@ -0,0 +1,55 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::links::{print_link, CreateLinkArgs, Link};
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::models::amount::Amount;
+use mollie_api::models::payment_link::CreatePaymentLinkRequest;
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    args: &CreateLinkArgs,
+    with_request: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Running Create Payment Link Command");
+    let amount = Amount::parse(&args.amount, &args.currency)?;
+    let request = CreatePaymentLinkRequest {
+        expires_at: args.expires_at,
+        redirect_url: args.redirect_url.clone(),
+        webhook_url: args.webhook_url.clone(),
+        reusable: args.reusable.then_some(true),
+        allowed_methods: (!args.method.is_empty()).then(|| args.method.clone()),
+        profile_id: config.profile_id(&args.profile_id),
+        testmode: args.testmode.then_some(true),
+        ..CreatePaymentLinkRequest::new(amount, &args.description)
+    };
+
+    if with_request {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&request).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    let token = config.bearer_token()?;
+    let link = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .payment_links()
+        .create(&request)
+        .await?;
+
+    info!("{}", Colorize::green("Payment link created"));
+    info!("{}", Colorize::bright_black(&*Link::header()));
+    info!("{}", Link::new(&link, time_format));
+    print_link(&link, args.qr);
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&link).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
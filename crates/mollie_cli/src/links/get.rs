@@ -0,0 +1,36 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::links::{print_link, Link};
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    link_id: &str,
+    qr: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Running Get API Payment Link for link: {}", link_id);
+    let token = config.bearer_token()?;
+    let link = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .payment_links()
+        .get_by_id(link_id)
+        .await?;
+
+    info!("{}", Colorize::bright_black(&*Link::header()));
+    info!("{}", Link::new(&link, time_format));
+    print_link(&link, qr);
+    debug!("{:?}", link);
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&link).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::links::Link;
+use crate::pagination;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn command(
+    config: &MollieConfig,
+    limit: Option<i32>,
+    from: &Option<String>,
+    profile_id: &Option<String>,
+    test_mode: Option<bool>,
+    all: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing payment links");
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .payment_links()
+        .list(limit, from, &config.profile_id(profile_id), test_mode)
+        .await?;
+
+    let (links, response) = pagination::collect_page(page, all).await?;
+
+    info!("   {}", Colorize::bright_black(&*Link::header()));
+    links.iter().enumerate().for_each(|(index, link)| {
+        info!("{}. {}", index + 1, Link::new(link, time_format));
+    });
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&response).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,263 @@
+use crate::config::ConfigurationService;
+use crate::datetime::{self, TimeFormat};
+use crate::payments::create::parse_method;
+use chrono::{DateTime, FixedOffset, Utc};
+use clap::{Parser, Subcommand};
+use colored::{ColoredString, Colorize};
+use mollie_api::models::method::PaymentMethod;
+use mollie_api::models::payment_link::PaymentLinkResource;
+use std::fmt::Display;
+
+mod create;
+mod get;
+mod list;
+
+#[derive(Parser)]
+#[clap(version, about, arg_required_else_help(true))]
+pub struct LinksCommand {
+    /// Enable debug logging
+    #[clap(short, long, global = true)]
+    debug: bool,
+
+    /// Print the API response after performing an API call
+    #[clap(long = "withResponse", global = true)]
+    with_response: bool,
+
+    /// Print the API request before performing an API call
+    #[clap(long = "withRequest", global = true)]
+    with_request: bool,
+
+    /// How to render timestamps
+    #[clap(long, value_enum, global = true, default_value_t)]
+    time_format: TimeFormat,
+
+    #[clap(subcommand)]
+    command: Option<LinksCommands>,
+}
+
+#[derive(clap::Args)]
+pub struct CreateLinkArgs {
+    #[clap(long)]
+    currency: String,
+
+    #[clap(long)]
+    amount: String,
+
+    #[clap(long)]
+    description: String,
+
+    /// When the link expires, as ISO 8601 timestamp or date (2024-01-31); it never expires when not set
+    #[clap(long, value_parser = datetime::parse_timestamp)]
+    expires_at: Option<DateTime<FixedOffset>>,
+
+    /// Url the customer is redirected to after paying
+    #[clap(long)]
+    redirect_url: Option<String>,
+
+    /// Url Mollie calls when a payment of the link changes status
+    #[clap(long)]
+    webhook_url: Option<String>,
+
+    /// Allow the link to be paid more than once
+    #[clap(long)]
+    reusable: bool,
+
+    /// Only allow this payment method, can be repeated to allow several methods
+    #[clap(long, value_parser = parse_method)]
+    method: Vec<PaymentMethod>,
+
+    #[clap(long)]
+    profile_id: Option<String>,
+
+    /// Create a test mode link, only used when authenticated with an access token
+    #[clap(long)]
+    testmode: bool,
+
+    /// Print the link as a QR code
+    #[clap(long)]
+    qr: bool,
+}
+
+#[derive(Subcommand)]
+pub enum LinksCommands {
+    /// Create a payment link to share with a customer
+    #[clap(arg_required_else_help(true))]
+    Create(Box<CreateLinkArgs>),
+    /// Get a payment link
+    #[clap(arg_required_else_help(true))]
+    Get {
+        id: String,
+        /// Print the link as a QR code
+        #[clap(long)]
+        qr: bool,
+    },
+    /// List payment links
+    List {
+        #[clap(short, long)]
+        limit: Option<i32>,
+        #[clap(short, long)]
+        from: Option<String>,
+        #[clap(short, long)]
+        profile_id: Option<String>,
+        #[clap(short, long)]
+        test_mode: Option<bool>,
+        /// Follow the pagination links and list the payment links of every page
+        #[clap(long)]
+        all: bool,
+    },
+}
+
+pub async fn command(
+    links_command: &LinksCommand,
+    config_service: &dyn ConfigurationService,
+) -> miette::Result<()> {
+    let config = config_service.read();
+    let with_response = links_command.with_response;
+    let time_format = links_command.time_format;
+    match links_command.command.as_ref() {
+        Some(LinksCommands::Create(args)) => {
+            create::command(
+                config,
+                args,
+                links_command.with_request,
+                with_response,
+                time_format,
+            )
+            .await
+        }
+        Some(LinksCommands::Get { id, qr }) => {
+            get::command(config, id, *qr, with_response, time_format).await
+        }
+        Some(LinksCommands::List {
+            limit,
+            from,
+            profile_id,
+            test_mode,
+            all,
+        }) => {
+            list::command(
+                config,
+                *limit,
+                from,
+                profile_id,
+                *test_mode,
+                *all,
+                with_response,
+                time_format,
+            )
+            .await
+        }
+        None => Ok(()),
+    }
+}
+
+/// Print the url of a payment link, and optionally a QR code of it
+pub fn print_link(link: &PaymentLinkResource, qr: bool) {
+    if let Some(url) = link.payment_link() {
+        log::info!("Payment link: {}", Colorize::blue(url));
+        if qr {
+            qr2term::print_qr(url).ok(/* only print qrcode if everything is fine */);
+        }
+    }
+}
+
+/// Whether a payment link can still be paid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkState {
+    Open,
+    Paid,
+    Expired,
+    Archived,
+}
+
+impl LinkState {
+    pub fn of(link: &PaymentLinkResource) -> Self {
+        if link.archived {
+            LinkState::Archived
+        } else if link.paid_at.is_some() && !link.reusable {
+            LinkState::Paid
+        } else if link
+            .expires_at
+            .is_some_and(|expires_at| expires_at < Utc::now())
+        {
+            LinkState::Expired
+        } else {
+            LinkState::Open
+        }
+    }
+}
+
+impl Display for LinkState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = match self {
+            LinkState::Open => "open",
+            LinkState::Paid => "paid",
+            LinkState::Expired => "expired",
+            LinkState::Archived => "archived",
+        };
+        write!(f, "{}", state)
+    }
+}
+
+pub struct Link {
+    pub id: String,
+    pub state: LinkState,
+    pub amount: String,
+    pub created_at: String,
+    pub expires_at: String,
+    pub description: String,
+    pub url: String,
+}
+
+impl Link {
+    pub fn new(link: &PaymentLinkResource, time_format: TimeFormat) -> Self {
+        Self {
+            id: link.id.clone(),
+            state: LinkState::of(link),
+            amount: link
+                .amount
+                .as_ref()
+                .map(|amount| amount.to_string())
+                .unwrap_or_else(|| String::from("any")),
+            created_at: time_format.format(&link.created_at),
+            expires_at: link
+                .expires_at
+                .as_ref()
+                .map(|expires_at| time_format.format(expires_at))
+                .unwrap_or_else(|| String::from("never")),
+            description: link.description.clone(),
+            url: link.payment_link().unwrap_or_default().to_string(),
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^24} {:^8} {:^12} {:^19} {:^19} {:^20} {} |",
+            "ID", "STATE", "AMOUNT", "CREATED_AT", "EXPIRES_AT", "DESCRIPTION", "URL"
+        )
+    }
+
+    fn colorize(&self, value: &str) -> ColoredString {
+        match self.state {
+            LinkState::Open => Colorize::blue(value),
+            LinkState::Paid => Colorize::green(value),
+            LinkState::Expired | LinkState::Archived => Colorize::yellow(value),
+        }
+    }
+}
+
+impl Display for Link {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {} | {} | {} | {} | {} | {}",
+            self.colorize(&self.id),
+            self.colorize(&self.state.to_string()),
+            Colorize::green(&*self.amount),
+            Colorize::blue(&*self.created_at),
+            self.expires_at,
+            self.description,
+            self.url,
+        )
+    }
+}
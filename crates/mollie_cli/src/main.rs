@@ -6,10 +6,24 @@ extern crate jsonxf;
 
 mod auth;
 mod balances;
+mod chargebacks;
 mod config;
+mod customers;
+mod datetime;
+mod env;
+mod input;
+mod invoices;
+mod links;
 mod logger;
+mod methods;
+mod orders;
+mod pagination;
 mod org;
 mod payments;
+mod profiles;
+mod refunds;
+mod settlements;
+mod subscriptions;
 
 #[derive(Parser)]
 #[clap(version, about, arg_required_else_help(true))]
@@ -33,10 +47,32 @@ enum Commands {
     Auth(auth::AuthCommand),
     /// Get information about your balances
     Balances(balances::BalancesCommand),
+    /// Monitor the payments your customers disputed
+    Chargebacks(chargebacks::ChargebacksCommand),
+    /// Create and manage your customers
+    Customers(customers::CustomersCommand),
+    /// Switch between Mollie API environments (e.g. production or a local mock)
+    Env(env::EnvCommand),
+    /// List and download the invoices of your Mollie fees
+    Invoices(invoices::InvoicesCommand),
+    /// Create payment links to share with your customers
+    Links(links::LinksCommand),
+    /// See which payment methods you can offer, their limits, issuers and pricing
+    Methods(methods::MethodsCommand),
+    /// Create and manage orders, needed for pay later methods like Klarna
+    Orders(orders::OrdersCommand),
     /// Get information about the organization you are authenticated as
     Org(org::OrgCommand),
     /// Create, refund and get information about your payments
     Payments(payments::PaymentsCommmand),
+    /// List your website profiles and pick the default one
+    Profiles(profiles::ProfilesCommand),
+    /// List, get and cancel refunds
+    Refunds(refunds::RefundsCommand),
+    /// See what your payouts consist of
+    Settlements(settlements::SettlementsCommand),
+    /// Create and manage the subscriptions of your customers
+    Subscriptions(subscriptions::SubscriptionsCommand),
 }
 
 #[tokio::main]
@@ -56,8 +92,19 @@ async fn main() -> miette::Result<()> {
     match cli.command {
         Some(Commands::Auth(command)) => auth::command(&command, &mut config_service).await?,
         Some(Commands::Balances(command)) => balances::command(&command, &config_service).await?,
+        Some(Commands::Chargebacks(command)) => chargebacks::command(&command, &config_service).await?,
+        Some(Commands::Customers(command)) => customers::command(&command, &config_service).await?,
+        Some(Commands::Env(command)) => env::command(&command, &mut config_service).await?,
+        Some(Commands::Invoices(command)) => invoices::command(&command, &config_service).await?,
+        Some(Commands::Links(command)) => links::command(&command, &config_service).await?,
+        Some(Commands::Methods(command)) => methods::command(&command, &config_service).await?,
+        Some(Commands::Orders(command)) => orders::command(&command, &config_service).await?,
         Some(Commands::Org(command)) => org::command(&command, &config_service).await?,
         Some(Commands::Payments(command)) => payments::command(&command, &config_service).await?,
+        Some(Commands::Profiles(command)) => profiles::command(&command, &mut config_service).await?,
+        Some(Commands::Refunds(command)) => refunds::command(&command, &config_service).await?,
+        Some(Commands::Settlements(command)) => settlements::command(&command, &config_service).await?,
+        Some(Commands::Subscriptions(command)) => subscriptions::command(&command, &config_service).await?,
         None => {}
     };
 
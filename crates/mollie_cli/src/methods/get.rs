@@ -0,0 +1,35 @@
+use crate::config::MollieConfig;
+use crate::methods::{print_details, Method};
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::models::method::{MethodsQuery, PaymentMethod};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    method: &PaymentMethod,
+    query: &MethodsQuery,
+    with_response: bool,
+) -> miette::Result<()> {
+    debug!("Running Get API Method for method: {}", method);
+    let token = config.bearer_token()?;
+    let method = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .methods()
+        .get_by_id(method, query)
+        .await?;
+
+    info!("{}", Colorize::bright_black(&*Method::header()));
+    info!("{}", Method::new(&method));
+    print_details(&method);
+    debug!("{:?}", method);
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&method).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::config::MollieConfig;
+use crate::methods::{print_details, Method};
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::models::method::MethodsQuery;
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    query: &MethodsQuery,
+    all: bool,
+    with_response: bool,
+) -> miette::Result<()> {
+    debug!("Listing payment methods");
+    let token = config.bearer_token()?;
+    let methods_api = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .methods();
+    let response = match all {
+        true => methods_api.list_all(query).await?,
+        false => methods_api.list(query).await?,
+    };
+
+    info!("   {}", Colorize::bright_black(&*Method::header()));
+    response
+        .items()
+        .iter()
+        .enumerate()
+        .for_each(|(index, method)| {
+            info!("{}. {}", index + 1, Method::new(method));
+            print_details(method);
+        });
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&response).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use crate::config::{ConfigurationService, MollieConfig};
+use crate::payments::create::parse_method;
+use crate::payments::Sequence;
+use clap::{Parser, Subcommand};
+use colored::{ColoredString, Colorize};
+use log::info;
+use mollie_api::models::amount::Amount;
+use mollie_api::models::method::{
+    MethodInclude, MethodResource, MethodStatus, MethodsQuery, PaymentMethod,
+};
+use mollie_api::models::payment::SequenceType;
+use std::fmt::Display;
+
+mod get;
+mod list;
+
+#[derive(Parser)]
+#[clap(version, about, arg_required_else_help(true))]
+pub struct MethodsCommand {
+    /// Enable debug logging
+    #[clap(short, long, global = true)]
+    debug: bool,
+
+    /// Print the API response after performing an API call
+    #[clap(long = "withResponse", global = true)]
+    with_response: bool,
+
+    #[clap(subcommand)]
+    command: Option<MethodsCommands>,
+}
+
+#[derive(clap::Args)]
+pub struct MethodFilterArgs {
+    /// Only methods that support this amount, requires --currency
+    #[clap(long, requires = "currency")]
+    amount: Option<String>,
+
+    #[clap(long, requires = "amount")]
+    currency: Option<String>,
+
+    /// Translate the descriptions, e.g. nl_NL
+    #[clap(long)]
+    locale: Option<String>,
+
+    /// Only methods that support this kind of payment
+    #[clap(long, value_enum)]
+    sequence_type: Option<Sequence>,
+
+    /// Only methods available to customers in this country, e.g. NL
+    #[clap(long)]
+    billing_country: Option<String>,
+
+    /// Include the issuers of the methods, e.g. the banks of iDEAL
+    #[clap(long)]
+    issuers: bool,
+
+    /// Include the fees charged for the methods
+    #[clap(long)]
+    pricing: bool,
+
+    #[clap(long)]
+    profile_id: Option<String>,
+
+    /// Only used when authenticated with an access token
+    #[clap(long)]
+    testmode: bool,
+}
+
+impl MethodFilterArgs {
+    fn query(&self, config: &MollieConfig) -> miette::Result<MethodsQuery> {
+        let amount = match (&self.amount, &self.currency) {
+            (Some(amount), Some(currency)) => Some(Amount::parse(amount, currency)?),
+            _ => None,
+        };
+        let include = [
+            (self.issuers, MethodInclude::Issuers),
+            (self.pricing, MethodInclude::Pricing),
+        ]
+        .into_iter()
+        .filter_map(|(included, include)| included.then_some(include))
+        .collect();
+
+        Ok(MethodsQuery {
+            amount,
+            locale: self.locale.clone(),
+            sequence_type: self.sequence_type.as_ref().map(SequenceType::from),
+            billing_country: self.billing_country.clone(),
+            include,
+            profile_id: config.profile_id(&self.profile_id),
+            test_mode: self.testmode.then_some(true),
+        })
+    }
+}
+
+#[derive(Subcommand)]
+pub enum MethodsCommands {
+    /// List the payment methods enabled on the profile
+    List {
+        /// List all methods Mollie offers, including the ones that are not enabled
+        #[clap(long)]
+        all: bool,
+
+        #[clap(flatten)]
+        filters: Box<MethodFilterArgs>,
+    },
+    /// Get a payment method
+    #[clap(arg_required_else_help(true))]
+    Get {
+        #[clap(value_parser = parse_method)]
+        id: PaymentMethod,
+
+        #[clap(flatten)]
+        filters: Box<MethodFilterArgs>,
+    },
+}
+
+pub async fn command(
+    methods_command: &MethodsCommand,
+    config_service: &dyn ConfigurationService,
+) -> miette::Result<()> {
+    let config = config_service.read();
+    let with_response = methods_command.with_response;
+    match methods_command.command.as_ref() {
+        Some(MethodsCommands::List { all, filters }) => {
+            list::command(config, &filters.query(config)?, *all, with_response).await
+        }
+        Some(MethodsCommands::Get { id, filters }) => {
+            get::command(config, id, &filters.query(config)?, with_response).await
+        }
+        None => Ok(()),
+    }
+}
+
+/// Print the issuers and pricing of a method beneath it, when they were included
+pub fn print_details(method: &MethodResource) {
+    for issuer in method.issuers.iter().flatten() {
+        info!(
+            "     {} {} ({})",
+            Colorize::bright_black("issuer "),
+            issuer.name,
+            issuer.id
+        );
+    }
+    for pricing in method.pricing.iter().flatten() {
+        info!(
+            "     {} {}: {} + {}%",
+            Colorize::bright_black("pricing"),
+            pricing.description,
+            pricing.fixed,
+            pricing.variable
+        );
+    }
+}
+
+pub struct Method {
+    pub id: String,
+    pub description: String,
+    pub status: Option<MethodStatus>,
+    pub minimum: String,
+    pub maximum: String,
+    pub issuers: String,
+}
+
+impl Method {
+    pub fn new(method: &MethodResource) -> Self {
+        Self {
+            id: method.id.to_string(),
+            description: method.description.clone(),
+            status: method.status.clone(),
+            minimum: method.minimum_amount.to_string(),
+            maximum: method
+                .maximum_amount
+                .as_ref()
+                .map(|amount| amount.to_string())
+                .unwrap_or_else(|| String::from("no limit")),
+            issuers: method
+                .issuers
+                .as_ref()
+                .map(|issuers| issuers.len().to_string())
+                .unwrap_or_else(|| String::from("-")),
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^14} {:^24} {:^16} {:^14} {:^14} {:^7} |",
+            "ID", "DESCRIPTION", "STATUS", "MINIMUM", "MAXIMUM", "ISSUERS"
+        )
+    }
+
+    fn colorize(&self, value: &str) -> ColoredString {
+        match self.status {
+            None | Some(MethodStatus::Activated) => Colorize::green(value),
+            Some(MethodStatus::Rejected) => Colorize::red(value),
+            Some(_) => Colorize::yellow(value),
+        }
+    }
+}
+
+impl Display for Method {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = self
+            .status
+            .as_ref()
+            .map(MethodStatus::to_string)
+            .unwrap_or_else(|| String::from("-"));
+        write!(
+            f,
+            "{} | {} | {} | {} | {} | {}",
+            self.colorize(&self.id),
+            self.description,
+            self.colorize(&status),
+            Colorize::blue(&*self.minimum),
+            Colorize::blue(&*self.maximum),
+            self.issuers,
+        )
+    }
+}
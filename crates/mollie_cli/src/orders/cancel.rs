@@ -0,0 +1,36 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::orders::print_order;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    order_id: &str,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Running Cancel API Order for order: {}", order_id);
+    let token = config.bearer_token()?;
+    let order = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .orders()
+        .cancel(order_id)
+        .await?;
+
+    info!(
+        "{}",
+        Colorize::red(&*format!("Order {} canceled", order_id))
+    );
+    print_order(&order, time_format);
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&order).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::config::MollieConfig;
+use colored::Colorize;
+use log::{debug, info};
+use mollie_api::models::order::{CancelOrderLinesRequest, OrderLineQuantity};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    order_id: &str,
+    lines: &[OrderLineQuantity],
+    testmode: bool,
+) -> miette::Result<()> {
+    debug!("Running Cancel API Order Lines for order: {}", order_id);
+    let request = CancelOrderLinesRequest {
+        lines: lines.to_vec(),
+        testmode: testmode.then_some(true),
+    };
+
+    let token = config.bearer_token()?;
+    Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .orders()
+        .cancel_lines(order_id, &request)
+        .await?;
+
+    lines.iter().for_each(|line| {
+        let canceled = match line.quantity {
+            Some(quantity) => format!("Quantity {} of line {} canceled", quantity, line.id),
+            None => format!("Line {} canceled", line.id),
+        };
+        info!("{}", Colorize::red(&*canceled));
+    });
+
+    Ok(())
+}
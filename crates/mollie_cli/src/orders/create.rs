@@ -0,0 +1,64 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::orders::{print_order, CreateOrderArgs};
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::models::order::CreateOrderRequest;
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    args: &CreateOrderArgs,
+    with_request: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Running Create Order Command");
+    let lines = args.lines.iter().flatten().cloned().collect();
+    let request = CreateOrderRequest {
+        shipping_address: args.shipping_address.clone(),
+        consumer_date_of_birth: args.consumer_date_of_birth,
+        redirect_url: args.redirect_url.clone(),
+        cancel_url: args.cancel_url.clone(),
+        webhook_url: args.webhook_url.clone(),
+        method: (!args.method.is_empty()).then(|| args.method.clone()),
+        payment: args.payment.clone(),
+        metadata: args.metadata.clone(),
+        expires_at: args.expires_at,
+        profile_id: config.profile_id(&args.profile_id),
+        testmode: args.testmode.then_some(true),
+        ..CreateOrderRequest::new(
+            &args.order_number,
+            lines,
+            args.billing_address.clone(),
+            &args.locale,
+        )?
+    };
+
+    if with_request {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&request).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    let token = config.bearer_token()?;
+    let order = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .orders()
+        .create(&request)
+        .await?;
+
+    info!("{}", Colorize::green("Order created"));
+    print_order(&order, time_format);
+    if let Some(checkout) = order.links.get("checkout") {
+        info!("Checkout: {}", Colorize::blue(&*checkout.href));
+    }
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&order).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
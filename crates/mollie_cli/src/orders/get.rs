@@ -0,0 +1,72 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::orders::{print_order, Shipment};
+use crate::payments::Payment;
+use crate::refunds::Refund;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::models::order::OrderEmbed;
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    order_id: &str,
+    embed: &[OrderEmbed],
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Running Get API Order for order: {}", order_id);
+    let token = config.bearer_token()?;
+    let order = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .orders()
+        .get_by_id(order_id, embed)
+        .await?;
+
+    print_order(&order, time_format);
+
+    let embedded = order.embedded.clone().unwrap_or_default();
+    if embed.contains(&OrderEmbed::Payments) {
+        info!("{}", Colorize::bold("Payments"));
+        info!("   {}", Colorize::bright_black(&*Payment::header()));
+        embedded
+            .payments
+            .into_iter()
+            .enumerate()
+            .for_each(|(index, payment)| {
+                info!("{}. {}", index + 1, Payment::new(payment, time_format));
+            });
+    }
+    if embed.contains(&OrderEmbed::Refunds) {
+        info!("{}", Colorize::bold("Refunds"));
+        info!("   {}", Colorize::bright_black(&*Refund::header()));
+        embedded
+            .refunds
+            .into_iter()
+            .enumerate()
+            .for_each(|(index, refund)| {
+                info!("{}. {}", index + 1, Refund::new(refund, time_format));
+            });
+    }
+    if embed.contains(&OrderEmbed::Shipments) {
+        info!("{}", Colorize::bold("Shipments"));
+        info!("   {}", Colorize::bright_black(&*Shipment::header()));
+        embedded
+            .shipments
+            .iter()
+            .enumerate()
+            .for_each(|(index, shipment)| {
+                info!("{}. {}", index + 1, Shipment::new(shipment, time_format));
+            });
+    }
+    debug!("{:?}", order);
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&order).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
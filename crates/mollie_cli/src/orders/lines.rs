@@ -0,0 +1,133 @@
+use crate::input;
+use mollie_api::models::amount::Amount;
+use mollie_api::models::order::{self, CreateOrderLine};
+use mollie_api::models::payment::LineType;
+use serde::Deserialize;
+
+/// Parse the lines of an order: a JSON array given inline or as `@lines.json`, or a CSV file
+/// given as `@lines.csv`
+pub fn parse_lines(value: &str) -> Result<Vec<CreateOrderLine>, String> {
+    match value.strip_prefix('@') {
+        Some(path) if path.to_lowercase().ends_with(".csv") => {
+            let csv = std::fs::read_to_string(path)
+                .map_err(|e| format!("Could not read '{}': {}", path, e))?;
+            parse_csv(&csv)
+        }
+        _ => input::parse_json(value),
+    }
+}
+
+/// Line of a CSV file, with a header row using the field names of the Mollie API.
+/// When `totalAmount` is left out it's calculated from the other columns, and when `vatAmount`
+/// is left out it's calculated from the total.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CsvLine {
+    #[serde(rename = "type")]
+    line_type: Option<LineType>,
+    name: String,
+    quantity: u32,
+    currency: String,
+    unit_price: String,
+    discount_amount: Option<String>,
+    vat_rate: String,
+    total_amount: Option<String>,
+    vat_amount: Option<String>,
+    sku: Option<String>,
+    image_url: Option<String>,
+    product_url: Option<String>,
+}
+
+fn parse_csv(csv: &str) -> Result<Vec<CreateOrderLine>, String> {
+    csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(csv.as_bytes())
+        .deserialize::<CsvLine>()
+        .enumerate()
+        .map(|(index, row)| {
+            // Row 1 is the header
+            let row_number = index + 2;
+            row.map_err(|e| format!("Invalid CSV on row {}: {}", row_number, e))
+                .and_then(|line| {
+                    into_order_line(line)
+                        .map_err(|e| format!("Invalid order line on row {}: {}", row_number, e))
+                })
+        })
+        .collect()
+}
+
+fn into_order_line(line: CsvLine) -> Result<CreateOrderLine, mollie_api::error::Error> {
+    let amount = |value: &str| Amount::parse(value, &line.currency);
+
+    let mut order_line = CreateOrderLine::new(
+        line.name.clone(),
+        line.quantity,
+        amount(&line.unit_price)?,
+        &line.vat_rate,
+    )?;
+    if let Some(discount) = &line.discount_amount {
+        order_line = order_line.with_discount(amount(discount)?)?;
+    }
+    if let Some(total) = &line.total_amount {
+        order_line.total_amount = amount(total)?;
+        // The VAT calculated so far belongs to the calculated total
+        order_line.vat_amount = order::vat_amount(&order_line.total_amount, &line.vat_rate)?;
+    }
+    if let Some(vat) = &line.vat_amount {
+        order_line.vat_amount = amount(vat)?;
+    }
+
+    Ok(CreateOrderLine {
+        line_type: line.line_type,
+        sku: line.sku,
+        image_url: line.image_url,
+        product_url: line.product_url,
+        ..order_line
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_parse_csv_lines() {
+        let lines = parse_csv(
+            "type,name,quantity,currency,unitPrice,discountAmount,vatRate,sku\n\
+             physical,LEGO 4440,2,EUR,399.00,100.00,21.00,5702016116977\n\
+             shipping_fee,Shipping,1,EUR,4.95,,21.00,\n",
+        )
+        .unwrap();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].line_type, Some(LineType::Physical));
+        assert_eq!(
+            lines[0].total_amount,
+            Amount::parse("698.00", "EUR").unwrap()
+        );
+        assert_eq!(lines[0].vat_amount, Amount::parse("121.14", "EUR").unwrap());
+        assert_eq!(lines[1].line_type, Some(LineType::ShippingFee));
+        assert_eq!(lines[1].discount_amount, None);
+        assert_eq!(lines[1].sku, None);
+
+        let error = parse_csv("name,quantity,currency,unitPrice,vatRate\nLEGO,1,EUR,ten,21\n");
+        assert!(error.unwrap_err().contains("row 2"));
+    }
+
+    #[test]
+    fn should_calculate_the_vat_of_an_overridden_total() {
+        let lines = parse_csv(
+            "name,quantity,currency,unitPrice,vatRate,totalAmount,vatAmount\n\
+             LEGO 4440,2,EUR,399.00,21.00,698.00,\n\
+             LEGO 4441,1,EUR,100.00,21.00,90.00,15.00\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            lines[0].total_amount,
+            Amount::parse("698.00", "EUR").unwrap()
+        );
+        assert_eq!(lines[0].vat_amount, Amount::parse("121.14", "EUR").unwrap());
+        assert_eq!(lines[1].vat_amount, Amount::parse("15.00", "EUR").unwrap());
+    }
+}
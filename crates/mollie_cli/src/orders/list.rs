@@ -0,0 +1,45 @@
+use crate::config::MollieConfig;
+use crate::datetime::{DateRange, TimeFormat};
+use crate::orders::Order;
+use crate::pagination;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn command(
+    config: &MollieConfig,
+    limit: Option<i32>,
+    from: &Option<String>,
+    profile_id: &Option<String>,
+    test_mode: Option<bool>,
+    all: bool,
+    range: &DateRange,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing orders");
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .orders()
+        .list(limit, from, &config.profile_id(profile_id), test_mode)
+        .await?;
+
+    let (mut orders, response) = pagination::collect_page(page, all).await?;
+    orders.retain(|order| range.contains(&order.created_at));
+
+    info!("   {}", Colorize::bright_black(&*Order::header()));
+    orders.iter().enumerate().for_each(|(index, order)| {
+        info!("{}. {}", index + 1, Order::new(order, time_format));
+    });
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&response).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
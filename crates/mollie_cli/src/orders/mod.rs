@@ -0,0 +1,595 @@
+use crate::config::ConfigurationService;
+use crate::datetime::{self, DateRange, TimeFormat};
+use crate::input;
+use crate::payments::create::parse_method;
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use clap::{Parser, Subcommand};
+use colored::{ColoredString, Colorize};
+use mollie_api::models::address::Address;
+use mollie_api::models::amount::Amount;
+use mollie_api::models::method::PaymentMethod;
+use mollie_api::models::mode::Mode;
+use mollie_api::models::order::{
+    CreateOrderLine, OrderEmbed, OrderLine, OrderLineQuantity, OrderLineStatus, OrderResource,
+    OrderStatus,
+};
+use mollie_api::models::shipment::{ShipmentResource, Tracking};
+use std::fmt::Display;
+
+mod cancel;
+mod cancel_lines;
+mod create;
+mod get;
+mod lines;
+mod list;
+mod ship;
+mod shipments;
+mod update;
+mod update_line;
+
+#[derive(Parser)]
+#[clap(version, about, arg_required_else_help(true))]
+pub struct OrdersCommand {
+    /// Enable debug logging
+    #[clap(short, long, global = true)]
+    debug: bool,
+
+    /// Print the API response after performing an API call
+    #[clap(long = "withResponse", global = true)]
+    with_response: bool,
+
+    /// Print the API request before performing an API call
+    #[clap(long = "withRequest", global = true)]
+    with_request: bool,
+
+    /// How to render timestamps
+    #[clap(long, value_enum, global = true, default_value_t)]
+    time_format: TimeFormat,
+
+    #[clap(subcommand)]
+    command: Option<OrdersCommands>,
+}
+
+#[derive(clap::Args)]
+pub struct CreateOrderArgs {
+    /// Your own reference of the order
+    #[clap(long)]
+    order_number: String,
+
+    /// Lines of the order, as a JSON array, @lines.json or @lines.csv. CSV files have a header row
+    /// with the columns type, name, quantity, currency, unitPrice, discountAmount, vatRate, totalAmount,
+    /// vatAmount, sku, imageUrl and productUrl; the total and VAT amount are calculated when left out.
+    /// Can be repeated to combine several files.
+    #[clap(long, required = true, value_parser = lines::parse_lines)]
+    lines: Vec<Vec<CreateOrderLine>>,
+
+    /// Billing address, as JSON or @file.json
+    #[clap(long, value_parser = input::parse_json::<Address>)]
+    billing_address: Address,
+
+    /// Shipping address, as JSON or @file.json
+    #[clap(long, value_parser = input::parse_json::<Address>)]
+    shipping_address: Option<Address>,
+
+    /// Locale of the checkout, e.g. nl_NL
+    #[clap(long)]
+    locale: String,
+
+    /// Date of birth of the consumer (2024-01-31), required by some pay later methods
+    #[clap(long, value_parser = datetime::parse_date)]
+    consumer_date_of_birth: Option<NaiveDate>,
+
+    #[clap(long)]
+    redirect_url: Option<String>,
+
+    /// Url the customer is redirected to after canceling the payment
+    #[clap(long)]
+    cancel_url: Option<String>,
+
+    /// Url Mollie calls when the status of the order changes
+    #[clap(long)]
+    webhook_url: Option<String>,
+
+    /// Only allow this payment method, can be repeated to allow several methods
+    #[clap(long, value_parser = parse_method)]
+    method: Vec<PaymentMethod>,
+
+    /// Parameters for the payment of the order, as JSON or @file.json
+    #[clap(long, value_parser = input::parse_json::<serde_json::Value>)]
+    payment: Option<serde_json::Value>,
+
+    /// Metadata to store with the order, as JSON or @file.json
+    #[clap(long, value_parser = input::parse_json::<serde_json::Value>)]
+    metadata: Option<serde_json::Value>,
+
+    /// Date the order expires (2024-01-31), at most 100 days from now
+    #[clap(long, value_parser = datetime::parse_date)]
+    expires_at: Option<NaiveDate>,
+
+    #[clap(long)]
+    profile_id: Option<String>,
+
+    /// Create a test mode order, only used when authenticated with an access token
+    #[clap(long)]
+    testmode: bool,
+}
+
+#[derive(clap::Args)]
+pub struct UpdateOrderArgs {
+    id: String,
+
+    /// Your own reference of the order
+    #[clap(long)]
+    order_number: Option<String>,
+
+    /// Billing address, as JSON or @file.json
+    #[clap(long, value_parser = input::parse_json::<Address>)]
+    billing_address: Option<Address>,
+
+    /// Shipping address, as JSON or @file.json
+    #[clap(long, value_parser = input::parse_json::<Address>)]
+    shipping_address: Option<Address>,
+
+    #[clap(long)]
+    redirect_url: Option<String>,
+
+    /// Url the customer is redirected to after canceling the payment
+    #[clap(long)]
+    cancel_url: Option<String>,
+
+    /// Url Mollie calls when the status of the order changes
+    #[clap(long)]
+    webhook_url: Option<String>,
+
+    /// Update a test mode order, only used when authenticated with an access token
+    #[clap(long)]
+    testmode: bool,
+}
+
+#[derive(clap::Args)]
+pub struct UpdateOrderLineArgs {
+    order_id: String,
+
+    line_id: String,
+
+    #[clap(long)]
+    name: Option<String>,
+
+    #[clap(long)]
+    quantity: Option<u32>,
+
+    /// Price of a single item, in the currency of the order
+    #[clap(long)]
+    unit_price: Option<String>,
+
+    /// Discount on the whole line, in the currency of the order
+    #[clap(long)]
+    discount_amount: Option<String>,
+
+    /// VAT rate as a percentage, e.g. 21.00
+    #[clap(long)]
+    vat_rate: Option<String>,
+
+    #[clap(long)]
+    sku: Option<String>,
+
+    #[clap(long)]
+    image_url: Option<String>,
+
+    #[clap(long)]
+    product_url: Option<String>,
+
+    /// Metadata to store with the line, as JSON or @file.json
+    #[clap(long, value_parser = input::parse_json::<serde_json::Value>)]
+    metadata: Option<serde_json::Value>,
+
+    /// Update a test mode order, only used when authenticated with an access token
+    #[clap(long)]
+    testmode: bool,
+}
+
+#[derive(clap::Args)]
+pub struct ShipOrderArgs {
+    order_id: String,
+
+    /// Line to ship, as odl_xxx for the whole line or odl_xxx:2 to ship 2 items of it.
+    /// Can be repeated, all shippable lines are shipped when not set
+    #[clap(long = "line", value_parser = parse_line_quantity)]
+    lines: Vec<OrderLineQuantity>,
+
+    /// Postal carrier of the shipment, e.g. PostNL
+    #[clap(long, requires = "code")]
+    carrier: Option<String>,
+
+    /// Track & trace code of the carrier
+    #[clap(long, requires = "carrier")]
+    code: Option<String>,
+
+    /// Url where the customer can track the shipment
+    #[clap(long, requires = "code")]
+    url: Option<String>,
+
+    /// Ship a test mode order, only used when authenticated with an access token
+    #[clap(long)]
+    testmode: bool,
+}
+
+#[derive(Subcommand)]
+pub enum OrdersCommands {
+    /// Create an order, needed for pay later methods like Klarna
+    #[clap(arg_required_else_help(true))]
+    Create(Box<CreateOrderArgs>),
+    /// Get an order with its lines
+    #[clap(arg_required_else_help(true))]
+    Get {
+        id: String,
+        /// Related resources to show, e.g. payments,refunds,shipments
+        #[clap(long, value_delimiter = ',')]
+        embed: Vec<OrderEmbed>,
+    },
+    /// Update the order number, addresses or urls of an order
+    #[clap(arg_required_else_help(true))]
+    Update(Box<UpdateOrderArgs>),
+    /// Update a line of an order, the total and VAT amount are recalculated when needed
+    #[clap(arg_required_else_help(true))]
+    UpdateLine(Box<UpdateOrderLineArgs>),
+    /// Cancel an order, including all its lines
+    #[clap(arg_required_else_help(true))]
+    Cancel { id: String },
+    /// Cancel some lines of an order
+    #[clap(arg_required_else_help(true))]
+    CancelLines {
+        order_id: String,
+        /// Lines to cancel, as odl_xxx for the whole line or odl_xxx:2 to cancel 2 items of it
+        #[clap(required = true, value_parser = parse_line_quantity)]
+        lines: Vec<OrderLineQuantity>,
+        /// Cancel lines of a test mode order, only used when authenticated with an access token
+        #[clap(long)]
+        testmode: bool,
+    },
+    /// Ship (part of) an order, which captures the amount of the shipped lines
+    #[clap(arg_required_else_help(true))]
+    Ship(Box<ShipOrderArgs>),
+    /// List the shipments of an order
+    #[clap(arg_required_else_help(true))]
+    Shipments {
+        order_id: String,
+        #[clap(short, long)]
+        test_mode: Option<bool>,
+    },
+    /// List orders
+    List {
+        #[clap(short, long)]
+        limit: Option<i32>,
+        #[clap(short, long)]
+        from: Option<String>,
+        #[clap(short, long)]
+        profile_id: Option<String>,
+        #[clap(short, long)]
+        test_mode: Option<bool>,
+        /// Follow the pagination links and list the orders of every page
+        #[clap(long)]
+        all: bool,
+        /// Only list orders created on or after this date (2024-01-31) or ISO 8601 timestamp
+        #[clap(long, value_parser = datetime::parse_since)]
+        since: Option<DateTime<FixedOffset>>,
+        /// Only list orders created on or before this date (2024-01-31) or before this ISO 8601 timestamp
+        #[clap(long, value_parser = datetime::parse_until)]
+        until: Option<DateTime<FixedOffset>>,
+    },
+}
+
+pub async fn command(
+    orders_command: &OrdersCommand,
+    config_service: &dyn ConfigurationService,
+) -> miette::Result<()> {
+    let config = config_service.read();
+    let with_response = orders_command.with_response;
+    let time_format = orders_command.time_format;
+    match orders_command.command.as_ref() {
+        Some(OrdersCommands::Create(args)) => {
+            create::command(
+                config,
+                args,
+                orders_command.with_request,
+                with_response,
+                time_format,
+            )
+            .await
+        }
+        Some(OrdersCommands::Get { id, embed }) => {
+            get::command(config, id, embed, with_response, time_format).await
+        }
+        Some(OrdersCommands::Update(args)) => {
+            update::command(config, args, with_response, time_format).await
+        }
+        Some(OrdersCommands::UpdateLine(args)) => {
+            update_line::command(config, args, with_response, time_format).await
+        }
+        Some(OrdersCommands::Cancel { id }) => {
+            cancel::command(config, id, with_response, time_format).await
+        }
+        Some(OrdersCommands::CancelLines {
+            order_id,
+            lines,
+            testmode,
+        }) => cancel_lines::command(config, order_id, lines, *testmode).await,
+        Some(OrdersCommands::Ship(args)) => {
+            let tracking = args
+                .carrier
+                .as_ref()
+                .zip(args.code.as_ref())
+                .map(|(carrier, code)| Tracking {
+                    carrier: carrier.clone(),
+                    code: code.clone(),
+                    url: args.url.clone(),
+                });
+            ship::command(
+                config,
+                &args.order_id,
+                &args.lines,
+                tracking,
+                args.testmode,
+                orders_command.with_request,
+                with_response,
+                time_format,
+            )
+            .await
+        }
+        Some(OrdersCommands::Shipments {
+            order_id,
+            test_mode,
+        }) => shipments::command(config, order_id, *test_mode, with_response, time_format).await,
+        Some(OrdersCommands::List {
+            limit,
+            from,
+            profile_id,
+            test_mode,
+            all,
+            since,
+            until,
+        }) => {
+            let range = DateRange {
+                since: *since,
+                until: *until,
+            };
+            list::command(
+                config,
+                *limit,
+                from,
+                profile_id,
+                *test_mode,
+                *all,
+                &range,
+                with_response,
+                time_format,
+            )
+            .await
+        }
+        None => Ok(()),
+    }
+}
+
+/// Parse a line of an order with an optional quantity: `odl_xxx` or `odl_xxx:2`
+fn parse_line_quantity(value: &str) -> Result<OrderLineQuantity, String> {
+    let (id, quantity) = match value.split_once(':') {
+        Some((id, quantity)) => {
+            let quantity = quantity
+                .parse()
+                .map_err(|_| format!("'{}' is not a number of items", quantity))?;
+            (id, Some(quantity))
+        }
+        None => (value, None),
+    };
+
+    Ok(OrderLineQuantity {
+        id: id.to_string(),
+        quantity,
+        amount: None,
+    })
+}
+
+pub struct Order {
+    pub id: String,
+    pub mode: Mode,
+    pub status: OrderStatus,
+    pub amount: Amount,
+    pub order_number: String,
+    pub created_at: String,
+    pub method: String,
+    pub lines: usize,
+}
+
+impl Order {
+    pub fn new(order: &OrderResource, time_format: TimeFormat) -> Self {
+        Self {
+            id: order.id.clone(),
+            mode: order.mode.clone(),
+            status: order.status.clone(),
+            amount: order.amount.clone(),
+            order_number: order.order_number.clone(),
+            created_at: time_format.format(&order.created_at),
+            method: order
+                .method
+                .as_ref()
+                .map(|method| method.to_string())
+                .unwrap_or_default(),
+            lines: order.lines.len(),
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^14} {:^10} {:^4} {:^12} {:^12} {:^19} {:^12} {} |",
+            "ID", "STATUS", "MODE", "AMOUNT", "ORDER_NUMBER", "CREATED_AT", "METHOD", "LINES"
+        )
+    }
+
+    fn colorize(&self, value: &str) -> ColoredString {
+        match self.status {
+            OrderStatus::Created | OrderStatus::Pending | OrderStatus::Authorized => {
+                Colorize::blue(value)
+            }
+            OrderStatus::Paid | OrderStatus::Shipping | OrderStatus::Completed => {
+                Colorize::green(value)
+            }
+            OrderStatus::Canceled | OrderStatus::Expired => Colorize::yellow(value),
+            OrderStatus::Unknown(_) => Colorize::blink(value),
+        }
+    }
+}
+
+impl Display for Order {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {} | {} | {} | {} | {} | {} | {}",
+            self.colorize(&self.id),
+            self.colorize(&self.status.to_string()),
+            if self.mode == Mode::Live {
+                Colorize::bright_green("LIVE")
+            } else {
+                Colorize::bright_black("TEST")
+            },
+            Colorize::green(&*self.amount.to_string()),
+            self.order_number,
+            Colorize::blue(&*self.created_at),
+            self.method,
+            self.lines,
+        )
+    }
+}
+
+pub struct Line {
+    pub id: String,
+    pub status: OrderLineStatus,
+    pub line_type: String,
+    pub name: String,
+    /// Quantity with how many items were shipped, refunded and canceled
+    pub quantity: String,
+    pub unit_price: Amount,
+    pub total_amount: Amount,
+    pub vat: String,
+}
+
+impl Line {
+    pub fn new(line: &OrderLine) -> Self {
+        Self {
+            id: line.id.clone(),
+            status: line.status.clone(),
+            line_type: line.line_type.to_string(),
+            name: line.name.clone(),
+            quantity: format!(
+                "{} ({}/{}/{})",
+                line.quantity,
+                line.quantity_shipped,
+                line.quantity_refunded,
+                line.quantity_canceled
+            ),
+            unit_price: line.unit_price.clone(),
+            total_amount: line.total_amount.clone(),
+            vat: format!("{} ({}%)", line.vat_amount, line.vat_rate),
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^12} {:^10} {:^12} {:^20} {:^30} {:^12} {:^12} {} |",
+            "ID",
+            "STATUS",
+            "TYPE",
+            "NAME",
+            "QTY (SHIPPED/REFUNDED/CANCELED)",
+            "UNIT_PRICE",
+            "TOTAL",
+            "VAT"
+        )
+    }
+
+    fn colorize(&self, value: &str) -> ColoredString {
+        match self.status {
+            OrderLineStatus::Created | OrderLineStatus::Pending | OrderLineStatus::Authorized => {
+                Colorize::blue(value)
+            }
+            OrderLineStatus::Paid | OrderLineStatus::Shipping | OrderLineStatus::Completed => {
+                Colorize::green(value)
+            }
+            OrderLineStatus::Canceled => Colorize::yellow(value),
+            OrderLineStatus::Unknown(_) => Colorize::blink(value),
+        }
+    }
+}
+
+impl Display for Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {} | {} | {} | {} | {} | {} | {}",
+            self.colorize(&self.id),
+            self.colorize(&self.status.to_string()),
+            self.line_type,
+            self.name,
+            self.quantity,
+            self.unit_price,
+            Colorize::green(&*self.total_amount.to_string()),
+            self.vat,
+        )
+    }
+}
+
+/// Print an order followed by its lines
+pub fn print_order(order: &OrderResource, time_format: TimeFormat) {
+    log::info!("{}", Colorize::bright_black(&*Order::header()));
+    log::info!("{}", Order::new(order, time_format));
+    log::info!("   {}", Colorize::bright_black(&*Line::header()));
+    order.lines.iter().enumerate().for_each(|(index, line)| {
+        log::info!("{}. {}", index + 1, Line::new(line));
+    });
+}
+
+pub struct Shipment {
+    pub id: String,
+    pub created_at: String,
+    pub tracking: String,
+    pub lines: String,
+}
+
+impl Shipment {
+    pub fn new(shipment: &ShipmentResource, time_format: TimeFormat) -> Self {
+        Self {
+            id: shipment.id.clone(),
+            created_at: time_format.format(&shipment.created_at),
+            tracking: shipment
+                .tracking
+                .as_ref()
+                .map(|tracking| format!("{} {}", tracking.carrier, tracking.code))
+                .unwrap_or_default(),
+            lines: shipment
+                .lines
+                .iter()
+                .map(|line| format!("{}x {}", line.quantity, line.name))
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^14} {:^19} {:^20} {} |",
+            "ID", "CREATED_AT", "TRACKING", "LINES"
+        )
+    }
+}
+
+impl Display for Shipment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {} | {} | {}",
+            Colorize::blue(&*self.id),
+            Colorize::blue(&*self.created_at),
+            self.tracking,
+            self.lines,
+        )
+    }
+}
@@ -0,0 +1,59 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::orders::Shipment;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::models::order::OrderLineQuantity;
+use mollie_api::models::shipment::{CreateShipmentRequest, Tracking};
+use mollie_api::Mollie;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn command(
+    config: &MollieConfig,
+    order_id: &str,
+    lines: &[OrderLineQuantity],
+    tracking: Option<Tracking>,
+    testmode: bool,
+    with_request: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Running Create API Shipment for order: {}", order_id);
+    let request = CreateShipmentRequest {
+        lines: lines.to_vec(),
+        tracking,
+        testmode: testmode.then_some(true),
+    };
+
+    if with_request {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&request).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    let token = config.bearer_token()?;
+    let shipment = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .shipments()
+        .create(order_id, &request)
+        .await?;
+
+    info!("{}", Colorize::green("Shipment created"));
+    info!("{}", Colorize::bright_black(&*Shipment::header()));
+    info!("{}", Shipment::new(&shipment, time_format));
+    if let Some(url) = shipment
+        .tracking
+        .as_ref()
+        .and_then(|tracking| tracking.url.as_ref())
+    {
+        info!("Track & trace: {}", Colorize::blue(&**url));
+    }
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&shipment).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::orders::Shipment;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    order_id: &str,
+    test_mode: Option<bool>,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing shipments of order {}", order_id);
+    let token = config.bearer_token()?;
+    let shipments = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .shipments()
+        .list(order_id, test_mode)
+        .await?;
+
+    info!("   {}", Colorize::bright_black(&*Shipment::header()));
+    shipments
+        .items()
+        .iter()
+        .enumerate()
+        .for_each(|(index, shipment)| {
+            info!("{}. {}", index + 1, Shipment::new(shipment, time_format));
+        });
+
+    if with_response {
+        let pretty_json =
+            jsonxf::pretty_print(&serde_json::to_string(&shipments).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
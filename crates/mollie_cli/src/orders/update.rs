@@ -0,0 +1,61 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::orders::{print_order, UpdateOrderArgs};
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use miette::miette;
+use mollie_api::models::order::UpdateOrderRequest;
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    args: &UpdateOrderArgs,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Running Update API Order for order: {}", args.id);
+
+    let request = UpdateOrderRequest {
+        billing_address: args.billing_address.clone(),
+        shipping_address: args.shipping_address.clone(),
+        order_number: args.order_number.clone(),
+        redirect_url: args.redirect_url.clone(),
+        cancel_url: args.cancel_url.clone(),
+        webhook_url: args.webhook_url.clone(),
+        testmode: args.testmode.then_some(true),
+    };
+
+    if request.billing_address.is_none()
+        && request.shipping_address.is_none()
+        && request.order_number.is_none()
+        && request.redirect_url.is_none()
+        && request.cancel_url.is_none()
+        && request.webhook_url.is_none()
+    {
+        return Err(miette!(
+            code = "orders::update::nothing_to_update",
+            help = "Pass at least one of --order-number, --billing-address, --shipping-address, --redirect-url, --cancel-url or --webhook-url",
+            "Nothing to update for order {}",
+            args.id
+        ));
+    }
+
+    let token = config.bearer_token()?;
+    let order = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .orders()
+        .update(&args.id, &request)
+        .await?;
+
+    info!("{}", Colorize::green("Order updated"));
+    print_order(&order, time_format);
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&order).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
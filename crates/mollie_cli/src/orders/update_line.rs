@@ -0,0 +1,115 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::orders::{print_order, UpdateOrderLineArgs};
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use miette::miette;
+use mollie_api::models::amount::Amount;
+use mollie_api::models::order::{CreateOrderLine, UpdateOrderLineRequest};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    args: &UpdateOrderLineArgs,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!(
+        "Running Update API Order Line for line {} of order {}",
+        args.line_id, args.order_id
+    );
+    let token = config.bearer_token()?;
+    let orders_api = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .orders();
+
+    let mut request = UpdateOrderLineRequest {
+        name: args.name.clone(),
+        sku: args.sku.clone(),
+        image_url: args.image_url.clone(),
+        product_url: args.product_url.clone(),
+        metadata: args.metadata.clone(),
+        testmode: args.testmode.then_some(true),
+        ..Default::default()
+    };
+
+    // Mollie requires the total and VAT amount to match the other amounts of the line,
+    // so they are recalculated from the current line whenever one of them changes
+    if args.quantity.is_some()
+        || args.unit_price.is_some()
+        || args.discount_amount.is_some()
+        || args.vat_rate.is_some()
+    {
+        let order = orders_api.get_by_id(&args.order_id, &[]).await?;
+        let line = order
+            .lines
+            .iter()
+            .find(|line| line.id == args.line_id)
+            .ok_or_else(|| {
+                miette!(
+                    code = "orders::update_line::not_found",
+                    "Order {} has no line {}",
+                    args.order_id,
+                    args.line_id
+                )
+            })?;
+        let currency = order.amount.currency();
+
+        let unit_price = match &args.unit_price {
+            Some(unit_price) => Amount::parse(unit_price, currency)?,
+            None => line.unit_price.clone(),
+        };
+        let discount_amount = match &args.discount_amount {
+            Some(discount_amount) => Some(Amount::parse(discount_amount, currency)?),
+            None => line.discount_amount.clone(),
+        };
+        let mut updated = CreateOrderLine::new(
+            line.name.clone(),
+            args.quantity.unwrap_or(line.quantity),
+            unit_price,
+            args.vat_rate.as_ref().unwrap_or(&line.vat_rate),
+        )?;
+        if let Some(discount_amount) = discount_amount {
+            updated = updated.with_discount(discount_amount)?;
+        }
+
+        request.quantity = Some(updated.quantity);
+        request.unit_price = Some(updated.unit_price);
+        request.discount_amount = updated.discount_amount;
+        request.total_amount = Some(updated.total_amount);
+        request.vat_rate = Some(updated.vat_rate);
+        request.vat_amount = Some(updated.vat_amount);
+    }
+
+    if request.name.is_none()
+        && request.quantity.is_none()
+        && request.sku.is_none()
+        && request.image_url.is_none()
+        && request.product_url.is_none()
+        && request.metadata.is_none()
+    {
+        return Err(miette!(
+            code = "orders::update_line::nothing_to_update",
+            help = "Pass at least one of --name, --quantity, --unit-price, --discount-amount, --vat-rate, --sku, --image-url, --product-url or --metadata",
+            "Nothing to update for line {} of order {}",
+            args.line_id,
+            args.order_id
+        ));
+    }
+
+    let order = orders_api
+        .update_line(&args.order_id, &args.line_id, &request)
+        .await?;
+
+    info!("{}", Colorize::green("Order line updated"));
+    print_order(&order, time_format);
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&order).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -3,7 +3,12 @@ use mollie_api::Mollie;
 
 pub async fn command(config: &MollieConfig) -> miette::Result<()> {
     let token = config.bearer_token()?;
-    let response = Mollie::build(token.as_str()).organizations().me().await?;
+    let response = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .organizations()
+        .me()
+        .await?;
     println!("Organization: {:#?}", response.id);
     println!("{:#?}", response);
     Ok(())
@@ -1,7 +1,9 @@
 use crate::config::ConfigurationService;
 use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
 mod me;
+mod onboarding;
 mod permissions;
 
 #[derive(Parser)]
@@ -25,6 +27,12 @@ pub enum OrgCommands {
         #[clap(short, long)]
         granted: bool,
     },
+    /// See whether onboarding is complete and payments and settlements can be received
+    Onboarding {
+        /// Prefill the onboarding with the organization and profile details in a JSON file
+        #[clap(long)]
+        submit: Option<PathBuf>,
+    },
 }
 
 pub async fn command(
@@ -36,6 +44,9 @@ pub async fn command(
         Some(OrgCommands::Permissions { granted }) => {
             permissions::command(config, granted, command.with_response).await?;
         }
+        Some(OrgCommands::Onboarding { submit }) => {
+            onboarding::command(config, submit, command.with_response).await?;
+        }
         None => me::command(config).await?,
     };
     Ok(())
@@ -0,0 +1,116 @@
+use crate::config::MollieConfig;
+use colored::{ColoredString, Colorize};
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use miette::miette;
+use mollie_api::models::onboarding::{
+    OnboardingResource, OnboardingStatus, SubmitOnboardingRequest,
+};
+use mollie_api::Mollie;
+use std::path::{Path, PathBuf};
+
+pub async fn command(
+    config: &MollieConfig,
+    submit: &Option<PathBuf>,
+    with_response: bool,
+) -> miette::Result<()> {
+    // Read the data to submit first, so a broken file doesn't get as far as the API
+    let request = submit.as_deref().map(read_request).transpose()?;
+
+    let token = config.bearer_token()?;
+    let onboarding_api = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .onboarding();
+
+    debug!("Getting the onboarding status");
+    let mut onboarding = onboarding_api.get_status().await?;
+
+    if let Some(request) = request {
+        // Mollie only accepts onboarding data while it still needs it
+        if onboarding.status != OnboardingStatus::NeedsData {
+            return Err(miette!(
+                code = "org::onboarding::submit",
+                help = "Onboarding data can only be submitted while the status is needs-data",
+                "Can't submit onboarding data, the onboarding of {} is {}",
+                onboarding.name,
+                onboarding.status
+            ));
+        }
+
+        debug!("Submitting onboarding data: {:?}", request);
+        onboarding_api.submit(&request).await?;
+        info!("Submitted the onboarding data of {}", onboarding.name);
+
+        onboarding = onboarding_api.get_status().await?;
+    }
+
+    print_status(&onboarding);
+
+    if with_response {
+        let pretty_json =
+            jsonxf::pretty_print(&serde_json::to_string(&onboarding).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
+
+/// Read the onboarding data to submit from a JSON file, using the field names of the Mollie API
+fn read_request(path: &Path) -> miette::Result<SubmitOnboardingRequest> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| miette!("Could not read '{}': {}", path.display(), e))?;
+
+    serde_json::from_str(&json).map_err(|e| {
+        miette!(
+            code = "org::onboarding::submit",
+            help = "The file should contain an organization and/or a profile object, e.g. {\"organization\": {\"name\": \"My Company\"}}",
+            "Invalid onboarding data in '{}': {}",
+            path.display(),
+            e
+        )
+    })
+}
+
+fn print_status(onboarding: &OnboardingResource) {
+    info!("Organization:            {}", onboarding.name);
+    info!("Signed up at:            {}", onboarding.signed_up_at);
+    info!(
+        "Status:                  {}",
+        colorize_status(&onboarding.status)
+    );
+    info!(
+        "Can receive payments:    {}",
+        yes_no(onboarding.can_receive_payments)
+    );
+    info!(
+        "Can receive settlements: {}",
+        yes_no(onboarding.can_receive_settlements)
+    );
+
+    if onboarding.status != OnboardingStatus::Completed {
+        if let Some(dashboard) = onboarding.links.get("dashboard") {
+            info!(
+                "Complete the onboarding in the dashboard: {}",
+                dashboard.href
+            );
+        }
+    }
+}
+
+fn colorize_status(status: &OnboardingStatus) -> ColoredString {
+    let text = status.to_string();
+    match status {
+        OnboardingStatus::NeedsData => text.red(),
+        OnboardingStatus::InReview => text.yellow(),
+        OnboardingStatus::Completed => text.green(),
+        OnboardingStatus::Unknown(_) => text.normal(),
+    }
+}
+
+fn yes_no(value: bool) -> ColoredString {
+    match value {
+        true => "yes".green(),
+        false => "no".red(),
+    }
+}
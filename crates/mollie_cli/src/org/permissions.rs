@@ -10,7 +10,9 @@ pub async fn command(
     filter_granted: &bool,
     with_response: bool,
 ) -> miette::Result<()> {
-    let permissions = Mollie::build(&config.bearer_token()?.as_str())
+    let permissions = Mollie::builder(config.bearer_token()?.as_str())
+        .base_url(config.api.url.clone())
+        .build()
         .permissions()
         .list()
         .await?;
@@ -0,0 +1,22 @@
+use futures::TryStreamExt;
+use mollie_api::models::list::Page;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Items of a page, or of every page starting from this one with `all`, together with the
+/// JSON to print for `--withResponse`.
+///
+/// When following every page there is no single API response to print, so the combined list
+/// of items is printed instead.
+pub async fn collect_page<T>(page: Page<T>, all: bool) -> miette::Result<(Vec<T>, String)>
+where
+    T: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    if all {
+        let items = page.into_stream().try_collect::<Vec<_>>().await?;
+        let response = serde_json::to_string(&items).unwrap();
+        return Ok((items, response));
+    }
+
+    let response = serde_json::to_string(page.resource()).unwrap();
+    Ok((page.into_items(), response))
+}
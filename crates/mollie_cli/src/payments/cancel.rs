@@ -1,4 +1,5 @@
 use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
 use crate::payments::Payment;
 use colored::Colorize;
 use colored_json::ToColoredJson;
@@ -9,30 +10,27 @@ pub async fn command(
     config: &MollieConfig,
     payment_id: &String,
     with_response: bool,
+    time_format: TimeFormat,
 ) -> miette::Result<()> {
     debug!("Running Cancel API Payment for paymner: {}", payment_id);
 
     let token = config.bearer_token()?;
 
-    let cancel = Mollie::build(&token.as_str())
+    let payment = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
         .payments()
         .cancel(payment_id)
-        .await;
+        .await?;
 
-    debug!("{:?}", cancel);
-    match cancel {
-        Ok(p) => {
-            info!("{}", Colorize::red("Payment Cancelled"));
-            info!("{}", Colorize::bright_black(&*Payment::header()));
-            info!("{}", Payment::from(p.clone()).to_string());
-            if with_response {
-                let pretty_json =
-                    jsonxf::pretty_print(&serde_json::to_string(&p).unwrap()).unwrap();
-                info!("{}", pretty_json.to_colored_json_auto().unwrap());
-            }
-        }
-        Err(e) => info!("{}", e),
+    debug!("{:?}", payment);
+    info!("{}", Colorize::red("Payment Cancelled"));
+    info!("{}", Colorize::bright_black(&*Payment::header()));
+    info!("{}", Payment::new(payment.clone(), time_format).to_string());
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&payment).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
     }
 
-    return Ok(());
+    Ok(())
 }
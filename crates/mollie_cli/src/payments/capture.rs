@@ -0,0 +1,56 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::payments::PaymentCapture;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::models::amount::Amount;
+use mollie_api::models::capture::CreateCaptureRequest;
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    payment_id: &str,
+    amount: Option<&str>,
+    description: &Option<String>,
+    idempotency_key: Option<&String>,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    let token = config.bearer_token()?;
+    let mollie = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build();
+
+    // Captures are always in the currency of the payment
+    let payment = mollie.payments().get_by_id(payment_id).await?;
+    let amount = amount
+        .map(|amount| Amount::parse(amount, payment.amount.currency()))
+        .transpose()?;
+    let captured = payment.validate_capture(amount.as_ref())?;
+    debug!("Capturing {} of payment {}", captured, payment_id);
+
+    let request = CreateCaptureRequest {
+        amount,
+        description: description.clone(),
+        ..Default::default()
+    };
+
+    let capture = match idempotency_key {
+        Some(key) => mollie.with_idempotency_key(key),
+        None => mollie,
+    }
+    .captures()
+    .create(payment_id, &request)
+    .await?;
+
+    info!("{}", Colorize::bright_black(&*PaymentCapture::header()));
+    info!("{}", PaymentCapture::new(&capture, time_format));
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&capture).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -1,67 +1,62 @@
-use crate::{config::MollieConfig, payments::create};
+use crate::config::MollieConfig;
+use crate::datetime;
+use crate::input;
+use crate::payments::CreatePaymentArgs;
 use log::{debug, info, warn};
+use miette::miette;
+use mollie_api::auth::ApiBearerToken;
+use mollie_api::models::address::Address;
+use mollie_api::models::amount::Amount;
+use mollie_api::models::currency;
+use mollie_api::models::method::{MethodResource, MethodsQuery, PaymentMethod};
+use mollie_api::models::payment::{
+    CaptureMode, CreatePaymentRequest, CreatePaymentRequestBuilder, PaymentLine, SequenceType,
+};
 use mollie_api::Mollie;
 use requestty::Question;
-use serde::Serialize;
+use chrono::{DateTime, FixedOffset};
 use colored::Colorize;
 use colored_json::ToColoredJson;
 
-pub async fn command(
-    config: &MollieConfig,
-    input_currency: Option<&String>,
-    input_amount: Option<&String>,
-    input_description: Option<&String>,
-    input_redirect_url: Option<&String>,
-    input_profile_id: Option<&String>,
-    debug: &bool,
-    with_request: bool,
-    with_response: bool,
-    qr: bool,
-) -> miette::Result<()> {
+pub async fn command(config: &MollieConfig, args: &CreatePaymentArgs) -> miette::Result<()> {
     debug!("Running Create Payment Command");
-    let currency = String::from(input_currency.unwrap());
-    let description = String::from(input_description.unwrap());
-    let redirect_url = String::from(input_redirect_url.unwrap());
-    let profile_id = Some(String::from(input_profile_id.unwrap()));
-
-    let create_payment_request = mollie_api::models::payment::CreatePaymentRequest {
-        amount: mollie_api::models::amount::Amount {
-            value: format!("{:.2}", input_amount.unwrap().parse::<f64>().unwrap()),
-            currency,
-        },
-        description,
-        redirect_url,
-        profile_id,
-    };
 
-    if debug == &true {
-        let json = serde_json::to_string(&create_payment_request).unwrap();
-        debug!("Request Body: {:?}", json);
-        ask_confirmation();
+    // API keys belong to a single profile, access tokens need to be told which one to use
+    let profile_id = config.profile_id(&args.profile_id);
+    if profile_id.is_none() && !matches!(config.bearer_token(), Ok(ApiBearerToken::ApiKey(_)) | Err(_)) {
+        return Err(miette!(
+            code = "payments::create::profile_id",
+            help = "Pass --profile-id, or set a default profile with `mol profiles use`",
+            "A profile id is required to create a payment with an access token"
+        ));
     }
 
-    debug!("{:?}", create_payment_request);
-
-    if with_request {
-        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&create_payment_request).unwrap()).unwrap();
-        info!("{}", pretty_json.to_colored_json_auto().unwrap());
-    }
-
-    let token = config.bearer_token()?;
-    let response = Mollie::build(&token.as_str())
-        .payments()
-        .create_payment(&create_payment_request)
-        .await;
+    let create_payment_request = CreatePaymentRequest {
+        amount: Amount::parse(args.amount.as_ref().unwrap(), args.currency.as_ref().unwrap())?,
+        description: String::from(args.description.as_ref().unwrap()),
+        redirect_url: args.redirect_url.clone(),
+        cancel_url: args.cancel_url.clone(),
+        webhook_url: args.webhook_url.clone(),
+        lines: args.lines.clone(),
+        billing_address: args.billing_address.clone(),
+        shipping_address: args.shipping_address.clone(),
+        locale: args.locale.clone(),
+        method: (!args.method.is_empty()).then(|| args.method.clone()),
+        restrict_payment_methods_to_country: args.restrict_payment_methods_to_country.clone(),
+        metadata: args.metadata.clone(),
+        sequence_type: args.sequence_type.as_ref().map(SequenceType::from),
+        customer_id: args.customer_id.clone(),
+        capture_mode: args.capture_mode.as_ref().map(CaptureMode::from),
+        capture_delay: args.capture_delay.clone(),
+        expires_at: args.expires_at,
+        profile_id,
+        testmode: args.testmode.then_some(true),
+    };
 
-    log::debug!("{:?}", response);
-    match response {
-        Ok(payment) => handle_payment_created_response(payment, with_response, qr),
-        Err(e) => info!("{}", e),
-    }
-    return Ok(());
+    create_payment(config, &create_payment_request, args).await
 }
 
-pub async fn interactive(config: &MollieConfig, debug: &bool, with_request: bool, with_response: bool, qr: bool) -> miette::Result<()> {
+pub async fn interactive(config: &MollieConfig, args: &CreatePaymentArgs) -> miette::Result<()> {
     debug!("Running interactive Create Payment Command");
 
     // Currency
@@ -72,43 +67,81 @@ pub async fn interactive(config: &MollieConfig, debug: &bool, with_request: bool
     let description = ask_description().unwrap();
     // Redirect URL
     let redirect_url = ask_redirect_url().unwrap();
-    // Webhook (Optional fields [...])
+
     // Profile ID - prompted only if auth is via access token
     let profile_id = ask_profile_id(config).unwrap();
-    let create_payment_request = mollie_api::models::payment::CreatePaymentRequest {
-        amount: mollie_api::models::amount::Amount {
-            currency: amount.currency,
-            value: amount.value,
-        },
-        description,
-        redirect_url,
-        profile_id,
-    };
+    // Sequence type - asked first, as it limits the methods that can be used
+    let sequence_type = ask_sequence_type().unwrap();
+    // Payment methods - picked from the methods available for the amount and sequence type
+    let methods = ask_methods(config, &amount, &profile_id, &sequence_type).await.unwrap();
+
+    let mut builder = CreatePaymentRequest::builder(amount, description);
+    if let Some(redirect_url) = redirect_url {
+        builder = builder.redirect_url(redirect_url);
+    }
+    if let Some(profile_id) = profile_id {
+        builder = builder.profile_id(profile_id);
+    }
+    if let Some(sequence_type) = sequence_type {
+        builder = builder.sequence_type(sequence_type);
+    }
+    if !methods.is_empty() {
+        builder = builder.methods(methods);
+    }
+
+    // Optional fields
+    if ask_optional_fields().unwrap() {
+        builder = ask_optional_parameters(builder).unwrap();
+    }
+
+    let create_payment_request = builder.build();
+
+    create_payment(config, &create_payment_request, args).await
+}
 
-    if with_request {
-        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&create_payment_request).unwrap()).unwrap();
+async fn create_payment(
+    config: &MollieConfig,
+    create_payment_request: &CreatePaymentRequest,
+    args: &CreatePaymentArgs,
+) -> miette::Result<()> {
+    if args.with_request {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(create_payment_request).unwrap()).unwrap();
         info!("{}", pretty_json.to_colored_json_auto().unwrap());
     }
 
-    if debug == &true {
-        let json = serde_json::to_string(&create_payment_request).unwrap();
+    if args.debug {
+        let json = serde_json::to_string(create_payment_request).unwrap();
         debug!("Request Body: {:?}", json);
         ask_confirmation();
     }
 
+    debug!("{:?}", create_payment_request);
+
     let token = config.bearer_token()?;
+    let mollie = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build();
+    let payment = match &args.idempotency_key {
+        Some(key) => mollie.with_idempotency_key(key),
+        None => mollie,
+    }
+    .payments()
+    .create_payment(create_payment_request)
+    .await?;
 
-    let response = Mollie::build(token.as_str())
-        .payments()
-        .create_payment(&create_payment_request)
-        .await;
+    log::debug!("{:?}", payment);
+    handle_payment_created_response(payment, args.with_response, args.qr);
+    Ok(())
+}
 
-    log::debug!("{:?}", response);
-    match response {
-        Ok(payment) => handle_payment_created_response(payment, with_response, qr),
-        Err(e) => info!("{}", e),
+/// Parse a payment method, rejecting the ones this version of the cli doesn't know about
+pub fn parse_method(value: &str) -> Result<PaymentMethod, String> {
+    match value.trim().parse::<PaymentMethod>() {
+        Ok(PaymentMethod::Unknown(_)) | Err(_) => {
+            Err(format!("Unknown payment method: {}", value))
+        }
+        Ok(method) => Ok(method),
     }
-    return Ok(());
 }
 
 fn handle_payment_created_response(response: mollie_api::models::payment::PaymentResource, with_response: bool, qr: bool) {
@@ -154,12 +187,6 @@ fn ask_confirmation() {
     }
 }
 
-#[derive(Serialize, Debug)]
-struct Amount {
-    currency: String,
-    value: String,
-}
-
 #[derive(Debug)]
 struct SorryCouldNotCreatePayment {}
 
@@ -167,6 +194,10 @@ fn ask_currency() -> Result<String, SorryCouldNotCreatePayment> {
     let question = Question::input("currency")
         .message("Currency (3 letter code)")
         .default("EUR")
+        .validate(|value, _| match currency::precision(&value.trim().to_uppercase()) {
+            Some(_) => Ok(()),
+            None => Err(format!("Unsupported currency: {}", value)),
+        })
         .build();
 
     let answer = requestty::prompt_one(question);
@@ -175,34 +206,34 @@ fn ask_currency() -> Result<String, SorryCouldNotCreatePayment> {
         Ok(result) => {
             let answer = result.as_string().unwrap();
 
-            debug!("Selected currency {} - not yet validated", answer);
+            debug!("Selected currency {}", answer);
 
-            // TODO: add validation
-            Ok(String::from(answer))
+            Ok(answer.trim().to_uppercase())
         }
         Err(_) => Err(SorryCouldNotCreatePayment {}),
     }
 }
 
 fn ask_amount(currency: String) -> Result<Amount, SorryCouldNotCreatePayment> {
-    let question = Question::float("amount")
-        .message("Amount (format depends on your desired currency")
-        .default(1.00)
+    let validation_currency = currency.clone();
+    let question = Question::input("amount")
+        .message(format!("Amount in {} (e.g. 10.00)", currency))
+        .default("1.00")
+        .validate(move |value, _| {
+            Amount::parse(value, &validation_currency)
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        })
         .build();
 
     let answer = requestty::prompt_one(question);
 
     match answer {
         Ok(result) => {
-            let answer = result.as_float().unwrap();
-            debug!("Input amount {} - not yet validated", answer);
-            let amount = Amount {
-                currency,
-                value: format!("{:.2}", answer),
-            };
-            debug!("Amount {:?} (not validated)", amount);
+            let answer = result.as_string().unwrap();
+            let amount = Amount::parse(answer, &currency).map_err(|_| SorryCouldNotCreatePayment {})?;
+            debug!("Amount {}", amount);
 
-            // TODO: add validation
             Ok(amount)
         }
         Err(_) => Err(SorryCouldNotCreatePayment {}),
@@ -230,9 +261,9 @@ fn ask_description() -> Result<String, SorryCouldNotCreatePayment> {
     }
 }
 
-fn ask_redirect_url() -> Result<String, SorryCouldNotCreatePayment> {
+fn ask_redirect_url() -> Result<Option<String>, SorryCouldNotCreatePayment> {
     let question = Question::input("redirect_url")
-        .message("Choose a redirect_url")
+        .message("Choose a redirect_url (leave empty for recurring payments)")
         .default("https://example.com/?source=mol-cli")
         .build();
 
@@ -245,20 +276,30 @@ fn ask_redirect_url() -> Result<String, SorryCouldNotCreatePayment> {
             debug!("Redirect URL: {} - not yet validated", answer);
 
             // TODO: add validation
-            Ok(String::from(answer))
+            let answer = answer.trim();
+            Ok((!answer.is_empty()).then(|| String::from(answer)))
         }
         Err(_) => Err(SorryCouldNotCreatePayment {}),
     }
 }
 
 fn ask_profile_id(config: &MollieConfig) -> Result<Option<String>, SorryCouldNotCreatePayment> {
-    if !config.auth.access_code.is_some() {
+    // API keys belong to a single profile, only access tokens need a profile id
+    if let Ok(ApiBearerToken::ApiKey(_)) | Err(_) = config.bearer_token() {
         return Ok(None);
     }
 
+    if let Some(profile_id) = config.profile_id(&None) {
+        debug!("Using default profile {}", profile_id);
+        return Ok(Some(profile_id));
+    }
+
     let question = Question::input("profile_id")
-        .message("Input a profile id")
-        .default("pfl_CRjJMqbnVr")
+        .message("Input a profile id (use `mol profiles use` to set a default)")
+        .validate(|value, _| match value.trim().starts_with("pfl_") {
+            true => Ok(()),
+            false => Err(format!("Invalid profile id: {}", value)),
+        })
         .build();
 
     let answer = requestty::prompt_one(question);
@@ -267,11 +308,227 @@ fn ask_profile_id(config: &MollieConfig) -> Result<Option<String>, SorryCouldNot
         Ok(result) => {
             let answer = result.as_string().unwrap();
 
-            debug!("Redirect URL: {} - not yet validated", answer);
+            debug!("Profile ID: {}", answer);
 
-            // TODO: add validation
-            Ok(Some(String::from(answer)))
+            Ok(Some(String::from(answer.trim())))
         }
         Err(_) => Err(SorryCouldNotCreatePayment {}),
     }
 }
+
+fn ask_optional_fields() -> Result<bool, SorryCouldNotCreatePayment> {
+    let question = Question::confirm("optional")
+        .message("Set optional parameters (webhook, metadata, addresses...)?")
+        .default(false)
+        .build();
+
+    match requestty::prompt_one(question) {
+        Ok(result) => Ok(result.as_bool().unwrap()),
+        Err(_) => Err(SorryCouldNotCreatePayment {}),
+    }
+}
+
+fn ask_optional_parameters(
+    mut builder: CreatePaymentRequestBuilder,
+) -> Result<CreatePaymentRequestBuilder, SorryCouldNotCreatePayment> {
+    if let Some(webhook_url) = ask_optional_input("webhook_url", "Webhook URL")? {
+        builder = builder.webhook_url(webhook_url);
+    }
+    if let Some(cancel_url) = ask_optional_input("cancel_url", "Cancel URL")? {
+        builder = builder.cancel_url(cancel_url);
+    }
+    if let Some(locale) = ask_optional_input("locale", "Locale (e.g. nl_NL)")? {
+        builder = builder.locale(locale);
+    }
+    if let Some(country) = ask_optional_input(
+        "restrict_payment_methods_to_country",
+        "Restrict payment methods to country (e.g. NL)",
+    )? {
+        builder = builder.restrict_payment_methods_to_country(country);
+    }
+    if let Some(metadata) = ask_optional_json::<serde_json::Value>("metadata", "Metadata (JSON)")? {
+        builder = builder.metadata(metadata);
+    }
+    if let Some(address) = ask_optional_json::<Address>("billing_address", "Billing address (JSON)")? {
+        builder = builder.billing_address(address);
+    }
+    if let Some(address) = ask_optional_json::<Address>("shipping_address", "Shipping address (JSON)")? {
+        builder = builder.shipping_address(address);
+    }
+    if let Some(lines) = ask_optional_json::<Vec<PaymentLine>>("lines", "Lines (JSON array)")? {
+        builder = builder.lines(lines);
+    }
+    if let Some(customer_id) = ask_optional_input("customer_id", "Customer ID")? {
+        builder = builder.customer_id(customer_id);
+    }
+    if let Some(capture_mode) = ask_capture_mode()? {
+        builder = builder.capture_mode(capture_mode);
+    }
+    if let Some(capture_delay) = ask_optional_input("capture_delay", "Capture delay (e.g. 8 hours)")? {
+        builder = builder.capture_delay(capture_delay);
+    }
+    if let Some(expires_at) = ask_expires_at()? {
+        builder = builder.expires_at(expires_at);
+    }
+    if ask_testmode()? {
+        builder = builder.testmode(true);
+    }
+
+    Ok(builder)
+}
+
+/// Ask for an optional value, an empty answer means the value is not set
+fn ask_optional_input(name: &str, message: &str) -> Result<Option<String>, SorryCouldNotCreatePayment> {
+    let question = Question::input(name)
+        .message(format!("{} (leave empty to skip)", message))
+        .build();
+
+    match requestty::prompt_one(question) {
+        Ok(result) => {
+            let answer = result.as_string().unwrap().trim();
+            Ok((!answer.is_empty()).then(|| String::from(answer)))
+        }
+        Err(_) => Err(SorryCouldNotCreatePayment {}),
+    }
+}
+
+fn ask_optional_json<T: serde::de::DeserializeOwned>(
+    name: &str,
+    message: &str,
+) -> Result<Option<T>, SorryCouldNotCreatePayment> {
+    let question = Question::input(name)
+        .message(format!("{} or @file.json (leave empty to skip)", message))
+        .validate(|value, _| match value.trim() {
+            "" => Ok(()),
+            value => input::parse_json::<T>(value).map(|_| ()),
+        })
+        .build();
+
+    match requestty::prompt_one(question) {
+        Ok(result) => match result.as_string().unwrap().trim() {
+            "" => Ok(None),
+            value => Ok(Some(input::parse_json(value).map_err(|_| SorryCouldNotCreatePayment {})?)),
+        },
+        Err(_) => Err(SorryCouldNotCreatePayment {}),
+    }
+}
+
+/// Let the user pick the payment methods, out of the ones that are enabled and support the
+/// amount and sequence type. When none are picked, or the methods can't be listed, the customer
+/// chooses any method in the checkout.
+async fn ask_methods(
+    config: &MollieConfig,
+    amount: &Amount,
+    profile_id: &Option<String>,
+    sequence_type: &Option<SequenceType>,
+) -> Result<Vec<PaymentMethod>, SorryCouldNotCreatePayment> {
+    let query = MethodsQuery {
+        amount: Some(amount.clone()),
+        profile_id: profile_id.clone(),
+        sequence_type: sequence_type.clone(),
+        ..Default::default()
+    };
+
+    let methods = match list_methods(config, &query).await {
+        Ok(methods) => methods,
+        Err(e) => {
+            warn!("Couldn't list the payment methods, the customer will choose one: {}", e);
+            return Ok(Vec::new());
+        }
+    };
+
+    let question = Question::multi_select("method")
+        .message("Payment methods (select none to let the customer choose)")
+        .choices(
+            methods
+                .iter()
+                .map(|method| format!("{} ({})", method.description, method.id)),
+        )
+        .build();
+
+    match requestty::prompt_one(question) {
+        Ok(result) => Ok(result
+            .as_list_items()
+            .unwrap()
+            .iter()
+            .map(|item| methods[item.index].id.clone())
+            .collect()),
+        Err(_) => Err(SorryCouldNotCreatePayment {}),
+    }
+}
+
+async fn list_methods(config: &MollieConfig, query: &MethodsQuery) -> miette::Result<Vec<MethodResource>> {
+    let token = config.bearer_token()?;
+    let methods = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .methods()
+        .list(query)
+        .await?;
+    Ok(methods.into_items())
+}
+
+fn ask_sequence_type() -> Result<Option<SequenceType>, SorryCouldNotCreatePayment> {
+    let question = Question::select("sequence_type")
+        .message("Sequence type")
+        .choices(vec!["(not set)", "oneoff", "first", "recurring"])
+        .default(0)
+        .build();
+
+    match requestty::prompt_one(question) {
+        Ok(result) => match result.as_list_item().unwrap().index {
+            0 => Ok(None),
+            1 => Ok(Some(SequenceType::OneOff)),
+            2 => Ok(Some(SequenceType::First)),
+            _ => Ok(Some(SequenceType::Recurring)),
+        },
+        Err(_) => Err(SorryCouldNotCreatePayment {}),
+    }
+}
+
+fn ask_expires_at() -> Result<Option<DateTime<FixedOffset>>, SorryCouldNotCreatePayment> {
+    let question = Question::input("expires_at")
+        .message("Expires at, as ISO 8601 timestamp or date (leave empty to skip)")
+        .validate(|value, _| match value.trim() {
+            "" => Ok(()),
+            value => datetime::parse_timestamp(value).map(|_| ()),
+        })
+        .build();
+
+    match requestty::prompt_one(question) {
+        Ok(result) => match result.as_string().unwrap().trim() {
+            "" => Ok(None),
+            value => Ok(Some(datetime::parse_timestamp(value).map_err(|_| SorryCouldNotCreatePayment {})?)),
+        },
+        Err(_) => Err(SorryCouldNotCreatePayment {}),
+    }
+}
+
+fn ask_testmode() -> Result<bool, SorryCouldNotCreatePayment> {
+    let question = Question::confirm("testmode")
+        .message("Create a test mode payment? (only used with an access token)")
+        .default(false)
+        .build();
+
+    match requestty::prompt_one(question) {
+        Ok(result) => Ok(result.as_bool().unwrap()),
+        Err(_) => Err(SorryCouldNotCreatePayment {}),
+    }
+}
+
+fn ask_capture_mode() -> Result<Option<CaptureMode>, SorryCouldNotCreatePayment> {
+    let question = Question::select("capture_mode")
+        .message("Capture mode")
+        .choices(vec!["(not set)", "automatic", "manual"])
+        .default(0)
+        .build();
+
+    match requestty::prompt_one(question) {
+        Ok(result) => match result.as_list_item().unwrap().index {
+            0 => Ok(None),
+            1 => Ok(Some(CaptureMode::Automatic)),
+            _ => Ok(Some(CaptureMode::Manual)),
+        },
+        Err(_) => Err(SorryCouldNotCreatePayment {}),
+    }
+}
@@ -1,11 +1,16 @@
 use crate::config::MollieConfig;
+use crate::datetime::{DateRange, TimeFormat};
 use crate::payments::Payment;
 use crate::payments::Status;
 use colored::Colorize;
 use colored_json::ToColoredJson;
+use futures::TryStreamExt;
 use log::{debug, info};
+use mollie_api::models::list::Page;
+use mollie_api::models::payment::{PaymentResource, PaymentStatus, PaymentsListResource};
 use mollie_api::Mollie;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn command(
     config: &MollieConfig,
     limit: &Option<i32>,
@@ -14,67 +19,113 @@ pub async fn command(
     test_mode: &Option<bool>,
     with_response: bool,
     status: &Option<Status>,
+    all: bool,
+    range: &DateRange,
+    time_format: TimeFormat,
 ) -> miette::Result<()> {
-    debug!("Listing 10 Payments");
+    debug!("Listing Payments");
     let token = config.bearer_token()?;
-    let response = Mollie::build(&token.as_str())
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
         .payments()
-        .list(limit, from, profile_id, test_mode)
-        .await;
-    match response {
-        Ok(res) => {
-            list_payments_from_response(res, with_response, status);
-        }
-        Err(e) => info!("{}", e),
+        .list(limit, from, &config.profile_id(profile_id), test_mode)
+        .await?;
+
+    list_payments(page, all, with_response, status, range, time_format).await
+}
+
+/// Print a page of payments, or every payment starting from this page with `all`
+pub async fn list_payments(
+    page: Page<PaymentResource>,
+    all: bool,
+    with_response: bool,
+    status: &Option<Status>,
+    range: &DateRange,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    if all {
+        return list_all_payments(page, with_response, status, range, time_format).await;
     }
-    return Ok(());
+
+    list_payments_from_response(page.resource(), with_response, status, range, time_format);
+    Ok(())
 }
 
 fn list_payments_from_response(
-    response: mollie_api::models::payment::PaymentsListResource,
+    response: &PaymentsListResource,
     with_response: bool,
     status: &Option<Status>,
+    range: &DateRange,
+    time_format: TimeFormat,
 ) {
     info!(" {}", Colorize::bright_black(&*Payment::header()));
-    /*response.embedded.payments.iter().enumerate().for_each(|(index, payment)| {
-        info!("{}. {}", index + 1, Payment::from(payment.clone()).to_string());
-    });
-    */
-    match status {
-        Some(s) => {
-            response
-                .embedded
-                .payments
-                .iter()
-                .enumerate()
-                .for_each(|(index, payment)| {
-                    if payment.status == s.to_string().to_lowercase() {
-                        info!(
-                            "{}. {}",
-                            index + 1,
-                            Payment::from(payment.clone()).to_string()
-                        );
-                    }
-                });
+    response
+        .items()
+        .iter()
+        .filter(|payment| is_listed(payment, status, range))
+        .enumerate()
+        .for_each(|(index, payment)| list_payment(index, payment, time_format));
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&response).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+}
+
+async fn list_all_payments(
+    page: Page<PaymentResource>,
+    with_response: bool,
+    status: &Option<Status>,
+    range: &DateRange,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    info!(" {}", Colorize::bright_black(&*Payment::header()));
+
+    let mut payments = page.into_stream();
+    let mut listed = Vec::new();
+    let mut index = 0;
+    while let Some(payment) = payments.try_next().await? {
+        // Payments are listed from new to old, so all the following pages are out of range too
+        if range.is_before(&payment.created_at) {
+            break;
+        }
+
+        if !is_listed(&payment, status, range) {
+            continue;
         }
-        None => {
-            response
-                .embedded
-                .payments
-                .iter()
-                .enumerate()
-                .for_each(|(index, payment)| {
-                    info!(
-                        "{}. {}",
-                        index + 1,
-                        Payment::from(payment.clone()).to_string()
-                    );
-                });
+
+        list_payment(index, &payment, time_format);
+        index += 1;
+
+        if with_response {
+            listed.push(payment);
         }
     }
 
     if with_response {
-        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&response).unwrap()).unwrap();
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&listed).unwrap()).unwrap();
         info!("{}", pretty_json.to_colored_json_auto().unwrap());
     }
+
+    Ok(())
+}
+
+/// Whether the payment has the status and was created in the range the list is filtered on
+fn is_listed(payment: &PaymentResource, status: &Option<Status>, range: &DateRange) -> bool {
+    if let Some(s) = status {
+        if payment.status != PaymentStatus::from(s) {
+            return false;
+        }
+    }
+
+    range.contains(&payment.created_at)
+}
+
+fn list_payment(index: usize, payment: &PaymentResource, time_format: TimeFormat) {
+    info!(
+        "{}. {}",
+        index + 1,
+        Payment::new(payment.clone(), time_format).to_string()
+    );
 }
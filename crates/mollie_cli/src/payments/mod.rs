@@ -1,15 +1,29 @@
 use std::fmt::Display;
 
 use crate::config::ConfigurationService;
+use crate::datetime::{self, DateRange, TimeFormat};
+use crate::input;
+use chrono::{DateTime, FixedOffset};
 use clap::{Parser, Subcommand};
-use colored::Colorize;
-use mollie_api::models::payment::PaymentResource;
+use colored::{ColoredString, Colorize};
+use mollie_api::models::mode::Mode;
+use mollie_api::models::address::Address;
+use mollie_api::models::amount::Amount;
+use mollie_api::models::capture::{CaptureResource, CaptureStatus};
+use mollie_api::models::method::PaymentMethod;
+use mollie_api::models::payment::{
+    CaptureMode, PaymentLine, PaymentResource, PaymentStatus, SequenceType,
+};
 use strum::Display;
 mod cancel;
-mod create;
+mod capture;
+pub mod create;
 mod get;
-mod list;
+pub mod list;
 mod refund;
+mod refunds;
+mod release;
+mod update;
 
 #[derive(Parser)]
 #[clap(version, about, arg_required_else_help(true))]
@@ -23,6 +37,10 @@ pub struct PaymentsCommmand {
     #[clap(long = "withRequest", global = true)]
     with_request: bool,
 
+    /// How to render timestamps
+    #[clap(long, value_enum, global = true, default_value_t)]
+    time_format: TimeFormat,
+
     #[clap(subcommand)]
     command: Option<PaymentsCommands>,
 }
@@ -38,43 +56,201 @@ pub enum Status {
     Paid,
 }
 
-#[derive(Subcommand)]
-pub enum PaymentsCommands {
-    /// Create a new payment
-    Create {
-        #[clap(short, long, global = true)]
-        debug: bool,
+impl From<&Status> for PaymentStatus {
+    fn from(status: &Status) -> Self {
+        match status {
+            Status::Open => PaymentStatus::Open,
+            Status::Canceled => PaymentStatus::Canceled,
+            Status::Pending => PaymentStatus::Pending,
+            Status::Authorized => PaymentStatus::Authorized,
+            Status::Expired => PaymentStatus::Expired,
+            Status::Failed => PaymentStatus::Failed,
+            Status::Paid => PaymentStatus::Paid,
+        }
+    }
+}
 
-        #[clap(short, long)]
-        interactive: bool,
+#[derive(clap::ValueEnum, Clone)]
+pub enum Sequence {
+    Oneoff,
+    First,
+    Recurring,
+}
 
-        #[clap(long, required_unless_present("interactive"))]
-        currency: Option<String>,
+impl From<&Sequence> for SequenceType {
+    fn from(sequence: &Sequence) -> Self {
+        match sequence {
+            Sequence::Oneoff => SequenceType::OneOff,
+            Sequence::First => SequenceType::First,
+            Sequence::Recurring => SequenceType::Recurring,
+        }
+    }
+}
 
-        #[clap(long, required_unless_present("interactive"))]
-        amount: Option<String>,
+#[derive(clap::ValueEnum, Clone)]
+pub enum Capture {
+    Automatic,
+    Manual,
+}
 
-        #[clap(long, required_unless_present("interactive"))]
-        description: Option<String>,
+impl From<&Capture> for CaptureMode {
+    fn from(capture: &Capture) -> Self {
+        match capture {
+            Capture::Automatic => CaptureMode::Automatic,
+            Capture::Manual => CaptureMode::Manual,
+        }
+    }
+}
 
-        #[clap(long, required_unless_present("interactive"))]
-        redirect_url: Option<String>,
+#[derive(clap::Args)]
+pub struct CreatePaymentArgs {
+    #[clap(short, long, global = true)]
+    debug: bool,
 
-        #[clap(long, required_unless_present("interactive"))]
-        profile_id: Option<String>,
+    #[clap(short, long)]
+    interactive: bool,
 
-        #[clap(long = "withRequest", global = true)]
-        with_request: bool,
+    #[clap(long, required_unless_present("interactive"))]
+    currency: Option<String>,
 
-        #[clap(long = "withResponse", global = true)]
-        with_response: bool,
+    #[clap(long, required_unless_present("interactive"))]
+    amount: Option<String>,
 
-        #[clap(long = "qr")]
-        qr: bool,
-    },
+    #[clap(long, required_unless_present("interactive"))]
+    description: Option<String>,
+
+    /// Where the customer is sent after the payment, not needed for recurring payments
+    #[clap(long)]
+    redirect_url: Option<String>,
+
+    /// Only needed with an access token, when no default profile is set with `mol profiles use`
+    #[clap(long)]
+    profile_id: Option<String>,
+
+    /// Url Mollie calls when the status of the payment changes
+    #[clap(long)]
+    webhook_url: Option<String>,
+
+    /// Url the customer is redirected to after canceling the payment
+    #[clap(long)]
+    cancel_url: Option<String>,
+
+    /// Only allow this payment method, can be repeated to allow several methods
+    #[clap(long, value_parser = create::parse_method)]
+    method: Vec<PaymentMethod>,
+
+    /// Locale of the checkout, e.g. nl_NL
+    #[clap(long)]
+    locale: Option<String>,
+
+    /// Only allow the payment methods available in this country (ISO 3166-1 alpha-2 code)
+    #[clap(long)]
+    restrict_payment_methods_to_country: Option<String>,
+
+    /// Metadata to store with the payment, as JSON or @file.json
+    #[clap(long, value_parser = input::parse_json::<serde_json::Value>)]
+    metadata: Option<serde_json::Value>,
+
+    /// Billing address, as JSON or @file.json
+    #[clap(long, value_parser = input::parse_json::<Address>)]
+    billing_address: Option<Address>,
+
+    /// Shipping address, as JSON or @file.json
+    #[clap(long, value_parser = input::parse_json::<Address>)]
+    shipping_address: Option<Address>,
+
+    /// Lines of the payment, as a JSON array or @file.json
+    #[clap(long, value_parser = input::parse_json::<Vec<PaymentLine>>)]
+    lines: Option<Vec<PaymentLine>>,
+
+    #[clap(long)]
+    customer_id: Option<String>,
+
+    #[clap(long, value_enum)]
+    sequence_type: Option<Sequence>,
+
+    #[clap(long, value_enum)]
+    capture_mode: Option<Capture>,
+
+    /// Delay before the payment is captured, e.g. "8 hours" or "2 days"
+    #[clap(long)]
+    capture_delay: Option<String>,
+
+    /// When the payment expires, as ISO 8601 timestamp or date (2024-01-31)
+    #[clap(long, value_parser = datetime::parse_timestamp)]
+    expires_at: Option<DateTime<FixedOffset>>,
+
+    /// Create a test mode payment, only used when authenticated with an access token
+    #[clap(long)]
+    testmode: bool,
+
+    #[clap(long = "withRequest", global = true)]
+    with_request: bool,
+
+    #[clap(long = "withResponse", global = true)]
+    with_response: bool,
+
+    #[clap(long = "qr")]
+    qr: bool,
+
+    /// Idempotency key to send along, to safely retry creating the same payment
+    #[clap(long)]
+    idempotency_key: Option<String>,
+}
+
+#[derive(clap::Args)]
+pub struct UpdatePaymentArgs {
+    id: String,
+
+    #[clap(long)]
+    description: Option<String>,
+
+    #[clap(long)]
+    redirect_url: Option<String>,
+
+    /// Url the customer is redirected to after canceling the payment
+    #[clap(long)]
+    cancel_url: Option<String>,
+
+    /// Url Mollie calls when the status of the payment changes
+    #[clap(long)]
+    webhook_url: Option<String>,
+
+    /// Only allow this payment method, can be repeated to allow several methods
+    #[clap(long, value_parser = create::parse_method)]
+    method: Vec<PaymentMethod>,
+
+    /// Locale of the checkout, e.g. nl_NL
+    #[clap(long)]
+    locale: Option<String>,
+
+    /// Only allow the payment methods available in this country (ISO 3166-1 alpha-2 code)
+    #[clap(long)]
+    restrict_payment_methods_to_country: Option<String>,
+
+    /// Metadata to store with the payment, as JSON or @file.json
+    #[clap(long, value_parser = input::parse_json::<serde_json::Value>)]
+    metadata: Option<serde_json::Value>,
+
+    /// Update a test mode payment, only used when authenticated with an access token
+    #[clap(long)]
+    testmode: bool,
+
+    /// Idempotency key to send along, to safely retry the same update
+    #[clap(long)]
+    idempotency_key: Option<String>,
+}
+
+#[derive(Subcommand)]
+pub enum PaymentsCommands {
+    /// Create a new payment
+    Create(Box<CreatePaymentArgs>),
     /// Get a payment's info
     #[clap(arg_required_else_help(true))]
     Get { id: String },
+    /// Update the description, urls, metadata or method restrictions of an open payment
+    #[clap(arg_required_else_help(true))]
+    Update(Box<UpdatePaymentArgs>),
     /// List payments
     List {
         #[clap(short, long)]
@@ -87,22 +263,72 @@ pub enum PaymentsCommands {
         test_mode: Option<bool>,
         #[clap(short, long)]
         status: Option<Status>,
+        /// Follow the pagination links and list the payments of every page
+        #[clap(long)]
+        all: bool,
+        /// Only list payments created on or after this date (2024-01-31) or ISO 8601 timestamp
+        #[clap(long, value_parser = datetime::parse_since)]
+        since: Option<DateTime<FixedOffset>>,
+        /// Only list payments created on or before this date (2024-01-31) or before this ISO 8601 timestamp
+        #[clap(long, value_parser = datetime::parse_until)]
+        until: Option<DateTime<FixedOffset>>,
     },
     /// Refund a payment
     #[clap(arg_required_else_help(true))]
     Refund {
         id: String,
 
+        /// Amount to refund (e.g. 10.00), in the currency of the payment
         #[clap(long)]
-        amount: f32,
+        amount: String,
 
         #[clap(long)]
         description: String,
+
+        /// Idempotency key to send along, to safely retry issuing the same refund
+        #[clap(long)]
+        idempotency_key: Option<String>,
+    },
+
+    /// List the refunds of a payment
+    #[clap(arg_required_else_help(true))]
+    Refunds {
+        payment_id: String,
+        #[clap(short, long)]
+        limit: Option<i32>,
+        #[clap(short, long)]
+        from: Option<String>,
+        #[clap(short, long)]
+        test_mode: Option<bool>,
+        /// Follow the pagination links and list the refunds of every page
+        #[clap(long)]
+        all: bool,
     },
 
     ///Cancel a Payment
     #[clap(arg_required_else_help(true))]
     Cancel { id: String },
+
+    /// Capture an authorized payment, fully or partially
+    #[clap(arg_required_else_help(true))]
+    Capture {
+        id: String,
+
+        /// Amount to capture (e.g. 10.00) in the currency of the payment, the whole remaining authorized amount when not set
+        #[clap(long)]
+        amount: Option<String>,
+
+        #[clap(long)]
+        description: Option<String>,
+
+        /// Idempotency key to send along, to safely retry the same capture
+        #[clap(long)]
+        idempotency_key: Option<String>,
+    },
+
+    /// Release the remaining authorized amount of a payment that won't be captured
+    #[clap(arg_required_else_help(true))]
+    Release { id: String },
 }
 
 pub async fn command(
@@ -111,41 +337,25 @@ pub async fn command(
 ) -> miette::Result<()> {
     let config = config_service.read();
     match payments_command.command.as_ref() {
-        Some(PaymentsCommands::Create {
-            debug,
-            interactive,
-            currency,
-            amount,
-            description,
-            redirect_url,
-            profile_id,
-            with_request,
-            with_response,
-            qr,
-        }) => {
-            match interactive {
-                true => {
-                    return create::interactive(config, debug, *with_request, *with_response, *qr).await;
-                }
-                false => {}
+        Some(PaymentsCommands::Create(args)) => {
+            if args.interactive {
+                return create::interactive(config, args).await;
             }
 
-            create::command(
+            create::command(config, args).await?;
+        }
+        Some(PaymentsCommands::Update(args)) => {
+            update::command(
                 config,
-                currency.as_ref(),
-                amount.as_ref(),
-                description.as_ref(),
-                redirect_url.as_ref(),
-                profile_id.as_ref(),
-                debug,
-                *with_request,
-                *with_response,
-                *qr,
+                args,
+                payments_command.with_request,
+                payments_command.with_response,
+                payments_command.time_format,
             )
             .await?;
         }
         Some(PaymentsCommands::Get { id }) => {
-            get::command(config, id, payments_command.with_response).await?;
+            get::command(config, id, payments_command.with_response, payments_command.time_format).await?;
         }
         Some(PaymentsCommands::List {
             limit,
@@ -153,7 +363,14 @@ pub async fn command(
             profile_id,
             test_mode,
             status,
+            all,
+            since,
+            until,
         }) => {
+            let range = DateRange {
+                since: *since,
+                until: *until,
+            };
             list::command(
                 config,
                 limit,
@@ -162,6 +379,9 @@ pub async fn command(
                 test_mode,
                 payments_command.with_response,
                 status,
+                *all,
+                &range,
+                payments_command.time_format,
             )
             .await?;
         }
@@ -169,18 +389,60 @@ pub async fn command(
             id,
             amount,
             description,
+            idempotency_key,
         }) => {
             refund::command(
                 config,
                 id,
                 amount,
                 description,
+                idempotency_key.as_ref(),
+                payments_command.with_response,
+                payments_command.time_format,
+            )
+            .await?;
+        }
+        Some(PaymentsCommands::Refunds {
+            payment_id,
+            limit,
+            from,
+            test_mode,
+            all,
+        }) => {
+            refunds::command(
+                config,
+                payment_id,
+                *limit,
+                from,
+                *test_mode,
+                *all,
                 payments_command.with_response,
+                payments_command.time_format,
             )
             .await?;
         }
         Some(PaymentsCommands::Cancel { id }) => {
-            cancel::command(config, id, payments_command.with_response).await?;
+            cancel::command(config, id, payments_command.with_response, payments_command.time_format).await?;
+        }
+        Some(PaymentsCommands::Capture {
+            id,
+            amount,
+            description,
+            idempotency_key,
+        }) => {
+            capture::command(
+                config,
+                id,
+                amount.as_deref(),
+                description,
+                idempotency_key.as_ref(),
+                payments_command.with_response,
+                payments_command.time_format,
+            )
+            .await?;
+        }
+        Some(PaymentsCommands::Release { id }) => {
+            release::command(config, id).await?;
         }
         None => {}
     }
@@ -190,8 +452,8 @@ pub async fn command(
 
 pub struct Payment {
     pub id: String,
-    pub mode: String,
-    pub status: String,
+    pub mode: Mode,
+    pub status: PaymentStatus,
     pub amount: String,
     pub created_at: String,
     pub description: String,
@@ -205,18 +467,30 @@ impl Payment {
             "ID", "STATUS", "MODE", "AMOUNT", "CREATED_AT", "DESCITPION", "REDIRECT_URL"
         )
     }
+
+    fn colorize(&self, value: &str) -> ColoredString {
+        match self.status {
+            PaymentStatus::Open | PaymentStatus::Pending | PaymentStatus::Authorized => {
+                Colorize::blue(value)
+            }
+            PaymentStatus::Canceled | PaymentStatus::Expired => Colorize::yellow(value),
+            PaymentStatus::Failed => Colorize::red(value),
+            PaymentStatus::Paid => Colorize::green(value),
+            PaymentStatus::Unknown(_) => Colorize::blink(value),
+        }
+    }
 }
 
-impl From<PaymentResource> for Payment {
-    fn from(payment: PaymentResource) -> Self {
+impl Payment {
+    pub fn new(payment: PaymentResource, time_format: TimeFormat) -> Self {
         Self {
             id: payment.id,
             mode: payment.mode,
             status: payment.status,
             amount: payment.amount.to_string(),
-            created_at: payment.created_at,
+            created_at: time_format.format(&payment.created_at),
             description: payment.description,
-            redirect_url: payment.redirect_url,
+            redirect_url: payment.redirect_url.unwrap_or_default(),
         }
     }
 }
@@ -226,59 +500,9 @@ impl Display for Payment {
         write!(
             f,
             "{} | {} | {} | {} | {} | {} | {}  ",
-            match self.status.as_str() {
-                "open" => {
-                    Colorize::blue(&*self.id)
-                }
-                "cancelled" => {
-                    Colorize::yellow(&*self.id)
-                }
-                "pending" => {
-                    Colorize::blue(&*self.id)
-                }
-                "authorized" => {
-                    Colorize::blue(&*self.id)
-                }
-                "expired" => {
-                    Colorize::yellow(&*self.id)
-                }
-                "failed" => {
-                    Colorize::red(&*self.id)
-                }
-                "paid" => {
-                    Colorize::green(&*self.id)
-                }
-                &_ => {
-                    Colorize::blink(&*self.id)
-                }
-            },
-            match self.status.as_str() {
-                "open" => {
-                    Colorize::blue(&*self.status)
-                }
-                "cancelled" => {
-                    Colorize::yellow(&*self.status)
-                }
-                "pending" => {
-                    Colorize::blue(&*self.status)
-                }
-                "authorized" => {
-                    Colorize::blue(&*self.status)
-                }
-                "expired" => {
-                    Colorize::yellow(&*self.status)
-                }
-                "failed" => {
-                    Colorize::red(&*self.status)
-                }
-                "paid" => {
-                    Colorize::green(&*self.status)
-                }
-                &_ => {
-                    Colorize::blink(&*self.status)
-                }
-            },
-            if self.mode == "live" {
+            self.colorize(&self.id),
+            self.colorize(&self.status.to_string()),
+            if self.mode == Mode::Live {
                 Colorize::bright_green("LIVE")
             } else {
                 Colorize::bright_black("TEST")
@@ -290,3 +514,97 @@ impl Display for Payment {
         )
     }
 }
+
+pub struct PaymentCapture {
+    pub id: String,
+    pub status: CaptureStatus,
+    pub amount: Amount,
+    pub settlement_amount: String,
+    pub payment_id: String,
+    pub created_at: String,
+    pub description: String,
+}
+
+impl PaymentCapture {
+    pub fn new(capture: &CaptureResource, time_format: TimeFormat) -> Self {
+        Self {
+            id: capture.id.clone(),
+            status: capture.status.clone(),
+            amount: capture.amount.clone(),
+            settlement_amount: capture
+                .settlement_amount
+                .as_ref()
+                .map(|amount| amount.to_string())
+                .unwrap_or_else(|| String::from("-")),
+            payment_id: capture.payment_id.clone(),
+            created_at: time_format.format(&capture.created_at),
+            description: capture.description.clone().unwrap_or_default(),
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^14} {:^10} {:^12} {:^16} {:^14} {:^19} {} |",
+            "ID",
+            "STATUS",
+            "AMOUNT",
+            "SETTLEMENT_AMOUNT",
+            "PAYMENT_ID",
+            "CREATED_AT",
+            "DESCRIPTION"
+        )
+    }
+
+    fn colorize(&self, value: &str) -> ColoredString {
+        match self.status {
+            CaptureStatus::Pending => Colorize::blue(value),
+            CaptureStatus::Succeeded => Colorize::green(value),
+            CaptureStatus::Failed => Colorize::red(value),
+            CaptureStatus::Unknown(_) => Colorize::blink(value),
+        }
+    }
+}
+
+impl Display for PaymentCapture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {} | {} | {} | {} | {} | {}",
+            self.colorize(&self.id),
+            self.colorize(&self.status.to_string()),
+            Colorize::green(&*self.amount.to_string()),
+            self.settlement_amount,
+            self.payment_id,
+            Colorize::blue(&*self.created_at),
+            self.description,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_create_payments_without_profile_id() {
+        // API keys belong to a single profile, so the profile id is checked once the auth is known
+        let command = PaymentsCommmand::try_parse_from([
+            "payments",
+            "create",
+            "--currency",
+            "EUR",
+            "--amount",
+            "10.00",
+            "--description",
+            "Order #12345",
+            "--redirect-url",
+            "https://example.com/order/12345",
+        ])
+        .unwrap();
+
+        match command.command {
+            Some(PaymentsCommands::Create(args)) => assert_eq!(args.profile_id, None),
+            _ => panic!("expected the create command"),
+        }
+    }
+}
@@ -1,31 +1,60 @@
 use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::refunds::Refund;
+use colored::Colorize;
 use colored_json::ToColoredJson;
+use miette::miette;
+use mollie_api::models::amount::Amount;
 use mollie_api::Mollie;
 
 pub async fn command(
     config: &MollieConfig,
-    payment_id: &String,
-    amount: &f32,
+    payment_id: &str,
+    amount: &str,
     description: &String,
+    idempotency_key: Option<&String>,
     with_response: bool,
+    time_format: TimeFormat,
 ) -> miette::Result<()> {
+    let token = config.bearer_token()?;
+    let mollie = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build();
+
+    // Refunds are always in the currency of the payment
+    let payment = mollie.payments().get_by_id(payment_id).await?;
+    let amount = Amount::parse(amount, payment.amount.currency())?;
+
+    if let Some(remaining) = &payment.amount_remaining {
+        if &amount > remaining {
+            return Err(miette!(
+                code = "payments::refund::amount",
+                help = "Refunds can't exceed the amount remaining on the payment",
+                "Can't refund {}, only {} remains on payment {}",
+                amount,
+                remaining,
+                payment_id
+            ));
+        }
+    }
+
     let request = mollie_api::models::refund::RefundPaymentRequest {
-        amount: mollie_api::models::amount::Amount {
-            value: format!("{:.2}", amount),
-            currency: String::from("EUR"),
-        },
+        amount,
         description: String::from(description),
     };
 
-    let token = config.bearer_token()?;
-    let response = Mollie::build(&token.as_str())
-        .refunds()
-        .refund(&payment_id, &request)
-        .await;
+    let response = match idempotency_key {
+        Some(key) => mollie.with_idempotency_key(key),
+        None => mollie,
+    }
+    .refunds()
+    .refund(payment_id, &request)
+    .await;
 
     match response {
         Ok(res) => {
-            log::info!("{}", res.to_string());
+            log::info!("{}", Colorize::bright_black(&*Refund::header()));
+            log::info!("{}", Refund::new(res.clone(), time_format).to_string());
             if with_response {
                 let pretty_json =
                     jsonxf::pretty_print(&serde_json::to_string(&res).unwrap()).unwrap();
@@ -35,5 +64,5 @@ pub async fn command(
         Err(e) => log::info!("{:?}", e),
     }
 
-    return Ok(());
+    Ok(())
 }
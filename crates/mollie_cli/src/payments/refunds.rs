@@ -0,0 +1,28 @@
+use crate::config::MollieConfig;
+use crate::datetime::{DateRange, TimeFormat};
+use crate::refunds::list::list_refunds;
+use log::debug;
+use mollie_api::Mollie;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn command(
+    config: &MollieConfig,
+    payment_id: &str,
+    limit: Option<i32>,
+    from: &Option<String>,
+    test_mode: Option<bool>,
+    all: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing refunds of payment {}", payment_id);
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .refunds()
+        .list_for_payment(payment_id, limit, from, test_mode)
+        .await?;
+
+    list_refunds(page, all, &DateRange::default(), with_response, time_format).await
+}
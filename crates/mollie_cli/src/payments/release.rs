@@ -0,0 +1,23 @@
+use crate::config::MollieConfig;
+use log::info;
+use mollie_api::Mollie;
+
+pub async fn command(config: &MollieConfig, payment_id: &str) -> miette::Result<()> {
+    let token = config.bearer_token()?;
+    let payments_api = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .payments();
+
+    // Only an authorization that wasn't fully captured yet can be released
+    let payment = payments_api.get_by_id(payment_id).await?;
+    let remaining = payment.validate_capture(None)?;
+
+    payments_api.release_authorization(payment_id).await?;
+
+    info!(
+        "Releasing the remaining authorized {} of payment {}",
+        remaining, payment_id
+    );
+    Ok(())
+}
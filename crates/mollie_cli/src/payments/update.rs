@@ -0,0 +1,80 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::payments::{Payment, UpdatePaymentArgs};
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use miette::miette;
+use mollie_api::models::payment::UpdatePaymentRequest;
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    args: &UpdatePaymentArgs,
+    with_request: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Running Update API Payment for payment: {}", args.id);
+
+    // --testmode only selects the payment to update, it doesn't change anything
+    if args.description.is_none()
+        && args.redirect_url.is_none()
+        && args.cancel_url.is_none()
+        && args.webhook_url.is_none()
+        && args.metadata.is_none()
+        && args.method.is_empty()
+        && args.locale.is_none()
+        && args.restrict_payment_methods_to_country.is_none()
+    {
+        return Err(miette!(
+            code = "payments::update::nothing_to_update",
+            help = "Pass at least one of the flags listed in `mol payments update --help`",
+            "Nothing to update for payment {}",
+            args.id
+        ));
+    }
+
+    let request = UpdatePaymentRequest {
+        description: args.description.clone(),
+        redirect_url: args.redirect_url.clone(),
+        cancel_url: args.cancel_url.clone(),
+        webhook_url: args.webhook_url.clone(),
+        metadata: args.metadata.clone(),
+        method: (!args.method.is_empty()).then(|| args.method.clone()),
+        locale: args.locale.clone(),
+        restrict_payment_methods_to_country: args.restrict_payment_methods_to_country.clone(),
+        testmode: args.testmode.then_some(true),
+    };
+
+    let body = serde_json::to_string(&request).unwrap();
+
+    if with_request {
+        let pretty_json = jsonxf::pretty_print(&body).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    let token = config.bearer_token()?;
+    let mollie = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build();
+    let payment = match &args.idempotency_key {
+        Some(key) => mollie.with_idempotency_key(key),
+        None => mollie,
+    }
+    .payments()
+    .update(&args.id, &request)
+    .await?;
+
+    debug!("{:?}", payment);
+    info!("{}", Colorize::green("Payment updated"));
+    info!("{}", Colorize::bright_black(&*Payment::header()));
+    info!("{}", Payment::new(payment.clone(), time_format).to_string());
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&payment).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
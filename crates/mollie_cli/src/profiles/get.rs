@@ -0,0 +1,44 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::profiles::Profile;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    profile_id: &Option<String>,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    let token = config.bearer_token()?;
+    let profiles_api = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .profiles();
+
+    // Without an id, the default profile is used with access tokens
+    // and the profile of the API key otherwise
+    let profile = match config.profile_id(profile_id) {
+        Some(profile_id) => {
+            debug!("Running Get API Profile for profile: {}", profile_id);
+            profiles_api.get_by_id(&profile_id).await?
+        }
+        None => {
+            debug!("Running Get API Profile for the current profile");
+            profiles_api.current().await?
+        }
+    };
+
+    info!("{}", Colorize::bright_black(&*Profile::header()));
+    info!("{}", Profile::new(&profile, time_format));
+    debug!("{:?}", profile);
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&profile).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
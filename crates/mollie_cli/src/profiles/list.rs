@@ -0,0 +1,45 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::pagination;
+use crate::profiles::Profile;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    limit: Option<i32>,
+    from: &Option<String>,
+    all: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing profiles");
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .profiles()
+        .list(limit, from)
+        .await?;
+
+    let (profiles, response) = pagination::collect_page(page, all).await?;
+
+    info!("   {}", Colorize::bright_black(&*Profile::header()));
+    profiles.iter().for_each(|profile| {
+        let row = Profile::new(profile, time_format);
+        if config.auth.profile_id.as_ref() == Some(&profile.id) {
+            info!("{} {}", Colorize::green("*"), row);
+        } else {
+            info!("  {}", row);
+        }
+    });
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&response).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
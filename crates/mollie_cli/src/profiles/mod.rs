@@ -0,0 +1,127 @@
+use crate::config::ConfigurationService;
+use crate::datetime::TimeFormat;
+use clap::{Parser, Subcommand};
+use colored::{ColoredString, Colorize};
+use mollie_api::models::mode::Mode;
+use mollie_api::models::profile::{ProfileResource, ProfileStatus};
+use std::fmt::Display;
+
+mod get;
+mod list;
+mod switch;
+
+#[derive(Parser)]
+#[clap(version, about, arg_required_else_help(true))]
+pub struct ProfilesCommand {
+    /// Enable debug logging
+    #[clap(short, long, global = true)]
+    debug: bool,
+
+    /// Print the API response after performing an API call
+    #[clap(long = "withResponse", global = true)]
+    with_response: bool,
+
+    /// How to render timestamps
+    #[clap(long, value_enum, global = true, default_value_t)]
+    time_format: TimeFormat,
+
+    #[clap(subcommand)]
+    command: Option<ProfilesCommands>,
+}
+
+#[derive(Subcommand)]
+pub enum ProfilesCommands {
+    /// List the profiles of the organization and highlight the default one
+    List {
+        #[clap(short, long)]
+        limit: Option<i32>,
+        #[clap(short, long)]
+        from: Option<String>,
+        /// Follow the pagination links and list the profiles of every page
+        #[clap(long)]
+        all: bool,
+    },
+    /// Get a profile, the default or current one when no id is passed
+    Get { id: Option<String> },
+    /// Set the default profile used with access tokens
+    #[clap(arg_required_else_help(true))]
+    Use { id: String },
+}
+
+pub async fn command(
+    command: &ProfilesCommand,
+    config_service: &mut dyn ConfigurationService,
+) -> miette::Result<()> {
+    let with_response = command.with_response;
+    let time_format = command.time_format;
+    match command.command.as_ref() {
+        Some(ProfilesCommands::List { limit, from, all }) => {
+            list::command(
+                config_service.read(),
+                *limit,
+                from,
+                *all,
+                with_response,
+                time_format,
+            )
+            .await
+        }
+        Some(ProfilesCommands::Get { id }) => {
+            get::command(config_service.read(), id, with_response, time_format).await
+        }
+        Some(ProfilesCommands::Use { id }) => switch::command(config_service, id).await,
+        None => Ok(()),
+    }
+}
+
+pub struct Profile {
+    pub id: String,
+    pub mode: Mode,
+    pub status: ProfileStatus,
+    pub name: String,
+    pub website: String,
+    pub created_at: String,
+}
+
+impl Profile {
+    pub fn new(profile: &ProfileResource, time_format: TimeFormat) -> Self {
+        Self {
+            id: profile.id.clone(),
+            mode: profile.mode.clone(),
+            status: profile.status.clone(),
+            name: profile.name.clone(),
+            website: profile.website.clone(),
+            created_at: time_format.format(&profile.created_at),
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^14} {:^6} {:^10} {:^20} {:^30} {:^19} |",
+            "ID", "MODE", "STATUS", "NAME", "WEBSITE", "CREATED_AT"
+        )
+    }
+
+    fn colorize(&self, value: &str) -> ColoredString {
+        match self.status {
+            ProfileStatus::Verified => Colorize::green(value),
+            ProfileStatus::Blocked => Colorize::red(value),
+            _ => Colorize::yellow(value),
+        }
+    }
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {} | {} | {} | {} | {}",
+            self.colorize(&self.id),
+            self.mode,
+            self.colorize(&self.status.to_string()),
+            self.name,
+            Colorize::blue(&*self.website),
+            Colorize::blue(&*self.created_at),
+        )
+    }
+}
@@ -0,0 +1,30 @@
+use crate::config::ConfigurationService;
+use log::{info, warn};
+use mollie_api::auth::ApiBearerToken;
+use mollie_api::Mollie;
+
+pub async fn command(
+    config_service: &mut dyn ConfigurationService,
+    id: &str,
+) -> miette::Result<()> {
+    let config = config_service.read();
+    let token = config.bearer_token()?;
+    if let ApiBearerToken::ApiKey(_) = token {
+        warn!("API keys belong to a single profile, the default profile is only used with access tokens");
+    }
+
+    // Make sure the profile exists before storing it
+    let profile = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .profiles()
+        .get_by_id(id)
+        .await?;
+
+    config_service.update(&|config| {
+        config.auth.profile_id = Some(profile.id.clone());
+    })?;
+
+    info!("Using profile {} ({}) by default", profile.id, profile.name);
+    Ok(())
+}
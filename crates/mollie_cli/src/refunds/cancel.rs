@@ -0,0 +1,27 @@
+use crate::config::MollieConfig;
+use colored::Colorize;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    payment_id: &str,
+    refund_id: &str,
+    testmode: bool,
+) -> miette::Result<()> {
+    debug!("Running Cancel API Refund for refund: {}", refund_id);
+    let token = config.bearer_token()?;
+    Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .refunds()
+        .cancel(payment_id, refund_id, testmode.then_some(true))
+        .await?;
+
+    info!(
+        "{}",
+        Colorize::red(&*format!("Refund {} cancelled", refund_id))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::refunds::Refund;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    payment_id: &str,
+    refund_id: &str,
+    testmode: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Running Get API Refund for refund: {}", refund_id);
+    let token = config.bearer_token()?;
+    let refund = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .refunds()
+        .get_by_id(payment_id, refund_id, testmode.then_some(true))
+        .await?;
+
+    info!("{}", Colorize::bright_black(&*Refund::header()));
+    info!("{}", Refund::new(refund.clone(), time_format).to_string());
+    debug!("{:?}", refund);
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&refund).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
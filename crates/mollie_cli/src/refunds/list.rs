@@ -0,0 +1,62 @@
+use crate::config::MollieConfig;
+use crate::datetime::{DateRange, TimeFormat};
+use crate::pagination;
+use crate::refunds::Refund;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::models::list::Page;
+use mollie_api::models::refund::RefundResource;
+use mollie_api::Mollie;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn command(
+    config: &MollieConfig,
+    limit: Option<i32>,
+    from: &Option<String>,
+    profile_id: &Option<String>,
+    test_mode: Option<bool>,
+    all: bool,
+    range: &DateRange,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing refunds");
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .refunds()
+        .list(limit, from, &config.profile_id(profile_id), test_mode)
+        .await?;
+
+    list_refunds(page, all, range, with_response, time_format).await
+}
+
+/// Print a page of refunds, or every refund starting from this page with `all`
+pub async fn list_refunds(
+    page: Page<RefundResource>,
+    all: bool,
+    range: &DateRange,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    let (mut refunds, response) = pagination::collect_page(page, all).await?;
+    refunds.retain(|refund| range.contains(&refund.created_at));
+
+    info!("   {}", Colorize::bright_black(&*Refund::header()));
+    refunds.iter().enumerate().for_each(|(index, refund)| {
+        info!(
+            "{}. {}",
+            index + 1,
+            Refund::new(refund.clone(), time_format).to_string()
+        );
+    });
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&response).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use crate::config::ConfigurationService;
+use crate::datetime::{self, DateRange, TimeFormat};
+use chrono::{DateTime, FixedOffset};
+use clap::{Parser, Subcommand};
+use colored::{ColoredString, Colorize};
+use mollie_api::models::amount::Amount;
+use mollie_api::models::refund::{RefundResource, RefundStatus};
+use std::fmt::Display;
+
+mod cancel;
+mod get;
+pub mod list;
+
+#[derive(Parser)]
+#[clap(version, about, arg_required_else_help(true))]
+pub struct RefundsCommand {
+    /// Enable debug logging
+    #[clap(short, long, global = true)]
+    debug: bool,
+
+    /// Print the API response after performing an API call
+    #[clap(long = "withResponse", global = true)]
+    with_response: bool,
+
+    /// How to render timestamps
+    #[clap(long, value_enum, global = true, default_value_t)]
+    time_format: TimeFormat,
+
+    #[clap(subcommand)]
+    command: Option<RefundsCommands>,
+}
+
+#[derive(Subcommand)]
+pub enum RefundsCommands {
+    /// List the refunds of your organization
+    List {
+        #[clap(short, long)]
+        limit: Option<i32>,
+        #[clap(short, long)]
+        from: Option<String>,
+        #[clap(short, long)]
+        profile_id: Option<String>,
+        #[clap(short, long)]
+        test_mode: Option<bool>,
+        /// Follow the pagination links and list the refunds of every page
+        #[clap(long)]
+        all: bool,
+        /// Only list refunds created on or after this date (2024-01-31) or ISO 8601 timestamp
+        #[clap(long, value_parser = datetime::parse_since)]
+        since: Option<DateTime<FixedOffset>>,
+        /// Only list refunds created on or before this date (2024-01-31) or before this ISO 8601 timestamp
+        #[clap(long, value_parser = datetime::parse_until)]
+        until: Option<DateTime<FixedOffset>>,
+    },
+    /// Get a refund of a payment
+    #[clap(arg_required_else_help(true))]
+    Get {
+        payment_id: String,
+        id: String,
+        /// Get a test mode refund, only used when authenticated with an access token
+        #[clap(long)]
+        testmode: bool,
+    },
+    /// Cancel a queued or pending refund of a payment
+    #[clap(arg_required_else_help(true))]
+    Cancel {
+        payment_id: String,
+        id: String,
+        /// Cancel a test mode refund, only used when authenticated with an access token
+        #[clap(long)]
+        testmode: bool,
+    },
+}
+
+pub async fn command(
+    refunds_command: &RefundsCommand,
+    config_service: &dyn ConfigurationService,
+) -> miette::Result<()> {
+    let config = config_service.read();
+    match refunds_command.command.as_ref() {
+        Some(RefundsCommands::List {
+            limit,
+            from,
+            profile_id,
+            test_mode,
+            all,
+            since,
+            until,
+        }) => {
+            let range = DateRange {
+                since: *since,
+                until: *until,
+            };
+            list::command(
+                config,
+                *limit,
+                from,
+                profile_id,
+                *test_mode,
+                *all,
+                &range,
+                refunds_command.with_response,
+                refunds_command.time_format,
+            )
+            .await
+        }
+        Some(RefundsCommands::Get {
+            payment_id,
+            id,
+            testmode,
+        }) => {
+            get::command(
+                config,
+                payment_id,
+                id,
+                *testmode,
+                refunds_command.with_response,
+                refunds_command.time_format,
+            )
+            .await
+        }
+        Some(RefundsCommands::Cancel {
+            payment_id,
+            id,
+            testmode,
+        }) => cancel::command(config, payment_id, id, *testmode).await,
+        None => Ok(()),
+    }
+}
+
+pub struct Refund {
+    pub id: String,
+    pub status: RefundStatus,
+    pub amount: Amount,
+    pub created_at: String,
+    pub payment_id: String,
+    pub description: String,
+}
+
+impl Refund {
+    pub fn new(refund: RefundResource, time_format: TimeFormat) -> Self {
+        Self {
+            id: refund.id,
+            status: refund.status,
+            amount: refund.amount,
+            created_at: time_format.format(&refund.created_at),
+            payment_id: refund.payment_id,
+            description: refund.description,
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^14} {:^10} {:^12} {:^19} {:^14} {} |",
+            "ID", "STATUS", "AMOUNT", "CREATED_AT", "PAYMENT_ID", "DESCRIPTION"
+        )
+    }
+
+    fn colorize(&self, value: &str) -> ColoredString {
+        match self.status {
+            RefundStatus::Queued | RefundStatus::Pending | RefundStatus::Processing => {
+                Colorize::blue(value)
+            }
+            RefundStatus::Canceled => Colorize::yellow(value),
+            RefundStatus::Failed => Colorize::red(value),
+            RefundStatus::Refunded => Colorize::green(value),
+            RefundStatus::Unknown(_) => Colorize::blink(value),
+        }
+    }
+}
+
+impl Display for Refund {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {} | {} | {} | {} | {}",
+            self.colorize(&self.id),
+            self.colorize(&self.status.to_string()),
+            Colorize::green(&*self.amount.to_string()),
+            Colorize::blue(&*self.created_at),
+            self.payment_id,
+            self.description,
+        )
+    }
+}
@@ -0,0 +1,43 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::pagination;
+use crate::payments::PaymentCapture;
+use crate::settlements::SettlementItemsArgs;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    args: &SettlementItemsArgs,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing captures of settlement {}", args.id);
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .settlements()
+        .list_captures(&args.id, args.limit, &args.from)
+        .await?;
+
+    let (captures, response) = pagination::collect_page(page, args.all).await?;
+
+    info!("   {}", Colorize::bright_black(&*PaymentCapture::header()));
+    captures.iter().enumerate().for_each(|(index, capture)| {
+        info!(
+            "{}. {}",
+            index + 1,
+            PaymentCapture::new(capture, time_format)
+        );
+    });
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&response).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use crate::chargebacks::list::list_chargebacks;
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::settlements::SettlementItemsArgs;
+use log::debug;
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    args: &SettlementItemsArgs,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing chargebacks of settlement {}", args.id);
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .settlements()
+        .list_chargebacks(&args.id, args.limit, &args.from)
+        .await?;
+
+    list_chargebacks(page, args.all, with_response, time_format).await
+}
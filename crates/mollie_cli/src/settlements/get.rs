@@ -0,0 +1,39 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::settlements::{print_summary, Settlement};
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    settlement_id: &str,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!(
+        "Running Get API Settlement for settlement: {}",
+        settlement_id
+    );
+    let token = config.bearer_token()?;
+    let settlement = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .settlements()
+        .get_by_id(settlement_id)
+        .await?;
+
+    info!("{}", Colorize::bright_black(&*Settlement::header()));
+    info!("{}", Settlement::new(&settlement, time_format));
+    print_summary(&settlement)?;
+    debug!("{:?}", settlement);
+
+    if with_response {
+        let pretty_json =
+            jsonxf::pretty_print(&serde_json::to_string(&settlement).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::config::MollieConfig;
+use crate::datetime::TimeFormat;
+use crate::pagination;
+use crate::settlements::Settlement;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    limit: Option<i32>,
+    from: &Option<String>,
+    all: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing settlements");
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .settlements()
+        .list(limit, from)
+        .await?;
+
+    let (settlements, response) = pagination::collect_page(page, all).await?;
+
+    info!("   {}", Colorize::bright_black(&*Settlement::header()));
+    settlements
+        .iter()
+        .enumerate()
+        .for_each(|(index, settlement)| {
+            info!(
+                "{}. {}",
+                index + 1,
+                Settlement::new(settlement, time_format)
+            );
+        });
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&response).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
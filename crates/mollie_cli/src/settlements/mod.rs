@@ -0,0 +1,208 @@
+use crate::config::ConfigurationService;
+use crate::datetime::TimeFormat;
+use clap::{Parser, Subcommand};
+use colored::{ColoredString, Colorize};
+use log::info;
+use mollie_api::models::amount::Amount;
+use mollie_api::models::settlement::{SettlementResource, SettlementStatus};
+use std::fmt::Display;
+
+mod captures;
+mod chargebacks;
+mod get;
+mod list;
+mod payments;
+mod refunds;
+
+#[derive(Parser)]
+#[clap(version, about, arg_required_else_help(true))]
+pub struct SettlementsCommand {
+    /// Enable debug logging
+    #[clap(short, long, global = true)]
+    debug: bool,
+
+    /// Print the API response after performing an API call
+    #[clap(long = "withResponse", global = true)]
+    with_response: bool,
+
+    /// How to render timestamps
+    #[clap(long, value_enum, global = true, default_value_t)]
+    time_format: TimeFormat,
+
+    #[clap(subcommand)]
+    command: Option<SettlementsCommands>,
+}
+
+/// Settlement to list the transactions of, and how to page through them
+#[derive(clap::Args)]
+pub struct SettlementItemsArgs {
+    /// Id or bank reference of the settlement, or `next` / `open`
+    id: String,
+    #[clap(short, long)]
+    limit: Option<i32>,
+    #[clap(short, long)]
+    from: Option<String>,
+    /// Follow the pagination links and list the items of every page
+    #[clap(long)]
+    all: bool,
+}
+
+#[derive(Subcommand)]
+pub enum SettlementsCommands {
+    /// List settlements
+    List {
+        #[clap(short, long)]
+        limit: Option<i32>,
+        #[clap(short, long)]
+        from: Option<String>,
+        /// Follow the pagination links and list the settlements of every page
+        #[clap(long)]
+        all: bool,
+    },
+    /// Get a settlement with its revenue and costs per payment method
+    #[clap(arg_required_else_help(true))]
+    Get {
+        /// Id or bank reference of the settlement, or `next` / `open`
+        id: String,
+    },
+    /// List the payments of a settlement
+    #[clap(arg_required_else_help(true))]
+    Payments(SettlementItemsArgs),
+    /// List the refunds of a settlement
+    #[clap(arg_required_else_help(true))]
+    Refunds(SettlementItemsArgs),
+    /// List the chargebacks of a settlement
+    #[clap(arg_required_else_help(true))]
+    Chargebacks(SettlementItemsArgs),
+    /// List the captures of a settlement
+    #[clap(arg_required_else_help(true))]
+    Captures(SettlementItemsArgs),
+}
+
+pub async fn command(
+    settlements_command: &SettlementsCommand,
+    config_service: &dyn ConfigurationService,
+) -> miette::Result<()> {
+    let config = config_service.read();
+    let with_response = settlements_command.with_response;
+    let time_format = settlements_command.time_format;
+    match settlements_command.command.as_ref() {
+        Some(SettlementsCommands::List { limit, from, all }) => {
+            list::command(config, *limit, from, *all, with_response, time_format).await
+        }
+        Some(SettlementsCommands::Get { id }) => {
+            get::command(config, id, with_response, time_format).await
+        }
+        Some(SettlementsCommands::Payments(args)) => {
+            payments::command(config, args, with_response, time_format).await
+        }
+        Some(SettlementsCommands::Refunds(args)) => {
+            refunds::command(config, args, with_response, time_format).await
+        }
+        Some(SettlementsCommands::Chargebacks(args)) => {
+            chargebacks::command(config, args, with_response, time_format).await
+        }
+        Some(SettlementsCommands::Captures(args)) => {
+            captures::command(config, args, with_response, time_format).await
+        }
+        None => Ok(()),
+    }
+}
+
+/// Print the gross revenue, costs and net amount of every payment method in a settlement
+pub fn print_summary(settlement: &SettlementResource) -> miette::Result<()> {
+    let totals = settlement.totals_by_method()?;
+    if totals.is_empty() {
+        return Ok(());
+    }
+
+    info!(
+        "{}",
+        Colorize::bright_black(&*format!(
+            "|{:^16} {:^6} {:^14} {:^14} {:^14} |",
+            "METHOD", "COUNT", "REVENUE", "COSTS", "NET"
+        ))
+    );
+    for (method, total) in totals {
+        let net = match (&total.revenue, &total.costs) {
+            (Some(revenue), Some(costs)) => revenue.checked_sub(costs)?,
+            (Some(revenue), None) => revenue.clone(),
+            (None, Some(costs)) => Amount::zero(costs.currency())?.checked_sub(costs)?,
+            (None, None) => continue,
+        };
+        info!(
+            "{} | {} | {} | {} | {}",
+            method.as_deref().unwrap_or("other"),
+            total.count,
+            Colorize::green(&*display_amount(&total.revenue)),
+            Colorize::red(&*display_amount(&total.costs)),
+            net,
+        );
+    }
+
+    Ok(())
+}
+
+fn display_amount(amount: &Option<Amount>) -> String {
+    amount
+        .as_ref()
+        .map(|amount| amount.to_string())
+        .unwrap_or_else(|| String::from("-"))
+}
+
+pub struct Settlement {
+    pub id: String,
+    pub reference: String,
+    pub status: SettlementStatus,
+    pub amount: Amount,
+    pub created_at: String,
+    pub settled_at: String,
+}
+
+impl Settlement {
+    pub fn new(settlement: &SettlementResource, time_format: TimeFormat) -> Self {
+        Self {
+            id: settlement.id.clone(),
+            reference: settlement.reference.clone().unwrap_or_default(),
+            status: settlement.status.clone(),
+            amount: settlement.amount.clone(),
+            created_at: time_format.format(&settlement.created_at),
+            settled_at: settlement
+                .settled_at
+                .as_ref()
+                .map(|settled_at| time_format.format(settled_at))
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^14} {:^16} {:^8} {:^14} {:^19} {:^19} |",
+            "ID", "REFERENCE", "STATUS", "AMOUNT", "CREATED_AT", "SETTLED_AT"
+        )
+    }
+
+    fn colorize(&self, value: &str) -> ColoredString {
+        match self.status {
+            SettlementStatus::Open | SettlementStatus::Pending => Colorize::blue(value),
+            SettlementStatus::Paidout => Colorize::green(value),
+            SettlementStatus::Failed => Colorize::red(value),
+            SettlementStatus::Unknown(_) => Colorize::blink(value),
+        }
+    }
+}
+
+impl Display for Settlement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {} | {} | {} | {} | {}",
+            self.colorize(&self.id),
+            self.reference,
+            self.colorize(&self.status.to_string()),
+            Colorize::green(&*self.amount.to_string()),
+            Colorize::blue(&*self.created_at),
+            self.settled_at,
+        )
+    }
+}
@@ -0,0 +1,32 @@
+use crate::config::MollieConfig;
+use crate::datetime::{DateRange, TimeFormat};
+use crate::payments::list::list_payments;
+use crate::settlements::SettlementItemsArgs;
+use log::debug;
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    args: &SettlementItemsArgs,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing payments of settlement {}", args.id);
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .settlements()
+        .list_payments(&args.id, args.limit, &args.from)
+        .await?;
+
+    list_payments(
+        page,
+        args.all,
+        with_response,
+        &None,
+        &DateRange::default(),
+        time_format,
+    )
+    .await
+}
@@ -0,0 +1,31 @@
+use crate::config::MollieConfig;
+use crate::datetime::{DateRange, TimeFormat};
+use crate::refunds::list::list_refunds;
+use crate::settlements::SettlementItemsArgs;
+use log::debug;
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    args: &SettlementItemsArgs,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing refunds of settlement {}", args.id);
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .settlements()
+        .list_refunds(&args.id, args.limit, &args.from)
+        .await?;
+
+    list_refunds(
+        page,
+        args.all,
+        &DateRange::default(),
+        with_response,
+        time_format,
+    )
+    .await
+}
@@ -0,0 +1,41 @@
+use crate::config::MollieConfig;
+use crate::subscriptions::Subscription;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    customer_id: &str,
+    subscription_id: &str,
+    testmode: bool,
+    with_response: bool,
+) -> miette::Result<()> {
+    debug!(
+        "Running Cancel API Subscription for subscription: {}",
+        subscription_id
+    );
+    let token = config.bearer_token()?;
+    let subscription = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .subscriptions()
+        .cancel(customer_id, subscription_id, testmode.then_some(true))
+        .await?;
+
+    info!(
+        "{}",
+        Colorize::red(&*format!("Subscription {} canceled", subscription_id))
+    );
+    info!("{}", Colorize::bright_black(&*Subscription::header()));
+    info!("{}", Subscription::new(subscription.clone()));
+
+    if with_response {
+        let pretty_json =
+            jsonxf::pretty_print(&serde_json::to_string(&subscription).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use crate::config::MollieConfig;
+use crate::subscriptions::{CreateSubscriptionArgs, Subscription};
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::models::amount::Amount;
+use mollie_api::models::subscription::CreateSubscriptionRequest;
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    args: &CreateSubscriptionArgs,
+    with_request: bool,
+    with_response: bool,
+) -> miette::Result<()> {
+    debug!("Running Create Subscription Command");
+    let amount = Amount::parse(&args.amount, &args.currency)?;
+    let request = CreateSubscriptionRequest {
+        times: args.times,
+        start_date: args.start_date,
+        method: args.method.clone(),
+        mandate_id: args.mandate_id.clone(),
+        webhook_url: args.webhook_url.clone(),
+        metadata: args.metadata.clone(),
+        testmode: args.testmode.then_some(true),
+        ..CreateSubscriptionRequest::new(amount, args.interval, &args.description)
+    };
+
+    if with_request {
+        let pretty_json = jsonxf::pretty_print(&serde_json::to_string(&request).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    let token = config.bearer_token()?;
+    let subscription = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .subscriptions()
+        .create(&args.customer_id, &request)
+        .await?;
+
+    info!("{}", Colorize::green("Subscription created"));
+    info!("{}", Colorize::bright_black(&*Subscription::header()));
+    info!("{}", Subscription::new(subscription.clone()));
+
+    if with_response {
+        let pretty_json =
+            jsonxf::pretty_print(&serde_json::to_string(&subscription).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::config::MollieConfig;
+use crate::subscriptions::Subscription;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    customer_id: &str,
+    subscription_id: &str,
+    testmode: bool,
+    with_response: bool,
+) -> miette::Result<()> {
+    debug!(
+        "Running Get API Subscription for subscription: {}",
+        subscription_id
+    );
+    let token = config.bearer_token()?;
+    let subscription = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .subscriptions()
+        .get_by_id(customer_id, subscription_id, testmode.then_some(true))
+        .await?;
+
+    info!("{}", Colorize::bright_black(&*Subscription::header()));
+    info!("{}", Subscription::new(subscription.clone()));
+    if let Some(last_charge) = subscription.last_payment_date() {
+        info!(
+            "Last charge on {}",
+            Colorize::blue(&*last_charge.to_string())
+        );
+    }
+    debug!("{:?}", subscription);
+
+    if with_response {
+        let pretty_json =
+            jsonxf::pretty_print(&serde_json::to_string(&subscription).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
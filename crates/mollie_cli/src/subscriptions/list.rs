@@ -0,0 +1,57 @@
+use crate::config::MollieConfig;
+use crate::pagination;
+use crate::subscriptions::Subscription;
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use mollie_api::Mollie;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn command(
+    config: &MollieConfig,
+    customer_id: &Option<String>,
+    limit: Option<i32>,
+    from: &Option<String>,
+    profile_id: &Option<String>,
+    test_mode: Option<bool>,
+    all: bool,
+    with_response: bool,
+) -> miette::Result<()> {
+    let token = config.bearer_token()?;
+    let subscriptions_api = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .subscriptions();
+
+    let page = match customer_id {
+        Some(customer_id) => {
+            debug!("Listing subscriptions of customer {}", customer_id);
+            subscriptions_api
+                .list(customer_id, limit, from, test_mode)
+                .await?
+        }
+        None => {
+            debug!("Listing all subscriptions");
+            subscriptions_api
+                .list_all(limit, from, &config.profile_id(profile_id), test_mode)
+                .await?
+        }
+    };
+
+    let (subscriptions, response) = pagination::collect_page(page, all).await?;
+
+    info!("   {}", Colorize::bright_black(&*Subscription::header()));
+    subscriptions
+        .iter()
+        .enumerate()
+        .for_each(|(index, subscription)| {
+            info!("{}. {}", index + 1, Subscription::new(subscription.clone()));
+        });
+
+    if with_response {
+        let pretty_json = jsonxf::pretty_print(&response).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,353 @@
+use crate::config::ConfigurationService;
+use crate::datetime::{self, TimeFormat};
+use crate::input;
+use crate::payments::create::parse_method;
+use chrono::NaiveDate;
+use clap::{Parser, Subcommand};
+use colored::{ColoredString, Colorize};
+use mollie_api::models::amount::Amount;
+use mollie_api::models::method::PaymentMethod;
+use mollie_api::models::subscription::{Interval, SubscriptionResource, SubscriptionStatus};
+use std::fmt::Display;
+
+mod cancel;
+mod create;
+mod get;
+mod list;
+mod payments;
+mod update;
+
+#[derive(Parser)]
+#[clap(version, about, arg_required_else_help(true))]
+pub struct SubscriptionsCommand {
+    /// Enable debug logging
+    #[clap(short, long, global = true)]
+    debug: bool,
+
+    /// Print the API response after performing an API call
+    #[clap(long = "withResponse", global = true)]
+    with_response: bool,
+
+    /// Print the API request before performing an API call
+    #[clap(long = "withRequest", global = true)]
+    with_request: bool,
+
+    /// How to render timestamps
+    #[clap(long, value_enum, global = true, default_value_t)]
+    time_format: TimeFormat,
+
+    #[clap(subcommand)]
+    command: Option<SubscriptionsCommands>,
+}
+
+#[derive(clap::Args)]
+pub struct CreateSubscriptionArgs {
+    customer_id: String,
+
+    #[clap(long)]
+    currency: String,
+
+    /// Amount charged every interval, e.g. 10.00
+    #[clap(long)]
+    amount: String,
+
+    /// Time between two charges, e.g. "1 month" or "14 days"
+    #[clap(long, value_parser = parse_interval)]
+    interval: Interval,
+
+    #[clap(long)]
+    description: String,
+
+    /// Total number of charges, the subscription runs until it's canceled when not set
+    #[clap(long)]
+    times: Option<u32>,
+
+    /// Date of the first charge (2024-01-31), today when not set
+    #[clap(long, value_parser = datetime::parse_date)]
+    start_date: Option<NaiveDate>,
+
+    /// Payment method of the charges, any method with a valid mandate when not set
+    #[clap(long, value_parser = parse_method)]
+    method: Option<PaymentMethod>,
+
+    /// Mandate to charge, any valid mandate of the customer when not set
+    #[clap(long)]
+    mandate_id: Option<String>,
+
+    /// Url Mollie calls when the status of a charge changes
+    #[clap(long)]
+    webhook_url: Option<String>,
+
+    /// Metadata to store with the subscription, as JSON or @file.json
+    #[clap(long, value_parser = input::parse_json::<serde_json::Value>)]
+    metadata: Option<serde_json::Value>,
+
+    /// Create a test mode subscription, only used when authenticated with an access token
+    #[clap(long)]
+    testmode: bool,
+}
+
+#[derive(clap::Args)]
+pub struct UpdateSubscriptionArgs {
+    customer_id: String,
+
+    id: String,
+
+    /// Currency of the new amount, the current currency when not set
+    #[clap(long, requires = "amount")]
+    currency: Option<String>,
+
+    /// New amount charged every interval, e.g. 10.00
+    #[clap(long)]
+    amount: Option<String>,
+
+    /// Time between two charges, e.g. "1 month" or "14 days"
+    #[clap(long, value_parser = parse_interval)]
+    interval: Option<Interval>,
+
+    #[clap(long)]
+    description: Option<String>,
+
+    /// Total number of charges, including the ones already made
+    #[clap(long)]
+    times: Option<u32>,
+
+    /// Date of the next charge (2024-01-31)
+    #[clap(long, value_parser = datetime::parse_date)]
+    start_date: Option<NaiveDate>,
+
+    /// Mandate to charge
+    #[clap(long)]
+    mandate_id: Option<String>,
+
+    /// Url Mollie calls when the status of a charge changes
+    #[clap(long)]
+    webhook_url: Option<String>,
+
+    /// Metadata to store with the subscription, as JSON or @file.json
+    #[clap(long, value_parser = input::parse_json::<serde_json::Value>)]
+    metadata: Option<serde_json::Value>,
+
+    /// Update a test mode subscription, only used when authenticated with an access token
+    #[clap(long)]
+    testmode: bool,
+}
+
+#[derive(Subcommand)]
+pub enum SubscriptionsCommands {
+    /// List the subscriptions of a customer, or of your whole organization
+    List {
+        /// Only list the subscriptions of this customer
+        #[clap(long)]
+        customer_id: Option<String>,
+        #[clap(short, long)]
+        limit: Option<i32>,
+        #[clap(short, long)]
+        from: Option<String>,
+        /// Only list the subscriptions of this profile, when listing for the whole organization
+        #[clap(short, long, conflicts_with = "customer_id")]
+        profile_id: Option<String>,
+        #[clap(short, long)]
+        test_mode: Option<bool>,
+        /// Follow the pagination links and list the subscriptions of every page
+        #[clap(long)]
+        all: bool,
+    },
+    /// Get a subscription of a customer
+    #[clap(arg_required_else_help(true))]
+    Get {
+        customer_id: String,
+        id: String,
+        /// Get a test mode subscription, only used when authenticated with an access token
+        #[clap(long)]
+        testmode: bool,
+    },
+    /// Create a subscription charging a customer on a regular interval
+    #[clap(arg_required_else_help(true))]
+    Create(Box<CreateSubscriptionArgs>),
+    /// Update the amount, interval, number of charges or other details of a subscription
+    #[clap(arg_required_else_help(true))]
+    Update(Box<UpdateSubscriptionArgs>),
+    /// Cancel a subscription, no further charges are made
+    #[clap(arg_required_else_help(true))]
+    Cancel {
+        customer_id: String,
+        id: String,
+        /// Cancel a test mode subscription, only used when authenticated with an access token
+        #[clap(long)]
+        testmode: bool,
+    },
+    /// List the payments charged for a subscription
+    #[clap(arg_required_else_help(true))]
+    Payments {
+        customer_id: String,
+        id: String,
+        #[clap(short, long)]
+        limit: Option<i32>,
+        #[clap(short, long)]
+        from: Option<String>,
+        #[clap(short, long)]
+        test_mode: Option<bool>,
+        /// Follow the pagination links and list the payments of every page
+        #[clap(long)]
+        all: bool,
+    },
+}
+
+pub async fn command(
+    subscriptions_command: &SubscriptionsCommand,
+    config_service: &dyn ConfigurationService,
+) -> miette::Result<()> {
+    let config = config_service.read();
+    let with_response = subscriptions_command.with_response;
+    let time_format = subscriptions_command.time_format;
+    match subscriptions_command.command.as_ref() {
+        Some(SubscriptionsCommands::List {
+            customer_id,
+            limit,
+            from,
+            profile_id,
+            test_mode,
+            all,
+        }) => {
+            list::command(
+                config,
+                customer_id,
+                *limit,
+                from,
+                profile_id,
+                *test_mode,
+                *all,
+                with_response,
+            )
+            .await
+        }
+        Some(SubscriptionsCommands::Get {
+            customer_id,
+            id,
+            testmode,
+        }) => get::command(config, customer_id, id, *testmode, with_response).await,
+        Some(SubscriptionsCommands::Create(args)) => {
+            create::command(
+                config,
+                args,
+                subscriptions_command.with_request,
+                with_response,
+            )
+            .await
+        }
+        Some(SubscriptionsCommands::Update(args)) => {
+            update::command(config, args, with_response).await
+        }
+        Some(SubscriptionsCommands::Cancel {
+            customer_id,
+            id,
+            testmode,
+        }) => cancel::command(config, customer_id, id, *testmode, with_response).await,
+        Some(SubscriptionsCommands::Payments {
+            customer_id,
+            id,
+            limit,
+            from,
+            test_mode,
+            all,
+        }) => {
+            payments::command(
+                config,
+                customer_id,
+                id,
+                *limit,
+                from,
+                *test_mode,
+                *all,
+                with_response,
+                time_format,
+            )
+            .await
+        }
+        None => Ok(()),
+    }
+}
+
+fn parse_interval(value: &str) -> Result<Interval, String> {
+    value.parse::<Interval>().map_err(|e| e.to_string())
+}
+
+pub struct Subscription {
+    pub id: String,
+    pub status: SubscriptionStatus,
+    pub amount: Amount,
+    pub interval: Interval,
+    pub next_charge: String,
+    pub remaining: String,
+    pub customer_id: String,
+    pub description: String,
+}
+
+impl Subscription {
+    pub fn new(subscription: SubscriptionResource) -> Self {
+        // Subscriptions without `times` run until they are canceled
+        let remaining = match (subscription.times_remaining, subscription.times) {
+            (Some(remaining), Some(times)) => format!("{}/{}", remaining, times),
+            (Some(remaining), None) => remaining.to_string(),
+            (None, _) => String::from("∞"),
+        };
+
+        Self {
+            id: subscription.id,
+            status: subscription.status,
+            amount: subscription.amount,
+            interval: subscription.interval,
+            next_charge: subscription
+                .next_payment_date
+                .map(|date| date.to_string())
+                .unwrap_or_else(|| String::from("-")),
+            remaining,
+            customer_id: subscription.customer_id,
+            description: subscription.description,
+        }
+    }
+
+    pub fn header() -> String {
+        format!(
+            "|{:^14} {:^9} {:^12} {:^9} {:^11} {:^9} {:^14} {} |",
+            "ID",
+            "STATUS",
+            "AMOUNT",
+            "INTERVAL",
+            "NEXT CHARGE",
+            "REMAINING",
+            "CUSTOMER_ID",
+            "DESCRIPTION"
+        )
+    }
+
+    fn colorize(&self, value: &str) -> ColoredString {
+        match self.status {
+            SubscriptionStatus::Pending => Colorize::blue(value),
+            SubscriptionStatus::Active => Colorize::green(value),
+            SubscriptionStatus::Canceled | SubscriptionStatus::Completed => {
+                Colorize::bright_black(value)
+            }
+            SubscriptionStatus::Suspended => Colorize::red(value),
+            SubscriptionStatus::Unknown(_) => Colorize::blink(value),
+        }
+    }
+}
+
+impl Display for Subscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {} | {} | {} | {} | {} | {} | {}",
+            self.colorize(&self.id),
+            self.colorize(&self.status.to_string()),
+            Colorize::green(&*self.amount.to_string()),
+            self.interval,
+            Colorize::blue(&*self.next_charge),
+            self.remaining,
+            self.customer_id,
+            self.description,
+        )
+    }
+}
@@ -0,0 +1,37 @@
+use crate::config::MollieConfig;
+use crate::datetime::{DateRange, TimeFormat};
+use crate::payments::list::list_payments;
+use log::debug;
+use mollie_api::Mollie;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn command(
+    config: &MollieConfig,
+    customer_id: &str,
+    subscription_id: &str,
+    limit: Option<i32>,
+    from: &Option<String>,
+    test_mode: Option<bool>,
+    all: bool,
+    with_response: bool,
+    time_format: TimeFormat,
+) -> miette::Result<()> {
+    debug!("Listing payments of subscription {}", subscription_id);
+    let token = config.bearer_token()?;
+    let page = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .subscriptions()
+        .list_payments(customer_id, subscription_id, limit, from, test_mode)
+        .await?;
+
+    list_payments(
+        page,
+        all,
+        with_response,
+        &None,
+        &DateRange::default(),
+        time_format,
+    )
+    .await
+}
@@ -0,0 +1,82 @@
+use crate::config::MollieConfig;
+use crate::subscriptions::{Subscription, UpdateSubscriptionArgs};
+use colored::Colorize;
+use colored_json::ToColoredJson;
+use log::{debug, info};
+use miette::miette;
+use mollie_api::models::amount::Amount;
+use mollie_api::models::subscription::UpdateSubscriptionRequest;
+use mollie_api::Mollie;
+
+pub async fn command(
+    config: &MollieConfig,
+    args: &UpdateSubscriptionArgs,
+    with_response: bool,
+) -> miette::Result<()> {
+    debug!(
+        "Running Update API Subscription for subscription: {}",
+        args.id
+    );
+    let token = config.bearer_token()?;
+    let subscriptions_api = Mollie::builder(token.as_str())
+        .base_url(config.api.url.clone())
+        .build()
+        .subscriptions();
+
+    // Without a currency the new amount is in the currency the subscription already charges
+    let amount = match (&args.amount, &args.currency) {
+        (Some(amount), Some(currency)) => Some(Amount::parse(amount, currency)?),
+        (Some(amount), None) => {
+            let subscription = subscriptions_api
+                .get_by_id(&args.customer_id, &args.id, args.testmode.then_some(true))
+                .await?;
+            Some(Amount::parse(amount, subscription.amount.currency())?)
+        }
+        (None, _) => None,
+    };
+
+    let request = UpdateSubscriptionRequest {
+        amount,
+        interval: args.interval,
+        description: args.description.clone(),
+        times: args.times,
+        start_date: args.start_date,
+        mandate_id: args.mandate_id.clone(),
+        webhook_url: args.webhook_url.clone(),
+        metadata: args.metadata.clone(),
+        testmode: args.testmode.then_some(true),
+    };
+
+    if request.amount.is_none()
+        && request.interval.is_none()
+        && request.description.is_none()
+        && request.times.is_none()
+        && request.start_date.is_none()
+        && request.mandate_id.is_none()
+        && request.webhook_url.is_none()
+        && request.metadata.is_none()
+    {
+        return Err(miette!(
+            code = "subscriptions::update::nothing_to_update",
+            help = "Pass at least one of --amount, --interval, --description, --times, --start-date, --mandate-id, --webhook-url or --metadata",
+            "Nothing to update for subscription {}",
+            args.id
+        ));
+    }
+
+    let subscription = subscriptions_api
+        .update(&args.customer_id, &args.id, &request)
+        .await?;
+
+    info!("{}", Colorize::green("Subscription updated"));
+    info!("{}", Colorize::bright_black(&*Subscription::header()));
+    info!("{}", Subscription::new(subscription.clone()));
+
+    if with_response {
+        let pretty_json =
+            jsonxf::pretty_print(&serde_json::to_string(&subscription).unwrap()).unwrap();
+        info!("{}", pretty_json.to_colored_json_auto().unwrap());
+    }
+
+    Ok(())
+}